@@ -0,0 +1,176 @@
+//! `erc20_*` JSON-RPC methods, so that dApps and wallets can read the token state kept by
+//! pallet-template without decoding storage keys. Every method is answered by the runtime's
+//! [`Erc20RuntimeApi`] at the given block, or the best block if none is given.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_template_runtime_api::Erc20Api as Erc20RuntimeApi;
+
+/// Error code returned when the runtime could not answer a query.
+pub const RUNTIME_ERROR: i32 = 1;
+
+/// One page of token holders, as returned by `erc20_holders`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoldersPage<AccountId, Balance> {
+	/// The holders on this page with their balances, in storage order.
+	pub holders: Vec<(AccountId, Balance)>,
+	/// Key to pass as `start_key` for the next page, `null` once every holder was returned.
+	pub next_key: Option<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait Erc20Api<BlockHash, AccountId, Balance> {
+	/// Total number of tokens in existence.
+	#[method(name = "erc20_totalSupply")]
+	fn total_supply(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// `account`'s balance as a transfer would see it, zero if it holds no tokens.
+	#[method(name = "erc20_balanceOf")]
+	fn balance_of(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// What is left of `owner`'s allowance for `spender`, zero if it approved none.
+	#[method(name = "erc20_allowance")]
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// The token's name. Bytes that are not valid UTF-8 are replaced.
+	#[method(name = "erc20_name")]
+	fn name(&self, at: Option<BlockHash>) -> RpcResult<String>;
+
+	/// The token's symbol. Bytes that are not valid UTF-8 are replaced.
+	#[method(name = "erc20_symbol")]
+	fn symbol(&self, at: Option<BlockHash>) -> RpcResult<String>;
+
+	/// Number of decimal places balances are shown with.
+	#[method(name = "erc20_decimals")]
+	fn decimals(&self, at: Option<BlockHash>) -> RpcResult<u8>;
+
+	/// Most tokens that may ever be in existence, `null` while the supply is uncapped.
+	#[method(name = "erc20_maxSupply")]
+	fn max_supply(&self, at: Option<BlockHash>) -> RpcResult<Option<Balance>>;
+
+	/// Number of accounts holding a non-zero balance.
+	#[method(name = "erc20_holderCount")]
+	fn holder_count(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Up to `limit` holders after `start_key`, or from the first holder if it is `null`. The
+	/// runtime caps `limit`, so keep following `nextKey` until it comes back `null`.
+	#[method(name = "erc20_holders")]
+	fn holders(
+		&self,
+		start_key: Option<Bytes>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<HoldersPage<AccountId, Balance>>;
+}
+
+/// Serves [`Erc20ApiServer`] from the runtime of `client`.
+pub struct Erc20<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Erc20<C, Block> {
+	/// Answers queries with the runtime of `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+fn runtime_error(error: sp_api::ApiError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the token state.",
+		Some(error.to_string()),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, Balance> Erc20ApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for Erc20<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: Erc20RuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+{
+	fn total_supply(&self, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().total_supply(at).map_err(runtime_error)
+	}
+
+	fn balance_of(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().balance_of(at, account).map_err(runtime_error)
+	}
+
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().allowance(at, owner, spender).map_err(runtime_error)
+	}
+
+	fn name(&self, at: Option<Block::Hash>) -> RpcResult<String> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let name = self.client.runtime_api().name(at).map_err(runtime_error)?;
+		Ok(String::from_utf8_lossy(&name).into_owned())
+	}
+
+	fn symbol(&self, at: Option<Block::Hash>) -> RpcResult<String> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let symbol = self.client.runtime_api().symbol(at).map_err(runtime_error)?;
+		Ok(String::from_utf8_lossy(&symbol).into_owned())
+	}
+
+	fn decimals(&self, at: Option<Block::Hash>) -> RpcResult<u8> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().decimals(at).map_err(runtime_error)
+	}
+
+	fn max_supply(&self, at: Option<Block::Hash>) -> RpcResult<Option<Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().max_supply(at).map_err(runtime_error)
+	}
+
+	fn holder_count(&self, at: Option<Block::Hash>) -> RpcResult<u32> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().holder_count(at).map_err(runtime_error)
+	}
+
+	fn holders(
+		&self,
+		start_key: Option<Bytes>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<HoldersPage<AccountId, Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let (holders, next_key) = self
+			.client
+			.runtime_api()
+			.holders(at, start_key.map(|key| key.0), limit)
+			.map_err(runtime_error)?;
+		Ok(HoldersPage { holders, next_key: next_key.map(Bytes) })
+	}
+}
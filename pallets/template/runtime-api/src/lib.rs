@@ -0,0 +1,90 @@
+//! Runtime API for reading the token state kept by pallet-template without submitting
+//! transactions.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_template::Erc20Error;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait Erc20Api<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Balances of `accounts` in input order, zero for accounts holding no tokens.
+		///
+		/// At most `pallet_template::MAX_BALANCES_QUERY` accounts are answered; the rest of the
+		/// input is ignored.
+		fn balances_of(accounts: Vec<AccountId>) -> Vec<Balance>;
+
+		/// Number of accounts holding a non-zero balance.
+		fn holder_count() -> u32;
+
+		/// All-time number of tokens burned.
+		fn total_burned() -> Balance;
+
+		/// Total supply less the balances of accounts excluded from circulation.
+		fn circulating_supply() -> Balance;
+
+		/// All-time number of transfers and total amount transferred.
+		fn transfer_stats() -> (u64, u128);
+
+		/// Total supply recorded at the start of supply tracking period `index`, if still kept.
+		fn supply_at_period(index: u32) -> Option<Balance>;
+
+		/// Deposit sub-account number `index` of `master`; its balance can be swept into
+		/// `master` without the sub-account's key.
+		fn derive_deposit_account(master: AccountId, index: u32) -> AccountId;
+
+		/// Domain separator that every payload signed for the token must include.
+		fn domain_separator() -> [u8; 32];
+
+		/// The exact bytes to sign to authorize `message`, the SCALE encoding of what is
+		/// authorized.
+		fn signing_payload(message: Vec<u8>) -> Vec<u8>;
+
+		/// Every spender `owner` has approved, with the remaining allowance.
+		fn approvals_of(owner: AccountId) -> Vec<(AccountId, Balance)>;
+
+		/// All-time tokens `spender` has moved out of `owner`'s balance.
+		fn spent_by(owner: AccountId, spender: AccountId) -> Balance;
+
+		/// `Ok` if `from` could transfer `value` to `to` right now, or why it could not. Nothing
+		/// is written.
+		fn simulate_transfer(from: AccountId, to: AccountId, value: Balance) -> Result<(), Erc20Error>;
+
+		/// Up to `limit` holders after the raw storage key `start_key`, plus the cursor for the
+		/// next page (`None` when there are no more holders).
+		fn holders(start_key: Option<Vec<u8>>, limit: u32) -> (Vec<(AccountId, Balance)>, Option<Vec<u8>>);
+
+		/// The latest reserve attestation: the reserves, the block they were measured at and
+		/// the attestation's reference.
+		fn latest_reserve_attestation() -> Option<(Balance, u64, Vec<u8>)>;
+
+		/// The `n` largest holders with their balances, largest first, at most
+		/// `TopHoldersTracked`.
+		fn top_holders(n: u32) -> Vec<(AccountId, Balance)>;
+
+		/// Total number of tokens in existence.
+		fn total_supply() -> Balance;
+
+		/// `account`'s balance as a transfer would see it, zero if it holds no tokens.
+		fn balance_of(account: AccountId) -> Balance;
+
+		/// What is left of `owner`'s allowance for `spender`, zero if it approved none.
+		fn allowance(owner: AccountId, spender: AccountId) -> Balance;
+
+		/// The token's name, as UTF-8 bytes.
+		fn name() -> Vec<u8>;
+
+		/// The token's symbol, as UTF-8 bytes.
+		fn symbol() -> Vec<u8>;
+
+		/// Number of decimal places balances are shown with.
+		fn decimals() -> u8;
+
+		/// Most tokens that may ever be in existence, `None` while the supply is uncapped.
+		fn max_supply() -> Option<Balance>;
+	}
+}
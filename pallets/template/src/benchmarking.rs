@@ -2,33 +2,1559 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
+use crate::traits::BenchmarkHelper;
 #[allow(unused)]
 use crate::Pallet as Template;
+use codec::Decode;
 use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Get, Hooks, ReservableCurrency},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Bounded, One, Saturating, StaticLookup, TrailingZeroInput, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+// Endows `who` the way the pallet itself would, keeping `HolderCount` and the provider
+// reference in step.
+fn fund<T: Config>(who: &T::AccountId, amount: T::Balance) {
+	frame_system::Pallet::<T>::inc_providers(who);
+	BalanceOf::<T>::insert(T::NativeAsset::get(), who, amount);
+	HolderCount::<T>::mutate(|count| *count += 1);
+	Pallet::<T>::update_top_holders(who, Zero::zero(), amount);
+}
+
+// An asset id other than the native token's, for `create_asset` to launch.
+fn launched_id<T: Config>() -> T::AssetId {
+	let id = T::AssetId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+	if id != T::NativeAsset::get() {
+		return id
+	}
+	T::AssetId::decode(&mut TrailingZeroInput::new(&[1; 32])).unwrap()
+}
+
+// Launches a token managed by `admin` whose balances may be as small as one.
+fn launch<T: Config>(admin: &T::AccountId) -> T::AssetId {
+	let id = launched_id::<T>();
+	Asset::<T>::insert(id, AssetDetails::<T> { admin: admin.clone(), min_balance: One::one() });
+	id
+}
+
+// Gives `owner` enough native currency to reserve `count` allowance deposits.
+fn fund_deposits<T: Config>(owner: &T::AccountId, count: u32) {
+	let deposits = T::AllowanceDeposit::get().saturating_mul(count.into());
+	T::Currency::make_free_balance_be(
+		owner,
+		T::Currency::minimum_balance().saturating_add(deposits),
+	);
+}
+
+// Fills `owner`'s approval index with `count` other spenders so the index is as large as it gets.
+fn approve_others<T: Config>(owner: &T::AccountId, count: u32) {
+	for i in 0..count {
+		let spender: T::AccountId = account("other", i, SEED);
+		Allowances::<T>::insert((T::NativeAsset::get(), owner, &spender), T::Balance::one());
+		Approvals::<T>::mutate(owner, |spenders| spenders.try_push(spender).unwrap());
+	}
+}
+
+// Puts a transfer limit on `who` that never gets in the way, so transfers pay for tracking it.
+fn limit_outflow<T: Config>(who: &T::AccountId) {
+	TransferLimits::<T>::insert(
+		who,
+		TransferLimit::<T> {
+			max_per_period: T::Balance::max_value(),
+			period: One::one(),
+			window_start: Zero::zero(),
+			sent_in_window: Zero::zero(),
+		},
+	);
+	LimitedAccounts::<T>::mutate(|count| *count += 1);
+}
+
+// Fills the announced mint queue with `count` mints, the last one due now.
+fn announce_mints<T: Config>(count: u32) {
+	for i in 0..count {
+		AnnouncedMints::<T>::mutate(|announced| {
+			announced
+				.try_push(AnnouncedMint {
+					id: i as u64,
+					to: account("to", i, SEED),
+					amount: 1_000u32.into(),
+					executable_at: Zero::zero(),
+				})
+				.unwrap()
+		});
+	}
+	NextMintId::<T>::put(count as u64);
+}
+
+// Fills `payer`'s request queue with `count` open requests carrying the longest memo.
+fn request_others<T: Config>(payer: &T::AccountId, count: u32) {
+	let memo: BoundedVec<_, _> =
+		sp_std::vec![0u8; T::MaxDataLen::get() as usize].try_into().unwrap();
+	for i in 0..count {
+		PaymentRequests::<T>::mutate(payer, |requests| {
+			requests
+				.try_push(PaymentRequest {
+					id: u64::MAX - i as u64,
+					requester: account("requester", i, SEED),
+					amount: One::one(),
+					memo: memo.clone(),
+					expires_at: T::RequestTtl::get(),
+				})
+				.unwrap()
+		});
+	}
+}
+
+// Gives `who` an active stake the way `stake` would.
+fn stake_for<T: Config>(who: &T::AccountId, amount: T::Balance) {
+	frame_system::Pallet::<T>::inc_providers(who);
+	Ledgers::<T>::insert(
+		who,
+		StakingLedger::<T> { active: amount, unlocking: Default::default(), reward_debt: 0 },
+	);
+	TotalStaked::<T>::mutate(|total| *total += amount);
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
+	fn total_supply() {
+		let value: T::Balance = 1_000_000u32.into();
+		TotalSupply::<T>::insert(T::NativeAsset::get(), value);
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		total_supply(RawOrigin::Signed(caller));
+
+		assert_last_event::<T>(Event::TotalSupply { value }.into());
+	}
+
+	#[benchmark]
+	fn balance_of() {
+		let balance: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let user: T::AccountId = account("user", 0, SEED);
+		fund::<T>(&user, balance);
+		#[extrinsic_call]
+		balance_of(RawOrigin::Signed(caller), user.clone());
+
+		assert_last_event::<T>(Event::BalanceOf { who: user, balance }.into());
+	}
+
+	#[benchmark]
+	fn force_set_balance() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let balance: T::Balance = 1_000_000u32.into();
+		let who: T::AccountId = account("who", 0, SEED);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), balance);
+
+		assert_eq!(Template::<T>::get_balance_of(&who), Some(balance));
+		assert_last_event::<T>(Event::BalanceForced { who, balance }.into());
+		Ok(())
+	}
+
+	// Worst case: the recipient has never held the token, so its entry has to be created, and the
+	// sender moves its whole balance and is reaped.
+	#[benchmark]
+	fn transfer() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		fund::<T>(&caller, value);
+		limit_outflow::<T>(&caller);
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(to.clone()), value);
+
+		assert_eq!(Template::<T>::get_balance_of(&to), Some(value));
+		assert!(!BalanceOf::<T>::contains_key(T::NativeAsset::get(), &caller));
+		assert_last_event::<T>(Event::Transfer { from: caller, to, value }.into());
+	}
+
+	// Same worst case as `transfer`, plus the id record.
+	#[benchmark]
+	fn transfer_with_id() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		fund::<T>(&caller, value);
+		limit_outflow::<T>(&caller);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(to.clone()), value, [1; 16]);
+
+		assert!(UsedTransferIds::<T>::contains_key(&caller, [1; 16]));
+		assert_last_event::<T>(
+			Event::TransferWithId { from: caller, to, value, id: [1; 16] }.into(),
+		);
+	}
+
+	// Worst case: the new spender takes the last free slot in the owner's approval index, a
+	// deposit is reserved for it, and it gets a deadline.
+	#[benchmark]
+	fn approve() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let deadline = BlockNumberFor::<T>::max_value();
+		fund_deposits::<T>(&caller, 1);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		#[extrinsic_call]
+		approve(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(spender.clone()),
+			value,
+			Some(deadline),
+		);
+
+		assert_eq!(Allowances::<T>::get((T::NativeAsset::get(), &caller, &spender)), Some(value));
+		assert_eq!(AllowanceExpiry::<T>::get((&caller, &spender)), Some(deadline));
+		assert!(Approvals::<T>::get(&caller).contains(&spender));
+		assert_last_event::<T>(Event::Approval { from: caller, to: spender, value }.into());
+	}
+
+	// Worst case: the recipient has never held the token, the owner is reaped and, after an
+	// exhausted subscription is consulted, the allowance is spent down to zero, which removes the
+	// allowance entry from a full approval index and returns its deposit.
+	#[benchmark]
+	fn transfer_from() {
+		let value: T::Balance = 1_000_000u32.into();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let spender: T::AccountId = whitelisted_caller();
+		fund::<T>(&owner, value);
+		limit_outflow::<T>(&owner);
+		approve_others::<T>(&owner, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		Allowances::<T>::insert((T::NativeAsset::get(), &owner, &spender), value);
+		Approvals::<T>::mutate(&owner, |spenders| spenders.try_push(spender.clone()).unwrap());
+		fund_deposits::<T>(&owner, 1);
+		T::Currency::reserve(&owner, T::AllowanceDeposit::get()).unwrap();
+		AllowanceDeposits::<T>::insert((&owner, &spender), T::AllowanceDeposit::get());
+		Subscriptions::<T>::insert(
+			(&owner, &spender),
+			Subscription::<T> {
+				limit: Zero::zero(),
+				period: One::one(),
+				window_start: Zero::zero(),
+				spent_in_window: Zero::zero(),
+			},
+		);
+		#[extrinsic_call]
+		transfer_from(
+			RawOrigin::Signed(spender.clone()),
+			T::Lookup::unlookup(owner.clone()),
+			T::Lookup::unlookup(spender.clone()),
+			value,
+		);
+
+		assert_eq!(Template::<T>::get_balance_of(&spender), Some(value));
+		assert!(!Allowances::<T>::contains_key((T::NativeAsset::get(), &owner, &spender)));
+		assert!(!Approvals::<T>::get(&owner).contains(&spender));
+		assert_last_event::<T>(Event::TransferFrom { from: owner, to: spender, value }.into());
+	}
+
+	// Worst case: as for `transfer_from`, with the allowance still in the legacy layout and a
+	// separate recipient.
+	#[benchmark]
+	fn spend_all_allowance() {
+		let value: T::Balance = 1_000_000u32.into();
+		let spender: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let to: T::AccountId = account("to", 1, SEED);
+		fund::<T>(&owner, value);
+		limit_outflow::<T>(&owner);
+		approve_others::<T>(&owner, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		Allowance::<T>::insert((&owner, &spender), T::Balance::max_value());
+		Approvals::<T>::mutate(&owner, |spenders| spenders.try_push(spender.clone()).unwrap());
+		fund_deposits::<T>(&owner, 1);
+		T::Currency::reserve(&owner, T::AllowanceDeposit::get()).unwrap();
+		AllowanceDeposits::<T>::insert((&owner, &spender), T::AllowanceDeposit::get());
+		#[extrinsic_call]
+		spend_all_allowance(RawOrigin::Signed(spender.clone()), owner.clone(), to.clone());
+
+		assert_eq!(Template::<T>::get_balance_of(&to), Some(value));
+		assert!(!Allowance::<T>::contains_key((&owner, &spender)));
+		assert!(!Approvals::<T>::get(&owner).contains(&spender));
+		assert_last_event::<T>(Event::TransferFrom { from: owner, to, value }.into());
+	}
+
+	// Worst case: the delegate is new and takes the last free delegation slot.
+	#[benchmark]
+	fn delegate_allowance() {
+		let spender: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let others = T::MaxDelegations::get().saturating_sub(1);
+		let amount = T::Balance::one();
+		Allowances::<T>::insert(
+			(T::NativeAsset::get(), &owner, &spender),
+			T::Balance::from(others) + amount,
+		);
+		Approvals::<T>::mutate(&owner, |spenders| spenders.try_push(spender.clone()).unwrap());
+		for i in 0..others {
+			let other: T::AccountId = account("other", i, SEED);
+			Delegations::<T>::mutate(&owner, &spender, |delegations| {
+				delegations.try_push((other.clone(), amount)).unwrap()
+			});
+			DelegatedAllowances::<T>::insert(&owner, &other, &spender);
+		}
+		#[extrinsic_call]
+		_(RawOrigin::Signed(spender.clone()), owner.clone(), delegate.clone(), amount);
+
+		assert_eq!(DelegatedAllowances::<T>::get(&owner, &delegate), Some(spender.clone()));
+		assert_last_event::<T>(
+			Event::AllowanceDelegated { owner, spender, delegate, amount }.into(),
+		);
+	}
+
+	// Same worst case as `transfer`, carrying the largest data blobs allowed.
+	#[benchmark]
+	fn send() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		fund::<T>(&caller, value);
+		limit_outflow::<T>(&caller);
+		let data: BoundedVec<_, _> =
+			sp_std::vec![0u8; T::MaxDataLen::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		send(RawOrigin::Signed(caller.clone()), to.clone(), value, data.clone(), data.clone());
+
+		assert_eq!(Template::<T>::get_balance_of(&to), Some(value));
+		assert_last_event::<T>(
+			Event::Sent { from: caller, to, value, data: data.clone(), operator_data: data }.into(),
+		);
+	}
+
+	// Worst case: the largest immediate mint, to an account that has never held the token but
+	// saves every incoming token, with none of its tranches matured. The whole tranche list is
+	// scanned and the new tokens join the last tranche.
+	#[benchmark]
+	fn mint() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount = T::MintAnnouncementThreshold::get().min(T::MaxMintPerBlock::get());
+		let max = T::MaxSavingTranches::get();
+		let tranches: BoundedVec<(BlockNumberFor<T>, T::Balance), _> = (1..=max)
+			.map(|i| ((max + i).into(), T::Balance::one()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		SavingsLock::<T>::insert(&to, BlockNumberFor::<T>::from(max + 1));
+		SavingTranches::<T>::insert(&to, tranches);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, to.clone(), amount);
+
+		assert_last_event::<T>(Event::Minted { to, amount }.into());
+		Ok(())
+	}
+
+	// Worst case: the whole balance is burned, reaping the account.
+	#[benchmark]
+	fn burn() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let from: T::AccountId = account("from", 0, SEED);
+		let value: T::Balance = 1_000u32.into();
+		fund::<T>(&from, value);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, from.clone(), value);
+
+		assert_eq!(Template::<T>::get_balance_of(&from), None);
+		assert_last_event::<T>(Event::Burned { from, value }.into());
+		Ok(())
+	}
+
+	// Worst case: the announcement takes the last free slot in the queue.
+	#[benchmark]
+	fn announce_mint() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		announce_mints::<T>(T::MaxAnnouncedMints::get() - 1);
+		let to: T::AccountId = account("to", 0, SEED);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, to, 1_000u32.into());
+
+		assert_eq!(AnnouncedMints::<T>::get().len() as u32, T::MaxAnnouncedMints::get());
+		Ok(())
+	}
+
+	// Worst case: the last mint in a full queue, to an account that has never held the token.
+	#[benchmark]
+	fn execute_mint() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		announce_mints::<T>(T::MaxAnnouncedMints::get());
+		let last = T::MaxAnnouncedMints::get() - 1;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, last as u64);
+
+		let to: T::AccountId = account("to", last, SEED);
+		let amount = 1_000u32.into();
+		assert_last_event::<T>(Event::MintExecuted { id: last as u64, to, amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_mint() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		announce_mints::<T>(T::MaxAnnouncedMints::get());
+		let last = (T::MaxAnnouncedMints::get() - 1) as u64;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, last);
+
+		assert_last_event::<T>(Event::MintCancelled { id: last }.into());
+		Ok(())
+	}
+
+	// Worst case: the account takes the last free slot.
+	#[benchmark]
+	fn exclude_from_circulating() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxExcludedAccounts::get();
+		let excluded: Vec<T::AccountId> = (1..max).map(|i| account("excluded", i, SEED)).collect();
+		ExcludedFromCirculating::<T>::put(BoundedVec::try_from(excluded).unwrap());
+		let who: T::AccountId = account("excluded", 0, SEED);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert_last_event::<T>(Event::ExcludedFromCirculating { who }.into());
+		Ok(())
+	}
+
+	// Worst case: the account is last in a full list.
+	#[benchmark]
+	fn include_in_circulating() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxExcludedAccounts::get();
+		let excluded: Vec<T::AccountId> = (0..max).map(|i| account("excluded", i, SEED)).collect();
+		ExcludedFromCirculating::<T>::put(BoundedVec::try_from(excluded).unwrap());
+		let who: T::AccountId = account("excluded", max - 1, SEED);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert_last_event::<T>(Event::IncludedInCirculating { who }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_parameter_change() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let block_reward: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Parameters { block_reward });
+
+		assert_eq!(PendingParameters::<T>::get().unwrap().parameters.block_reward, block_reward);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_parameter_change() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		PendingParameters::<T>::put(ParameterChange {
+			parameters: Parameters { block_reward: 1_000u32.into() },
+			activates_at: T::ParameterDelay::get(),
+		});
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(!PendingParameters::<T>::exists());
+		Ok(())
+	}
+
+	// Worst case: the caller stakes for the first time, has rewards waiting to be shared out
+	// and paid, and moves its whole free balance.
+	#[benchmark]
+	fn stake() {
+		let amount: T::Balance = 1_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let other: T::AccountId = account("staker", 0, SEED);
+		fund::<T>(&caller, amount);
+		stake_for::<T>(&other, amount);
+		fund::<T>(&T::StakingPot::get(), amount);
+		#[extrinsic_call]
+		stake(RawOrigin::Signed(caller.clone()), amount);
+
+		assert_eq!(Ledgers::<T>::get(&caller).map(|ledger| ledger.active), Some(amount));
+		assert_last_event::<T>(Event::Staked { who: caller, amount }.into());
+	}
+
+	// Worst case: rewards are paid out and the new chunk takes the last free slot.
+	#[benchmark]
+	fn unstake() {
+		let amount: T::Balance = 1_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		stake_for::<T>(&caller, amount);
+		fund::<T>(&T::StakingPot::get(), amount);
+		let chunks = T::MaxUnbondingChunks::get().saturating_sub(1);
+		Ledgers::<T>::mutate(&caller, |ledger| {
+			let ledger = ledger.as_mut().unwrap();
+			for _ in 0..chunks {
+				ledger.unlocking.try_push((Zero::zero(), One::one())).unwrap();
+			}
+		});
+		#[extrinsic_call]
+		unstake(RawOrigin::Signed(caller.clone()), amount);
+
+		assert_eq!(Ledgers::<T>::get(&caller).unwrap().unlocking.len() as u32, chunks + 1);
+	}
+
+	// Worst case: every chunk has matured and the emptied ledger is removed.
+	#[benchmark]
+	fn withdraw_unbonded() {
+		let caller: T::AccountId = whitelisted_caller();
+		stake_for::<T>(&caller, 1_000u32.into());
+		Ledgers::<T>::mutate(&caller, |ledger| {
+			let ledger = ledger.as_mut().unwrap();
+			ledger.active = Zero::zero();
+			for _ in 0..T::MaxUnbondingChunks::get() {
+				ledger.unlocking.try_push((Zero::zero(), One::one())).unwrap();
+			}
+		});
+		TotalStaked::<T>::put(T::Balance::zero());
+		#[extrinsic_call]
+		withdraw_unbonded(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Ledgers::<T>::contains_key(&caller));
+		assert_last_event::<T>(
+			Event::Withdrawn { who: caller, amount: T::MaxUnbondingChunks::get().into() }.into(),
+		);
+	}
+
+	// Worst case: the sender is reaped and the tokens are locked in a not yet existing pot.
+	#[benchmark]
+	fn bridge_out() {
+		let amount: T::Balance = 1_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, amount);
+		limit_outflow::<T>(&caller);
+		let dest_address: BoundedVec<_, _> =
+			sp_std::vec![0u8; T::MaxAddressLen::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		bridge_out(RawOrigin::Signed(caller.clone()), 1, dest_address.clone(), amount);
+
+		assert_last_event::<T>(
+			Event::BridgedOut { nonce: 0, from: caller, dest_chain: 1, dest_address, amount }
+				.into(),
+		);
+	}
+
+	// Worst case: the pot is emptied into a recipient that has never held the token.
+	#[benchmark]
+	fn bridge_in() -> Result<(), BenchmarkError> {
+		let origin =
+			T::BridgeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount: T::Balance = 1_000u32.into();
+		fund::<T>(&T::BridgePot::get(), amount);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, to.clone(), amount);
+
+		assert_last_event::<T>(Event::BridgedIn { nonce: 0, to, amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_relayers(n: Linear<1, { T::MaxRelayers::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let relayers: BoundedVec<T::AccountId, T::MaxRelayers> = (0..n)
+			.map(|i| account("relayer", i, SEED))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, relayers, n);
+
+		assert_last_event::<T>(Event::RelayersSet { threshold: n }.into());
+		Ok(())
+	}
+
+	// Worst case: every relayer must vote, and the last vote releases the whole pot into a
+	// recipient that has never held the token.
+	#[benchmark]
+	fn vote_bridge_in() {
+		let max = T::MaxRelayers::get();
+		let relayers: Vec<T::AccountId> = (0..max).map(|i| account("relayer", i, SEED)).collect();
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount: T::Balance = 1_000u32.into();
+		let last = relayers[max as usize - 1].clone();
+		let voters: BoundedVec<_, _> = relayers[..max as usize - 1].to_vec().try_into().unwrap();
+		Relayers::<T>::put(BoundedVec::try_from(relayers).unwrap());
+		RelayerThreshold::<T>::put(max);
+		InboundVotes::<T>::insert(
+			0,
+			InboundProposal::<T> { to: to.clone(), amount, voters, frozen: false },
+		);
+		fund::<T>(&T::BridgePot::get(), amount);
+		#[extrinsic_call]
+		vote_bridge_in(RawOrigin::Signed(last), 0, to.clone(), amount);
+
+		assert_last_event::<T>(Event::BridgedIn { nonce: 0, to, amount }.into());
+	}
+
+	#[benchmark]
+	fn accept_incoming() {
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		accept_incoming(RawOrigin::Signed(caller.clone()), false);
+
+		assert!(StrictReceivers::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn allow_sender() {
+		let caller: T::AccountId = whitelisted_caller();
+		let sender: T::AccountId = account("sender", 0, SEED);
+		#[extrinsic_call]
+		allow_sender(RawOrigin::Signed(caller.clone()), sender.clone());
+
+		assert!(AllowedSenders::<T>::contains_key((&caller, &sender)));
+	}
+
+	#[benchmark]
+	fn disallow_sender() {
+		let caller: T::AccountId = whitelisted_caller();
+		let sender: T::AccountId = account("sender", 0, SEED);
+		AllowedSenders::<T>::insert((&caller, &sender), ());
+		#[extrinsic_call]
+		disallow_sender(RawOrigin::Signed(caller.clone()), sender.clone());
+
+		assert!(!AllowedSenders::<T>::contains_key((&caller, &sender)));
+	}
+
+	#[benchmark]
+	fn approve_subscription() {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let limit: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		approve_subscription(RawOrigin::Signed(caller.clone()), spender.clone(), limit, One::one());
+
+		assert!(Subscriptions::<T>::contains_key((&caller, &spender)));
+	}
+
+	#[benchmark]
+	fn cancel_subscription() {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		Subscriptions::<T>::insert(
+			(&caller, &spender),
+			Subscription::<T> {
+				limit: 1_000u32.into(),
+				period: One::one(),
+				window_start: Zero::zero(),
+				spent_in_window: Zero::zero(),
+			},
+		);
+		#[extrinsic_call]
+		cancel_subscription(RawOrigin::Signed(caller.clone()), spender.clone());
+
+		assert_last_event::<T>(Event::SubscriptionCancelled { owner: caller, spender }.into());
+	}
+
+	#[benchmark]
+	fn set_transfer_limit() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		let period: BlockNumberFor<T> = One::one();
+		let max_per_period: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), max_per_period, period);
+
+		assert_last_event::<T>(Event::TransferLimitSet { who, max_per_period, period }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_transfer_limit() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		limit_outflow::<T>(&who);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert_last_event::<T>(Event::TransferLimitCleared { who }.into());
+		Ok(())
+	}
+
+	// Worst case: the request takes the last free slot in the payer's queue.
+	#[benchmark]
+	fn request_payment() {
+		let caller: T::AccountId = whitelisted_caller();
+		let payer: T::AccountId = account("payer", 0, SEED);
+		request_others::<T>(&payer, T::MaxPendingRequests::get() - 1);
+		let memo: BoundedVec<_, _> =
+			sp_std::vec![0u8; T::MaxDataLen::get() as usize].try_into().unwrap();
+		let amount: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		request_payment(RawOrigin::Signed(caller.clone()), payer.clone(), amount, memo.clone());
+
+		assert_last_event::<T>(
+			Event::PaymentRequested { id: 0, requester: caller, payer, amount, memo }.into(),
+		);
+	}
+
+	// Worst case: the request is last in a full queue and pays a new account.
+	#[benchmark]
+	fn accept_request() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, 1_000u32.into());
+		request_others::<T>(&caller, T::MaxPendingRequests::get());
+		let last = u64::MAX - (T::MaxPendingRequests::get() - 1) as u64;
+		#[extrinsic_call]
+		accept_request(RawOrigin::Signed(caller.clone()), last);
+
+		assert_last_event::<T>(Event::PaymentRequestAccepted { id: last, payer: caller }.into());
+	}
+
+	#[benchmark]
+	fn reject_request() {
+		let caller: T::AccountId = whitelisted_caller();
+		request_others::<T>(&caller, T::MaxPendingRequests::get());
+		let last = u64::MAX - (T::MaxPendingRequests::get() - 1) as u64;
+		#[extrinsic_call]
+		reject_request(RawOrigin::Signed(caller.clone()), last);
+
+		assert_last_event::<T>(Event::PaymentRequestRejected { id: last, payer: caller }.into());
+	}
+
+	#[benchmark]
+	fn cancel_request() {
+		let payer: T::AccountId = account("payer", 0, SEED);
+		request_others::<T>(&payer, T::MaxPendingRequests::get());
+		let index = T::MaxPendingRequests::get() - 1;
+		let requester: T::AccountId = account("requester", index, SEED);
+		let last = u64::MAX - index as u64;
+		#[extrinsic_call]
+		cancel_request(RawOrigin::Signed(requester), payer.clone(), last);
+
+		assert_last_event::<T>(Event::PaymentRequestCancelled { id: last, payer }.into());
+	}
+
+	// Worst case: a new spender takes the last free slot in the owner's approval index.
+	#[benchmark]
+	fn approve_if() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		fund_deposits::<T>(&caller, 1);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		#[extrinsic_call]
+		approve_if(RawOrigin::Signed(caller.clone()), spender.clone(), Zero::zero(), value);
+
+		assert_eq!(Allowances::<T>::get((T::NativeAsset::get(), &caller, &spender)), Some(value));
+		assert_last_event::<T>(Event::Approval { from: caller, to: spender, value }.into());
+	}
+
+	// Every index points at a funded sub-account and the master has never held the token.
+	#[benchmark]
+	fn sweep(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let value: T::Balance = 1_000u32.into();
+		for index in 0..n {
+			fund::<T>(&Template::<T>::derive_deposit_account(caller.clone(), index), value);
+		}
+		let indices: BoundedVec<_, _> = (0..n).collect::<Vec<_>>().try_into().unwrap();
+		#[extrinsic_call]
+		sweep(RawOrigin::Signed(caller.clone()), indices);
+
+		assert_eq!(Template::<T>::get_balance_of(&caller), Some(value * T::Balance::from(n)));
+		assert_last_event::<T>(Event::Swept { master: caller, index: n - 1, value }.into());
+	}
+
+	// Worst case: a new scoped entry takes the last free slot of the owner's approvals and
+	// reserves its deposit.
+	#[benchmark]
+	fn approve_scoped() {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let purpose = [1; 8];
+		let value: T::Balance = 1_000u32.into();
+		fund_deposits::<T>(&caller, 1);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		#[extrinsic_call]
+		approve_scoped(RawOrigin::Signed(caller.clone()), spender.clone(), purpose, value);
+
+		assert_eq!(ScopedApprovalCount::<T>::get(&caller), 1);
+		assert_last_event::<T>(
+			Event::ScopedApproval { owner: caller, spender, purpose, value }.into(),
+		);
+	}
+
+	// Worst case: as for `transfer_from`, spending the scoped entry down to zero, which
+	// removes it and returns its deposit.
+	#[benchmark]
+	fn transfer_from_scoped() {
+		let value: T::Balance = 1_000_000u32.into();
+		let spender: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let to: T::AccountId = account("to", 1, SEED);
+		let purpose = [1; 8];
+		fund::<T>(&owner, value);
+		limit_outflow::<T>(&owner);
+		fund_deposits::<T>(&owner, 1);
+		T::Currency::reserve(&owner, T::AllowanceDeposit::get()).unwrap();
+		ScopedAllowances::<T>::insert(
+			(&owner, &spender, purpose),
+			(value, T::AllowanceDeposit::get()),
+		);
+		ScopedApprovalCount::<T>::insert(&owner, 1);
+		#[extrinsic_call]
+		transfer_from_scoped(
+			RawOrigin::Signed(spender.clone()),
+			owner.clone(),
+			purpose,
+			to.clone(),
+			value,
+		);
+
+		assert_eq!(Template::<T>::get_balance_of(&to), Some(value));
+		assert!(!ScopedApprovalCount::<T>::contains_key(&owner));
+		assert_last_event::<T>(Event::ScopedTransfer { owner, spender, purpose, to, value }.into());
+	}
+
+	// Worst case: the caller holds every kind of state that moves, with a full approval index
+	// whose deposits are re-reserved from the new account, and `n` owners approved it.
+	#[benchmark]
+	fn migrate_account(n: Linear<0, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let new: T::AccountId = account("new", 0, SEED);
+		let max = T::MaxApprovalsPerOwner::get();
+		let balance: T::Balance = 1_000u32.into();
+		fund::<T>(&caller, balance);
+		approve_others::<T>(&caller, max);
+		fund_deposits::<T>(&caller, max);
+		fund_deposits::<T>(&new, max);
+		for spender in Approvals::<T>::get(&caller) {
+			T::Currency::reserve(&caller, T::AllowanceDeposit::get()).unwrap();
+			AllowanceDeposits::<T>::insert((&caller, spender), T::AllowanceDeposit::get());
+		}
+		let approvers: BoundedVec<_, _> = (0..n)
+			.map(|i| {
+				let owner: T::AccountId = account("owner", i, SEED);
+				Allowances::<T>::insert(
+					(T::NativeAsset::get(), &owner, &caller),
+					T::Balance::one(),
+				);
+				Approvals::<T>::mutate(&owner, |spenders| {
+					spenders.try_push(caller.clone()).unwrap()
+				});
+				AllowanceDeposits::<T>::insert((&owner, &caller), T::AllowanceDeposit::get());
+				owner
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		stake_for::<T>(&caller, balance);
+		limit_outflow::<T>(&caller);
+		FreeTransfers::<T>::insert(&caller, (BlockNumberFor::<T>::zero(), 1));
+		request_others::<T>(&caller, T::MaxPendingRequests::get());
+		#[extrinsic_call]
+		migrate_account(RawOrigin::Signed(caller.clone()), new.clone(), approvers);
+
+		assert_eq!(Template::<T>::get_balance_of(&new), Some(balance));
+		assert_eq!(Approvals::<T>::get(&new).len(), max as usize);
+		assert!(!Ledgers::<T>::contains_key(&caller));
+		assert_last_event::<T>(Event::AccountMigrated { old: caller, new }.into());
+	}
+
+	#[benchmark]
+	fn attest_transfer() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ComplianceOracle::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let from: T::AccountId = account("from", 0, SEED);
+		let to: T::AccountId = account("to", 1, SEED);
+		let expiry = frame_system::Pallet::<T>::block_number();
+		let value: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, from.clone(), to.clone(), value, expiry);
+
+		assert_last_event::<T>(Event::TransferAttested { from, to, value, expiry }.into());
+		Ok(())
+	}
+
+	// Worst case: the owner has more history than one call removes.
+	#[benchmark]
+	fn prune_spent_by() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		for i in 0..=T::MaxBatchSize::get() {
+			SpentBy::<T>::insert(
+				&owner,
+				account::<T::AccountId>("spender", i, SEED),
+				T::Balance::one(),
+			);
+		}
+		#[extrinsic_call]
+		prune_spent_by(RawOrigin::Signed(caller), owner.clone());
+
+		assert_eq!(SpentBy::<T>::iter_prefix(&owner).count(), 1);
+	}
+
+	// Every id is in use and old enough to be pruned.
+	#[benchmark]
+	fn prune_transfer_ids(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let ids: BoundedVec<_, _> =
+			(0..n).map(|i| [i as u8; 16]).collect::<Vec<_>>().try_into().unwrap();
+		for id in ids.iter() {
+			UsedTransferIds::<T>::insert(&sender, id, BlockNumberFor::<T>::zero());
+		}
+		frame_system::Pallet::<T>::set_block_number(T::TransferIdRetention::get());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), sender.clone(), ids);
+
+		assert_eq!(UsedTransferIds::<T>::iter_prefix(&sender).count(), 0);
+	}
+
+	#[benchmark]
+	fn freeze_amount() -> Result<(), BenchmarkError> {
+		let origin =
+			T::FreezeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount: T::Balance = 1_000u32.into();
+		FrozenBalance::<T>::insert(&who, amount);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), amount);
+
+		assert_last_event::<T>(Event::AmountFrozen { who, amount, frozen: amount + amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unfreeze_amount() -> Result<(), BenchmarkError> {
+		let origin =
+			T::FreezeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		let amount: T::Balance = 1_000u32.into();
+		FrozenBalance::<T>::insert(&who, amount + amount);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), amount);
+
+		assert_last_event::<T>(Event::AmountUnfrozen { who, amount, frozen: amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_savings_mode() {
+		let caller: T::AccountId = whitelisted_caller();
+		let lock_blocks = Some(One::one());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), lock_blocks);
+
+		assert_last_event::<T>(Event::SavingsModeSet { who: caller, lock_blocks }.into());
+	}
+
+	// Worst case: every tranche but the last has matured, so the rest is written back.
+	#[benchmark]
+	fn release_matured() {
+		let caller: T::AccountId = whitelisted_caller();
+		let max = T::MaxSavingTranches::get();
+		let value: T::Balance = 1_000u32.into();
+		let tranches: BoundedVec<(BlockNumberFor<T>, T::Balance), _> =
+			(1..=max).map(|i| (i.into(), value)).collect::<Vec<_>>().try_into().unwrap();
+		SavingTranches::<T>::insert(&caller, tranches);
+		frame_system::Pallet::<T>::set_block_number(max.saturating_sub(1).into());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(SavingTranches::<T>::get(&caller).len(), 1);
+		assert_last_event::<T>(
+			Event::SavingsReleased { who: caller, value: value * T::Balance::from(max - 1) }.into(),
+		);
+	}
+
+	// Every spender is new to the owner, so each entry grows the approval index.
+	#[benchmark]
+	fn batch_approve(n: Linear<1, { T::MaxBatchSize::get().min(T::MaxApprovalsPerOwner::get()) }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let value: T::Balance = 1_000u32.into();
+		fund_deposits::<T>(&caller, n);
+		let approvals: BoundedVec<_, _> = (0..n)
+			.map(|i| (account::<T::AccountId>("spender", i, SEED), value))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let last = approvals[n as usize - 1].0.clone();
+		#[extrinsic_call]
+		batch_approve(RawOrigin::Signed(caller.clone()), approvals);
+
+		assert_eq!(Approvals::<T>::get(&caller).len(), n as usize);
+		assert_last_event::<T>(Event::Approval { from: caller, to: last, value }.into());
+	}
+
+	// Worst case: the history is full, so the oldest entry is dropped, and the references are as
+	// long as they get.
+	#[benchmark]
+	fn post_reserve_attestation() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ReserveOracle::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reference: BoundedVec<_, _> =
+			vec![0; T::MaxDataLen::get() as usize].try_into().unwrap();
+		let attestation = ReserveAttestation {
+			reserves: Zero::zero(),
+			as_of: BlockNumberFor::<T>::zero(),
+			reference: reference.clone(),
+		};
+		let history: BoundedVec<_, _> =
+			vec![attestation.clone(); T::MaxReserveHistory::get() as usize]
+				.try_into()
+				.unwrap();
+		ReserveAttestationHistory::<T>::put(history);
+		LatestReserveAttestation::<T>::put(attestation);
+		let as_of = frame_system::Pallet::<T>::block_number();
+		let reserves: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reserves, as_of, reference);
+
+		assert_last_event::<T>(Event::ReservesAttested { reserves, as_of }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_claims_root() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let total: T::Balance = 1_000u32.into();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, [1; 32], total);
+
+		assert_last_event::<T>(Event::ClaimsRootSet { root: [1; 32], total }.into());
+		Ok(())
+	}
+
+	// Worst case: the proof is as deep as it can be. The Ethereum key is generated in the
+	// keystore, so the benchmark externalities must register one.
+	#[benchmark]
+	fn claim(p: Linear<0, { T::MaxClaimProofLen::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let key_type = sp_runtime::KeyTypeId(*b"clam");
+		let key = sp_io::crypto::ecdsa_generate(key_type, None);
+		let hash = sp_io::hashing::keccak_256(&Template::<T>::claim_message(&caller));
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &key, &hash).unwrap();
+		let mut eth_signature = [0u8; 65];
+		eth_signature.copy_from_slice(signature.as_ref());
+		let public = sp_io::crypto::secp256k1_ecdsa_recover(&eth_signature, &hash).unwrap();
+		let mut eth_address = EthereumAddress::default();
+		eth_address.copy_from_slice(&sp_io::hashing::keccak_256(&public)[12..]);
+		let amount = T::MintAnnouncementThreshold::get().min(T::MaxMintPerBlock::get());
+		let proof: BoundedVec<_, _> =
+			(0..p).map(|i| [i as u8; 32]).collect::<Vec<_>>().try_into().unwrap();
+		let leaf = Template::<T>::claim_leaf(&eth_address, amount);
+		ClaimsRoot::<T>::put(Template::<T>::claims_root_of(leaf, &proof));
+		ClaimsLeft::<T>::put(amount);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), eth_address, amount, proof, eth_signature);
+
+		assert_last_event::<T>(Event::Claimed { who: caller, eth_address, amount }.into());
+	}
+
+	// Worst case: the permit creates a new allowance, reserving its deposit and growing the
+	// owner's approvals index.
+	#[benchmark]
+	fn permit_unsigned() {
+		let owner = T::BenchmarkHelper::signer();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		fund_deposits::<T>(&owner, 1);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let value: T::Balance = 1_000u32.into();
+		let payload =
+			Template::<T>::payload_for_permit(owner.clone(), spender.clone(), value, deadline);
+		let signature = T::BenchmarkHelper::sign(&owner, &payload);
+		#[extrinsic_call]
+		_(RawOrigin::None, owner.clone(), spender.clone(), value, deadline, signature);
+
+		assert_eq!(Nonces::<T>::get(&owner), 1);
+		assert_last_event::<T>(Event::Approval { from: owner, to: spender, value }.into());
+	}
+
+	// Worst case: the asset does not exist yet, so it is created.
+	#[benchmark]
+	fn migrate_to_assets() {
+		let asset = AssetIdOf::<T>::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset);
+
+		assert!(AssetMigration::<T>::exists());
+		assert_last_event::<T>(Event::AssetMigrationStarted { asset }.into());
+	}
+
+	#[benchmark]
+	fn initiate_sunset() {
+		let pot: T::AccountId = account("pot", 0, SEED);
+		let destination = SunsetDestination::Refund(pot);
+		#[extrinsic_call]
+		_(RawOrigin::Root, destination.clone());
+
+		assert!(Sunset::<T>::exists());
+		assert_last_event::<T>(Event::SunsetInitiated { destination }.into());
+	}
+
+	// Worst case: the allowance is new, so it reserves a deposit and takes the last free slot
+	// in the owner's approval index.
+	#[benchmark]
+	fn increase_allowance() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		fund_deposits::<T>(&caller, 1);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), spender.clone(), value);
+
+		assert_eq!(Allowances::<T>::get((T::NativeAsset::get(), &caller, &spender)), Some(value));
+		assert_last_event::<T>(
+			Event::AllowanceChanged { owner: caller, spender, allowance: value }.into(),
+		);
+	}
+
+	// Worst case: the allowance is lowered to zero, which removes it from a full approval index
+	// and returns its deposit.
+	#[benchmark]
+	fn decrease_allowance() {
+		let value: T::Balance = 1_000_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		Allowances::<T>::insert((T::NativeAsset::get(), &caller, &spender), value);
+		Approvals::<T>::mutate(&caller, |spenders| spenders.try_push(spender.clone()).unwrap());
+		fund_deposits::<T>(&caller, 1);
+		T::Currency::reserve(&caller, T::AllowanceDeposit::get()).unwrap();
+		AllowanceDeposits::<T>::insert((&caller, &spender), T::AllowanceDeposit::get());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), spender.clone(), value);
+
+		assert!(!Allowances::<T>::contains_key((T::NativeAsset::get(), &caller, &spender)));
+		assert_last_event::<T>(
+			Event::AllowanceChanged { owner: caller, spender, allowance: Zero::zero() }.into(),
+		);
+	}
+
+	// Worst case: name and symbol are as long as `StringLimit` allows.
+	#[benchmark]
+	fn set_metadata() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let text: BoundedVec<u8, T::StringLimit> =
+			sp_std::vec![b'a'; T::StringLimit::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, text.clone(), text.clone(), 18);
+
+		assert_eq!(Template::<T>::metadata().decimals, 18);
+		assert_last_event::<T>(
+			Event::MetadataSet { name: text.clone(), symbol: text, decimals: 18 }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset = launched_id::<T>();
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let min_balance = One::one();
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset, T::Lookup::unlookup(admin.clone()), min_balance);
+
+		assert!(Asset::<T>::contains_key(asset));
+		assert_last_event::<T>(Event::AssetCreated { asset, admin, min_balance }.into());
+		Ok(())
+	}
+
+	// Worst case: the recipient holds none of the token yet.
+	#[benchmark]
+	fn mint_asset() {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = launch::<T>(&caller);
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount: T::Balance = 1_000_000u32.into();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset, T::Lookup::unlookup(to.clone()), amount);
+
+		assert_eq!(Template::<T>::asset_balance(asset, &to), amount);
+		assert_last_event::<T>(Event::AssetIssued { asset, to, amount }.into());
+	}
+
+	// Worst case: the whole balance is burned, so its entry is removed.
+	#[benchmark]
+	fn burn_asset() {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = launch::<T>(&caller);
+		let from: T::AccountId = account("from", 0, SEED);
+		let amount: T::Balance = 1_000_000u32.into();
+		BalanceOf::<T>::insert(asset, &from, amount);
+		TotalSupply::<T>::insert(asset, amount);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset, T::Lookup::unlookup(from.clone()), amount);
+
+		assert!(!BalanceOf::<T>::contains_key(asset, &from));
+		assert_last_event::<T>(Event::AssetBurned { asset, from, amount }.into());
+	}
+
+	// Worst case: the recipient holds none of the token yet.
+	#[benchmark]
+	fn transfer_asset() {
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let asset = launch::<T>(&admin);
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let value: T::Balance = 1_000_000u32.into();
+		BalanceOf::<T>::insert(asset, &caller, value + value);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset, T::Lookup::unlookup(to.clone()), value);
+
+		assert_eq!(Template::<T>::asset_balance(asset, &to), value);
+		assert_last_event::<T>(Event::AssetTransferred { asset, from: caller, to, value }.into());
+	}
+
+	// Worst case: a new allowance that takes a deposit and the last free approval slot.
+	#[benchmark]
+	fn approve_asset() {
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let asset = launch::<T>(&admin);
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let value: T::Balance = 1_000_000u32.into();
+		fund_deposits::<T>(&caller, 1);
+		approve_others::<T>(&caller, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset, T::Lookup::unlookup(spender.clone()), value);
+
+		assert_eq!(Allowances::<T>::get((asset, &caller, &spender)), Some(value));
+		assert_eq!(AssetApprovalCount::<T>::get(&caller), 1);
+		assert_last_event::<T>(
+			Event::AssetApproval { asset, owner: caller, spender, value }.into(),
+		);
+	}
+
+	// Worst case: the recipient holds none of the token yet and the allowance is used up, which
+	// returns its deposit.
+	#[benchmark]
+	fn transfer_asset_from() {
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let asset = launch::<T>(&admin);
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let value: T::Balance = 1_000_000u32.into();
+		BalanceOf::<T>::insert(asset, &owner, value + value);
+		Allowances::<T>::insert((asset, &owner, &caller), value);
+		AssetApprovalCount::<T>::insert(&owner, 1);
+		fund_deposits::<T>(&owner, 1);
+		T::Currency::reserve(&owner, T::AllowanceDeposit::get()).unwrap();
+		AssetAllowanceDeposits::<T>::insert((asset, &owner, &caller), T::AllowanceDeposit::get());
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset,
+			T::Lookup::unlookup(owner.clone()),
+			T::Lookup::unlookup(to.clone()),
+			value,
+		);
+
+		assert!(!Allowances::<T>::contains_key((asset, &owner, &caller)));
+		assert!(!AssetApprovalCount::<T>::contains_key(&owner));
+		assert_eq!(Template::<T>::asset_balance(asset, &to), value);
+		assert_last_event::<T>(
+			Event::AssetApproval { asset, owner, spender: caller, value: Zero::zero() }.into(),
+		);
+	}
+
+	// Worst case: name and symbol are as long as `StringLimit` allows.
+	#[benchmark]
+	fn set_asset_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = launch::<T>(&caller);
+		let text: BoundedVec<u8, T::StringLimit> =
+			sp_std::vec![b'a'; T::StringLimit::get() as usize].try_into().unwrap();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset, text.clone(), text.clone(), 18);
+
+		assert_eq!(Metadata::<T>::get(asset).decimals, 18);
+		assert_last_event::<T>(
+			Event::AssetMetadataSet { asset, name: text.clone(), symbol: text, decimals: 18 }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn pause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(Paused::<T>::get());
+		assert_last_event::<T>(Event::Paused.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unpause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Paused::<T>::put(true);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(!Paused::<T>::get());
+		assert_last_event::<T>(Event::Unpaused.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_account() -> Result<(), BenchmarkError> {
+		let origin =
+			T::FreezeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(Frozen::<T>::contains_key(&who));
+		assert_last_event::<T>(Event::AccountFrozen { who }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_account() -> Result<(), BenchmarkError> {
+		let origin =
+			T::FreezeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("who", 0, SEED);
+		Frozen::<T>::insert(&who, ());
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!Frozen::<T>::contains_key(&who));
+		assert_last_event::<T>(Event::AccountThawed { who }.into());
+		Ok(())
+	}
+
+	// Worst case: as for `permit_unsigned`.
+	#[benchmark]
+	fn permit() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = T::BenchmarkHelper::signer();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		fund_deposits::<T>(&owner, 1);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let value: T::Balance = 1_000u32.into();
+		let payload =
+			Template::<T>::payload_for_permit(owner.clone(), spender.clone(), value, deadline);
+		let signature = T::BenchmarkHelper::sign(&owner, &payload);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner.clone(), spender.clone(), value, deadline, signature);
+
+		assert_eq!(Nonces::<T>::get(&owner), 1);
+		assert_last_event::<T>(Event::Approval { from: owner, to: spender, value }.into());
+	}
+
+	// Worst case: as for `transfer`, once per recipient, none of which has held the token.
+	#[benchmark]
+	fn transfer_batch(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let value: T::Balance = 1_000u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, value * n.into());
+		limit_outflow::<T>(&caller);
+		let recipients: BoundedVec<_, _> = (0..n)
+			.map(|i| (account::<T::AccountId>("to", i, SEED), value))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), recipients, BatchMode::AllOrNothing);
+
+		let total = value * n.into();
+		assert_last_event::<T>(Event::BatchTransfer { from: caller, count: n, total }.into());
+	}
+
+	// Worst case: as for `transfer`, into the last free vesting slot of the recipient.
+	#[benchmark]
+	fn transfer_with_vesting() {
+		let value: T::Balance = 1_000_000u32.into();
+		let per_block: T::Balance = One::one();
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		fund::<T>(&caller, value);
+		limit_outflow::<T>(&caller);
+		let schedule = VestingSchedule::<T> {
+			locked: One::one(),
+			per_block,
+			starting_block: Bounded::max_value(),
+		};
+		let schedules: BoundedVec<_, _> =
+			sp_std::vec![schedule; T::MaxVestingSchedules::get() as usize - 1]
+				.try_into()
+				.unwrap();
+		VestingSchedules::<T>::insert(&to, schedules);
+		let starting_block = frame_system::Pallet::<T>::block_number();
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(to.clone()),
+			value,
+			per_block,
+			starting_block,
+		);
+
+		assert_eq!(VestingSchedules::<T>::get(&to).len() as u32, T::MaxVestingSchedules::get());
+		assert_last_event::<T>(
+			Event::VestingScheduleAdded {
+				from: caller,
+				to,
+				locked: value,
+				per_block,
+				starting_block,
+			}
+			.into(),
+		);
+	}
+
+	// Worst case: every schedule slot is taken and none is done yet.
+	#[benchmark]
+	fn vest() {
 		let caller: T::AccountId = whitelisted_caller();
+		let locked: T::Balance = 1_000u32.into();
+		let schedule = VestingSchedule::<T> {
+			locked,
+			per_block: One::one(),
+			starting_block: frame_system::Pallet::<T>::block_number(),
+		};
+		let count = T::MaxVestingSchedules::get();
+		let schedules: BoundedVec<_, _> =
+			sp_std::vec![schedule; count as usize].try_into().unwrap();
+		VestingSchedules::<T>::insert(&caller, schedules);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + One::one(),
+		);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		let left = (locked - One::one()) * count.into();
+		assert_last_event::<T>(Event::Vested { who: caller, locked: left }.into());
+	}
+
+	#[benchmark]
+	fn set_max_supply() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let cap: T::Balance = 1_000_000u32.into();
+		TotalSupply::<T>::insert(T::NativeAsset::get(), cap);
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		_(origin as T::RuntimeOrigin, cap);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(MaxSupply::<T>::get(), Some(cap));
+		assert_last_event::<T>(Event::SupplyCapSet { cap }.into());
+		Ok(())
 	}
 
+	// Worst case: the whole allowance is used up, releasing its deposit, and the whole balance
+	// is burned, reaping the owner.
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn burn_from() {
+		let value: T::Balance = 1_000_000u32.into();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let spender: T::AccountId = whitelisted_caller();
+		fund::<T>(&owner, value);
+		approve_others::<T>(&owner, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		Allowances::<T>::insert((T::NativeAsset::get(), &owner, &spender), value);
+		Approvals::<T>::mutate(&owner, |spenders| spenders.try_push(spender.clone()).unwrap());
+		fund_deposits::<T>(&owner, 1);
+		T::Currency::reserve(&owner, T::AllowanceDeposit::get()).unwrap();
+		AllowanceDeposits::<T>::insert((&owner, &spender), T::AllowanceDeposit::get());
+		#[extrinsic_call]
+		_(RawOrigin::Signed(spender.clone()), owner.clone(), value);
+
+		assert_eq!(Template::<T>::get_balance_of(&owner), None);
+		assert_eq!(Allowances::<T>::get((T::NativeAsset::get(), &owner, &spender)), None);
+		assert_last_event::<T>(Event::BurnedFrom { owner, spender, value }.into());
+	}
+
+	// Every account holds just under `MinBalance` and is reaped, its dust burned.
+	#[benchmark]
+	fn sweep_dust(n: Linear<1, { T::MaxBatchSize::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
+		let dust = T::MinBalance::get().saturating_sub(One::one()).max(One::one());
+		let accounts: BoundedVec<_, _> = (0..n)
+			.map(|i| account::<T::AccountId>("dust", i, SEED))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		for who in &accounts {
+			fund::<T>(who, dust);
+		}
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), accounts.clone());
+
+		// With no minimum configured there is nothing to reap.
+		if dust < T::MinBalance::get() {
+			assert!(accounts.iter().all(|who| Template::<T>::get_balance_of(who).is_none()));
+		}
+	}
+
+	// Worst case: the expired allowance holds a deposit and is the last entry of a full
+	// approval index. Measured through `on_idle`, so it also covers finding the entry.
+	#[benchmark]
+	fn expire_allowance() -> Result<(), BenchmarkError> {
+		let value: T::Balance = 1_000_000u32.into();
+		let owner: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		fund_deposits::<T>(&owner, 1);
+		approve_others::<T>(&owner, T::MaxApprovalsPerOwner::get().saturating_sub(1));
+		Template::<T>::approve(
+			RawOrigin::Signed(owner.clone()).into(),
+			T::Lookup::unlookup(spender.clone()),
+			value,
+			Some(deadline),
+		)?;
+		let now = deadline.saturating_add(One::one());
+		frame_system::Pallet::<T>::set_block_number(now);
+		#[block]
+		{
+			Template::<T>::on_idle(now, Weight::MAX);
+		}
+
+		assert_eq!(Allowances::<T>::get((T::NativeAsset::get(), &owner, &spender)), None);
+		assert_eq!(AllowanceSweepCursor::<T>::get(), Some(now));
+		assert_last_event::<T>(Event::AllowanceExpired { owner, spender }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fee_rate() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let rate = FixedU128::saturating_from_rational(3, 2);
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(origin as T::RuntimeOrigin, rate);
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(TokensPerNativeUnit::<T>::get(), rate);
+		assert_last_event::<T>(Event::FeeRateSet { rate }.into());
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,99 @@
+//! Transaction extensions the runtime adds to its `SignedExtra`.
+use crate::{Call, Config, Frozen};
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension, StaticLookup},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::marker::PhantomData;
+
+/// `InvalidTransaction::Custom` code of a transaction [`CheckNotFrozen`] turned away.
+pub const ACCOUNT_FROZEN: u8 = 1;
+
+/// Keeps transactions of frozen accounts out of the pool and out of blocks.
+///
+/// Any call to the pallet signed by an account in `Frozen` is invalid, as is a direct transfer
+/// to one. Dispatch still checks both, so calls wrapped in other pallets' calls, such as a
+/// utility batch, fail with `AccountFrozen` when they run.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckNotFrozen<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckNotFrozen<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// The account `call` pays out to, for the calls that name one.
+	fn recipient(call: &Call<T>) -> Option<T::AccountId> {
+		match call {
+			Call::transfer { to, .. } |
+			Call::transfer_with_id { to, .. } |
+			Call::transfer_from { to, .. } |
+			Call::transfer_with_vesting { to, .. } |
+			Call::transfer_asset { to, .. } |
+			Call::transfer_asset_from { to, .. } => T::Lookup::lookup(to.clone()).ok(),
+			Call::send { to, .. } |
+			Call::spend_all_allowance { to, .. } |
+			Call::transfer_from_scoped { to, .. } => Some(to.clone()),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckNotFrozen<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckNotFrozen<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckNotFrozen")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckNotFrozen<T>
+where
+	T::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckNotFrozen";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let Some(call) = call.is_sub_type() else { return Ok(ValidTransaction::default()) };
+		if Frozen::<T>::contains_key(who) ||
+			Self::recipient(call).map_or(false, |to| Frozen::<T>::contains_key(to))
+		{
+			return InvalidTransaction::Custom(ACCOUNT_FROZEN).into()
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
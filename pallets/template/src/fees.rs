@@ -0,0 +1,87 @@
+//! Paying transaction fees in the token.
+use crate::{Config, Pallet, TokensPerNativeUnit};
+use codec::FullCodec;
+use pallet_transaction_payment::{CurrencyAdapter, OnChargeTransaction};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, DispatchInfoOf, MaybeSerializeDeserialize, PostDispatchInfoOf},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	FixedPointNumber, FixedPointOperand,
+};
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+type TokenAdapter<T> = CurrencyAdapter<Pallet<T>, ()>;
+
+/// `OnChargeTransaction` that takes fees from the payer's token balance instead of the native
+/// currency. Like native fees under `CurrencyAdapter<Balances, ()>`, they are burned.
+///
+/// Fees are still computed in `NativeBalance`, so the fee schedule and the payment runtime API
+/// keep native units. Each fee is converted at `TokensPerNativeUnit` when it is charged.
+pub struct TokenFees<T, NativeBalance>(PhantomData<(T, NativeBalance)>);
+
+impl<T, NativeBalance> TokenFees<T, NativeBalance>
+where
+	T: Config,
+	NativeBalance: AtLeast32BitUnsigned + FixedPointOperand,
+{
+	/// `amount` of native fee in tokens. Amounts too large for the token once converted
+	/// cannot be paid.
+	pub fn in_tokens(amount: NativeBalance) -> Result<T::Balance, TransactionValidityError> {
+		<TokensPerNativeUnit<T>>::get()
+			.checked_mul_int(amount)
+			.and_then(|tokens| tokens.try_into().ok())
+			.and_then(|tokens: u128| tokens.try_into().ok())
+			.ok_or_else(|| InvalidTransaction::Payment.into())
+	}
+}
+
+impl<T, NativeBalance> OnChargeTransaction<T> for TokenFees<T, NativeBalance>
+where
+	T: Config + pallet_transaction_payment::Config,
+	TokenAdapter<T>: OnChargeTransaction<T, Balance = T::Balance>,
+	NativeBalance: AtLeast32BitUnsigned
+		+ FixedPointOperand
+		+ FullCodec
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ Default
+		+ TypeInfo,
+{
+	type Balance = NativeBalance;
+	type LiquidityInfo = <TokenAdapter<T> as OnChargeTransaction<T>>::LiquidityInfo;
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		fee: NativeBalance,
+		tip: NativeBalance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		<TokenAdapter<T> as OnChargeTransaction<T>>::withdraw_fee(
+			who,
+			call,
+			info,
+			Self::in_tokens(fee)?,
+			Self::in_tokens(tip)?,
+		)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		corrected_fee: NativeBalance,
+		tip: NativeBalance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		<TokenAdapter<T> as OnChargeTransaction<T>>::correct_and_deposit_fee(
+			who,
+			info,
+			post_info,
+			Self::in_tokens(corrected_fee)?,
+			Self::in_tokens(tip)?,
+			already_withdrawn,
+		)
+	}
+}
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -10,16 +12,126 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod extensions;
+pub mod fees;
+pub mod migrations;
+pub mod traits;
 pub mod weights;
 pub use weights::*;
 
+pub use extensions::CheckNotFrozen;
+
+/// Maximum number of accounts answered by a single [`Pallet::balances_of`] query.
+pub const MAX_BALANCES_QUERY: usize = 1_000;
+
+/// Maximum number of entries returned by a single [`Pallet::holders`] page.
+pub const MAX_HOLDERS_PAGE: u32 = 1_000;
+
+/// Persistent offchain local storage key holding the URL token metrics are POSTed to.
+///
+/// Nodes opt in to publishing metrics by setting it, e.g. through the
+/// `offchain_localStorageSet` RPC; the offchain worker does nothing while it is unset.
+pub const METRICS_ENDPOINT_KEY: &[u8] = b"template::metrics-endpoint";
+
+/// Prefix of the offchain storage keys [`TransferRecord`]s are indexed under.
+pub const TRANSFER_RECORD_PREFIX: &[u8] = b"template::transfer";
+
+/// How long the offchain worker waits for the metrics endpoint to answer.
+const METRICS_HTTP_TIMEOUT_MS: u64 = 2_000;
+
+const LOG_TARGET: &str = "runtime::template";
+
+/// Fixed-point scale of `RewardPerShare`.
+const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Writes each transfer of a `transfer_batch` makes in its benchmark.
+const BATCH_LEG_WRITES: u64 = 5;
+
+/// What a scoped allowance may be spent on, as agreed between owner and spender.
+pub type Purpose = [u8; 8];
+
+/// The purpose of the plain allowances `approve_if` and `transfer_from` work with.
+pub const DEFAULT_PURPOSE: Purpose = [0; 8];
+
+/// Wrapping some wallets put around raw bytes before signing them, so that what they sign can
+/// never be a valid transaction.
+const WRAPPED_BYTES_PREFIX: &[u8] = b"<Bytes>";
+const WRAPPED_BYTES_POSTFIX: &[u8] = b"</Bytes>";
+
+/// Client-chosen identifier that makes a `transfer_with_id` safe to retry.
+pub type TransferId = [u8; 16];
+
+/// Key of a holder in `TopHolders`: its balance as big-endian bytes, then the account.
+pub type HolderRank<AccountId> = ([u8; 16], AccountId);
+
+/// An Ethereum address: the last 20 bytes of the Keccak-256 hash of the account's public key.
+pub type EthereumAddress = [u8; 20];
+
+/// A recoverable secp256k1 signature as Ethereum wallets produce it: `r`, `s`, then `v`.
+pub type EcdsaSignature = [u8; 65];
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use codec::{Codec, EncodeLike};
+	use frame_support::{
+		dispatch::WithPostDispatchInfo,
+		pallet_prelude::*,
+		storage::{transactional, with_storage_layer, with_transaction, TransactionOutcome},
+		traits::{
+			tokens::{
+				fungible,
+				fungibles::{self, approvals::Mutate as _, Create as _, Inspect as _, Mutate as _},
+				DepositConsequence, Fortitude, Pay, PaymentStatus, Precision, Preservation,
+				Provenance, WithdrawConsequence,
+			},
+			BalanceStatus, Contains, Currency, ExistenceRequirement, Imbalance,
+			NamedReservableCurrency, ReservableCurrency, SameOrOther, SignedImbalance, TryDrop,
+			TryMerge, WithdrawReasons,
+		},
+		PalletId,
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_io::{
+		crypto::secp256k1_ecdsa_recover,
+		hashing::{blake2_256, keccak_256},
+	};
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		offchain::{http, Duration, StorageKind},
+		traits::{
+			AccountIdConversion, AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedSub,
+			IdentifyAccount, One, Saturating, StaticLookup, TrailingZeroInput, Verify, Zero,
+		},
+		FixedPointOperand, FixedU128, PerThing, Perbill, Rounding, SaturatedConversion,
+	};
+	use sp_std::{mem, vec::Vec};
+	use traits::{
+		KycLimit, KycTier, KycTierOf, OnSlash, OnTokenTransfer, OnTokensReceived, SlashHandler,
+	};
+
+	/// Balance of the native currency allowance deposits are reserved in.
+	pub type DepositBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// An address `T::Lookup` resolves to an account, such as a `MultiAddress`.
+	pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+	/// Identifier of an asset in `T::Assets`.
+	pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+
+	/// Balance of an asset in `T::Assets`.
+	pub type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -29,19 +141,1233 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
+		/// The balance type of the token, for every balance, allowance and supply.
+		type Balance: Parameter
+			+ Member
+			+ AtLeast32BitUnsigned
+			+ Codec
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ FixedPointOperand;
+		/// Identifier of a token: the pallet's own at `NativeAsset`, or one launched with
+		/// `create_asset`.
+		type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+		/// Id the pallet's own token is kept under. Every call without an asset id works on it,
+		/// and only it has staking, bridging, savings and the pallet's other features.
+		#[pallet::constant]
+		type NativeAsset: Get<Self::AssetId>;
+		/// Maximum number of spenders a single owner may have approved at once.
+		#[pallet::constant]
+		type MaxApprovalsPerOwner: Get<u32>;
+		/// Maximum number of delegates a spender may carve parts of a single allowance out for.
+		#[pallet::constant]
+		type MaxDelegations: Get<u32>;
+		/// Legacy allowance entries moved into the new layout per block while the v3 re-keying
+		/// runs. Must be non-zero.
+		#[pallet::constant]
+		type AllowanceMigrationBatch: Get<u32>;
+		/// Number of recent blocks whose transfer volume is kept in `RecentVolume`. Zero disables
+		/// the per-block history.
+		#[pallet::constant]
+		type StatsWindow: Get<u32>;
+		/// The offchain worker publishes token metrics every `MetricsInterval` blocks. Zero
+		/// disables publishing.
+		#[pallet::constant]
+		type MetricsInterval: Get<u32>;
+		/// Length in blocks of a supply tracking period. `TotalSupply` is checkpointed into
+		/// `SupplyHistory` at the start of every period; zero disables tracking.
+		#[pallet::constant]
+		type SupplyTrackingPeriod: Get<u32>;
+		/// Number of most recent periods kept in `SupplyHistory`.
+		#[pallet::constant]
+		type MaxSupplyHistory: Get<u32>;
+		/// Identifier mixed into the derivation of deposit sub-accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Maximum number of entries a single batch call may carry. At most `u16::MAX`, so an
+		/// index into a batch fits the errors that report one.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Token name committed to by [`Pallet::domain_separator`].
+		type DomainName: Get<&'static [u8]>;
+		/// Version committed to by [`Pallet::domain_separator`]. Bumping it in a runtime upgrade
+		/// invalidates every signature made against the previous version.
+		#[pallet::constant]
+		type DomainVersion: Get<u32>;
+		/// Signature wallets make over signed token payloads, such as `MultiSignature` to accept
+		/// sr25519, ed25519 and ecdsa keys alike.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+		/// Key an `OffchainSignature` is made with, identifying the account that signed.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Priority of `permit_unsigned` transactions in the pool.
+		#[pallet::constant]
+		type PermitPriority: Get<TransactionPriority>;
+		/// Signs the payloads of the signature benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: traits::BenchmarkHelper<Self::AccountId, Self::OffchainSignature>;
+		/// Notified of every [`Pallet::send`] after the recipient is credited; may reject it.
+		type TokensReceived: OnTokensReceived<Self::AccountId, Self::Balance>;
+		/// Maximum length of the `data` and `operator_data` blobs attached to a send.
+		#[pallet::constant]
+		type MaxDataLen: Get<u32>;
+		/// Origin allowed to administer the token.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Tokens minted to `RewardBeneficiary` every block until a parameter change scheduled
+		/// by the admin origin replaces it. Zero disables emissions.
+		#[pallet::constant]
+		type BlockReward: Get<Self::Balance>;
+		/// Account the per-block reward is minted to, e.g. a staking-rewards pot.
+		type RewardBeneficiary: Get<Self::AccountId>;
+		/// Share of an idle balance that decays into `DemurragePool` per block. Zero turns
+		/// demurrage off entirely.
+		#[pallet::constant]
+		type DemurrageRate: Get<Perbill>;
+		/// Community account collecting decayed balances. Its own balance never decays.
+		type DemurragePool: Get<Self::AccountId>;
+		/// Account holding staking rewards. Whatever is paid into it is shared among stakers in
+		/// proportion to their stake.
+		type StakingPot: Get<Self::AccountId>;
+		/// Number of blocks unstaked tokens stay locked before they can be withdrawn.
+		#[pallet::constant]
+		type UnbondingBlocks: Get<BlockNumberFor<Self>>;
+		/// Maximum number of unbonding chunks an account may have pending at once.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+		/// Whether a slash larger than the staked and unbonding tokens carries on into the free
+		/// balance.
+		#[pallet::constant]
+		type SlashFreeBalance: Get<bool>;
+		/// Notified of every amount slashed.
+		type OnSlash: OnSlash<Self::AccountId, Self::Balance>;
+		/// Origin of the bridge relayer, allowed to release inbound transfers.
+		type BridgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Account bridged-out tokens are locked in, and inbound transfers are released from.
+		type BridgePot: Get<Self::AccountId>;
+		/// Burn bridged-out tokens and mint inbound ones instead of locking and releasing them
+		/// through `BridgePot`.
+		#[pallet::constant]
+		type BridgeBurns: Get<bool>;
+		/// Account whose incoming transfers are burned rather than credited to it, so tokens
+		/// sent to a dead address leave `TotalSupply`. It can never send. `None` for no such
+		/// account.
+		type BurnAddress: Get<Option<Self::AccountId>>;
+		/// Maximum length of a destination address on another chain.
+		#[pallet::constant]
+		type MaxAddressLen: Get<u32>;
+		/// Maximum number of bridge relayers, and so of votes on one inbound transfer.
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
+		/// Number of blocks a payment request stays open.
+		#[pallet::constant]
+		type RequestTtl: Get<BlockNumberFor<Self>>;
+		/// Maximum number of open payment requests per payer.
+		#[pallet::constant]
+		type MaxPendingRequests: Get<u32>;
+		/// Most tokens that minting calls and inbound bridge transfers may create together in
+		/// one block. The block reward does not count against it.
+		#[pallet::constant]
+		type MaxMintPerBlock: Get<Self::Balance>;
+		/// Largest amount `mint` creates on the spot. Larger mints must be announced with
+		/// `announce_mint` and wait out `MintDelay`.
+		#[pallet::constant]
+		type MintAnnouncementThreshold: Get<Self::Balance>;
+		/// Number of blocks an announced mint waits before it can be executed.
+		#[pallet::constant]
+		type MintDelay: Get<BlockNumberFor<Self>>;
+		/// Maximum number of announced mints waiting at once.
+		#[pallet::constant]
+		type MaxAnnouncedMints: Get<u32>;
+		/// Number of blocks a scheduled parameter change waits before it takes effect.
+		#[pallet::constant]
+		type ParameterDelay: Get<BlockNumberFor<Self>>;
+		/// Maximum number of accounts left out of the circulating supply.
+		#[pallet::constant]
+		type MaxExcludedAccounts: Get<u32>;
+		/// Number of transfers of at most `FreeTransferMaxValue` each account may make without
+		/// a fee in every `FreeTransferPeriod` blocks. Zero turns fee-less transfers off.
+		#[pallet::constant]
+		type FreeTransfersPerPeriod: Get<u32>;
+		/// Largest transfer that can be fee-less.
+		#[pallet::constant]
+		type FreeTransferMaxValue: Get<Self::Balance>;
+		/// Length of the window `FreeTransfersPerPeriod` counts over.
+		#[pallet::constant]
+		type FreeTransferPeriod: Get<BlockNumberFor<Self>>;
+		/// Native currency allowance deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Native deposit reserved from the owner for every allowance entry it creates, and
+		/// returned when the entry is removed.
+		#[pallet::constant]
+		type AllowanceDeposit: Get<DepositBalanceOf<Self>>;
+		/// Origin of the compliance oracle that attests large transfers.
+		type ComplianceOracle: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Transfers of at least this value need a ticket from `attest_transfer`.
+		#[pallet::constant]
+		type AttestationThreshold: Get<Self::Balance>;
+		/// Origin of the oracle that attests the reserves backing the token.
+		type ReserveOracle: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Number of superseded reserve attestations kept besides the latest.
+		#[pallet::constant]
+		type MaxReserveHistory: Get<u32>;
+		/// Whether mints that would leave `TotalSupply` above the latest attested reserves
+		/// fail, rather than only emitting `UnderCollateralized`.
+		#[pallet::constant]
+		type MintRequiresCollateral: Get<bool>;
+		/// Whether to write a `TransferRecord` of every transfer, mint and burn to offchain
+		/// storage. Nodes keep them only with `--enable-offchain-indexing`.
+		#[pallet::constant]
+		type IndexTransfers: Get<bool>;
+		/// Report transfers as `TransferDetailed` and `TransferFromDetailed`, with the
+		/// demurrage settled on the way and both resulting balances, instead of the lean
+		/// `Transfer` and `TransferFrom`.
+		#[pallet::constant]
+		type DetailedEvents: Get<bool>;
+		/// Origin allowed to freeze and thaw accounts and parts of balances.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Tells the KYC tier of senders.
+		type KycProvider: KycTierOf<Self::AccountId>;
+		/// Sending limits of accounts without KYC.
+		#[pallet::constant]
+		type KycNoneLimit: Get<KycLimit<Self::Balance>>;
+		/// Sending limits of accounts with basic KYC.
+		#[pallet::constant]
+		type KycBasicLimit: Get<KycLimit<Self::Balance>>;
+		/// Sending limits of fully verified accounts.
+		#[pallet::constant]
+		type KycFullLimit: Get<KycLimit<Self::Balance>>;
+		/// Length of the window `KycLimit::max_per_window` counts over. Must be non-zero.
+		#[pallet::constant]
+		type KycWindow: Get<BlockNumberFor<Self>>;
+		/// Identifier of a reserve made through `NamedReservableCurrency`.
+		type ReserveIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+		/// Most named reserves an account can hold at once.
+		#[pallet::constant]
+		type MaxReserves: Get<u32>;
+		/// Most savings tranches an account keeps at once. Once that many are pending, further
+		/// credits are folded into the latest one.
+		#[pallet::constant]
+		type MaxSavingTranches: Get<u32>;
+		/// Most accounts that may hold tokens at once, or `None` for no limit. Transfers and
+		/// mints to new accounts fail at the cap; reaped accounts free their place at once.
+		#[pallet::constant]
+		type MaxHolders: Get<Option<u32>>;
+		/// Most sibling hashes a `claim` proof may hold, the depth of the deepest claims tree.
+		#[pallet::constant]
+		type MaxClaimProofLen: Get<u32>;
+		/// Blocks a `transfer_with_id` id stays used before `prune_transfer_ids` may free it.
+		#[pallet::constant]
+		type TransferIdRetention: Get<BlockNumberFor<Self>>;
+		/// Assets registry, such as `pallet-assets`, that `migrate_to_assets` moves balances
+		/// and allowances into.
+		type Assets: fungibles::Create<Self::AccountId>
+			+ fungibles::Mutate<Self::AccountId>
+			+ fungibles::approvals::Mutate<Self::AccountId>;
+		/// Balances, then allowances, moved into `Assets` per block while `migrate_to_assets`
+		/// runs. Must be non-zero.
+		#[pallet::constant]
+		type AssetMigrationBatch: Get<u32>;
+		/// Holders wound down per block while a sunset runs. Must be non-zero.
+		#[pallet::constant]
+		type SunsetBatch: Get<u32>;
+		/// Number of largest holders kept in `TopHolders`. A balance change touches at most
+		/// three of its entries, however many are kept.
+		#[pallet::constant]
+		type TopHoldersTracked: Get<u32>;
+		/// Longest token name or symbol, in bytes.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// Origin allowed to pause and unpause the token in an emergency.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Smallest balance an account keeps after sending. Whatever is left below it when
+		/// tokens are transferred, spent or burned out of an account is removed as dust,
+		/// unless part of the balance is frozen or locked. Zero for no dust removal.
+		#[pallet::constant]
+		type MinBalance: Get<Self::Balance>;
+		/// Account dust is sent to, or `None` to burn it.
+		type DustReceiver: Get<Option<Self::AccountId>>;
+		/// Most vesting schedules an account can be under at once.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+		/// Notified before and after every transfer, allowance spend, mint and burn; may veto
+		/// them.
+		type OnTransfer: OnTokenTransfer<Self::AccountId, Self::Balance>;
+		/// Tokens `faucet` mints per call.
+		#[cfg(feature = "testnet-faucet")]
+		type FaucetAmount: Get<Self::Balance>;
+		/// Blocks an account must wait between two `faucet` calls.
+		#[cfg(feature = "testnet-faucet")]
+		type FaucetCooldown: Get<BlockNumberFor<Self>>;
+	}
+
+	/// How wallets present the token: its name, its symbol and the decimal places balances
+	/// are shown with.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		DefaultNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct TokenMetadata<T: Config> {
+		pub name: BoundedVec<u8, T::StringLimit>,
+		pub symbol: BoundedVec<u8, T::StringLimit>,
+		pub decimals: u8,
+	}
+
+	/// A token launched with `create_asset`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct AssetDetails<T: Config> {
+		/// May mint and burn the token and set its metadata.
+		pub admin: T::AccountId,
+		/// Smallest non-zero balance of the token an account may hold.
+		pub min_balance: T::Balance,
+	}
+
+	/// Tokens received with `transfer_with_vesting`, of which `per_block` vest in every block
+	/// from `starting_block` on. `locked` only drops when the holder calls `vest`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct VestingSchedule<T: Config> {
+		pub locked: T::Balance,
+		pub per_block: T::Balance,
+		pub starting_block: BlockNumberFor<T>,
+	}
+
+	impl<T: Config> VestingSchedule<T> {
+		/// What is still unvested at block `now`.
+		pub fn locked_at(&self, now: BlockNumberFor<T>) -> T::Balance {
+			let elapsed = now.saturating_sub(self.starting_block).saturated_into::<u128>();
+			self.locked
+				.saturating_sub(self.per_block.saturating_mul(elapsed.saturated_into()))
+		}
+	}
+
+	/// An allowance of up to `limit` tokens in every `period` blocks.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Subscription<T: Config> {
+		pub limit: T::Balance,
+		pub period: BlockNumberFor<T>,
+		/// First block of the window `spent_in_window` counts against.
+		pub window_start: BlockNumberFor<T>,
+		pub spent_in_window: T::Balance,
+	}
+
+	/// A cap of `max_per_period` tokens sent by one account in every `period` blocks.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct TransferLimit<T: Config> {
+		pub max_per_period: T::Balance,
+		pub period: BlockNumberFor<T>,
+		/// First block of the window `sent_in_window` counts against.
+		pub window_start: BlockNumberFor<T>,
+		pub sent_in_window: T::Balance,
+	}
+
+	/// A large mint announced ahead of time.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct AnnouncedMint<T: Config> {
+		pub id: u64,
+		pub to: T::AccountId,
+		pub amount: T::Balance,
+		/// First block in which the mint can be executed.
+		pub executable_at: BlockNumberFor<T>,
+	}
+
+	/// The token's parameters governance can change, only ever with `ParameterDelay` notice.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Parameters<Balance> {
+		/// Tokens minted to `RewardBeneficiary` every block.
+		pub block_reward: Balance,
+	}
+
+	/// Parameters waiting out `ParameterDelay`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ParameterChange<T: Config> {
+		pub parameters: Parameters<T::Balance>,
+		/// Block in whose `on_initialize` the parameters take effect.
+		pub activates_at: BlockNumberFor<T>,
+	}
+
+	/// Backing reserves of the token, as attested by `ReserveOracle`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ReserveAttestation<T: Config> {
+		pub reserves: T::Balance,
+		/// Block the reserves were measured at.
+		pub as_of: BlockNumberFor<T>,
+		/// Where the attestation can be checked, such as an audit report's URL or hash.
+		pub reference: BoundedVec<u8, T::MaxDataLen>,
+	}
+
+	/// Progress of a `migrate_to_assets`, kept until every balance and allowance has moved.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct AssetMigrationStatus<T: Config> {
+		/// The asset balances and allowances are moved into.
+		pub asset: AssetIdOf<T>,
+		/// `TotalSupply` when the migration started.
+		pub supply: T::Balance,
+		/// Issuance of `asset` when the migration started.
+		pub issuance: AssetBalanceOf<T>,
+		/// Whether every balance has been walked, so allowances are being moved.
+		pub balances_done: bool,
+		/// The last holder whose balance could not be moved, which the walk resumes after.
+		pub resume_after: Option<T::AccountId>,
+		/// Balances moved so far, and the tokens they held.
+		pub holders: u32,
+		pub tokens: T::Balance,
+		/// Allowances approved in `asset` so far.
+		pub allowances: u32,
+		/// Balances `asset` refused, which stay in this pallet.
+		pub stranded: u32,
+		/// Allowances `asset` refused, which were removed without an approval.
+		pub dropped_allowances: u32,
+	}
+
+	/// How `transfer_batch` deals with a transfer that fails.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum BatchMode {
+		/// The first failure fails the whole batch.
+		AllOrNothing,
+		/// Failed transfers are skipped and reported in `BatchCompleted`.
+		BestEffort,
+	}
+
+	/// Where `initiate_sunset` sends the tokens of every holder.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum SunsetDestination<AccountId> {
+		/// Every balance moves to this account.
+		Successor(AccountId),
+		/// Every balance is burned, and its holder paid its share of this account's native
+		/// balance at the start of the sunset.
+		Refund(AccountId),
+	}
+
+	/// Progress of an `initiate_sunset`. Kept once it has finished, so that the pallet stays
+	/// frozen.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct SunsetStatus<T: Config> {
+		pub destination: SunsetDestination<T::AccountId>,
+		/// `TotalSupply` when the sunset started.
+		pub supply: T::Balance,
+		/// Native balance of the `Refund` account when the sunset started, shared out
+		/// pro rata to `supply`.
+		pub pool: DepositBalanceOf<T>,
+		/// The last holder that could not be wound down, which the walk resumes after.
+		pub resume_after: Option<T::AccountId>,
+		/// Holders wound down so far, the tokens they held and the native refunds paid.
+		pub holders: u32,
+		pub tokens: T::Balance,
+		pub refunded: DepositBalanceOf<T>,
+		/// Holders whose balance could not be moved or refunded, and stays here.
+		pub stranded: u32,
+		/// Whether every holder has been walked.
+		pub finished: bool,
+	}
+
+	/// A merchant's request for a payment, waiting for the payer to accept it.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct PaymentRequest<T: Config> {
+		pub id: u64,
+		/// Account the payment goes to.
+		pub requester: T::AccountId,
+		pub amount: T::Balance,
+		pub memo: BoundedVec<u8, T::MaxDataLen>,
+		/// Last block in which the request can be accepted.
+		pub expires_at: BlockNumberFor<T>,
+	}
+
+	/// The payload relayers are voting to release for one inbound nonce.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct InboundProposal<T: Config> {
+		/// Recipient named by the first vote.
+		pub to: T::AccountId,
+		/// Amount named by the first vote.
+		pub amount: T::Balance,
+		/// Relayers that voted for this payload.
+		pub voters: BoundedVec<T::AccountId, T::MaxRelayers>,
+		/// Set once a relayer voted for a different payload; only governance can settle it.
+		pub frozen: bool,
+	}
+
+	/// An account's staked tokens.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct StakingLedger<T: Config> {
+		/// Tokens currently earning rewards.
+		pub active: T::Balance,
+		/// Unstaked tokens and the block from which each chunk can be withdrawn.
+		pub unlocking: BoundedVec<(BlockNumberFor<T>, T::Balance), T::MaxUnbondingChunks>,
+		/// `active * RewardPerShare` at the last payout, scaled by `REWARD_PRECISION`.
+		pub reward_debt: u128,
+	}
+
+	/// What is written to offchain storage, under [`Pallet::transfer_record_key`], for every
+	/// transfer, mint and burn while `Config::IndexTransfers` is set.
+	///
+	/// SCALE-encoded in field order: `from` and `to` as `Option<AccountId>`, `None` for the
+	/// minting and burning side respectively, then `value` as a `Config::Balance`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct TransferRecord<AccountId, Balance> {
+		pub from: Option<AccountId>,
+		pub to: Option<AccountId>,
+		pub value: Balance,
+	}
+
+	/// Why a transfer would fail, as answered by [`Pallet::simulate_transfer`].
+	///
+	/// Unlike the pallet's `Error`, whose encoding follows the declaration order of all its
+	/// variants, every variant here keeps its index for good.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum Erc20Error {
+		#[codec(index = 0)]
+		InsufficientFunds,
+		/// The recipient only accepts tokens from its allowed senders.
+		#[codec(index = 1)]
+		RecipientNotAccepting,
+		/// The sender's `TransferLimit` for the current period would be exceeded.
+		#[codec(index = 2)]
+		TransferLimitExceeded,
+		/// The transfer needs a ticket from the compliance oracle.
+		#[codec(index = 3)]
+		AttestationRequired,
+		#[codec(index = 4)]
+		KycLimitExceeded { tier: KycTier },
+		/// Emptying the sender would remove an account that is still referenced.
+		#[codec(index = 5)]
+		StillReferenced,
+		#[codec(index = 6)]
+		Overflow,
+		/// The sender holds no tokens.
+		#[codec(index = 7)]
+		AccountNotFound,
+		#[codec(index = 8)]
+		BelowMinimum,
+		#[codec(index = 9)]
+		Frozen,
+		/// The recipient holds no tokens yet and `MaxHolders` accounts already do.
+		#[codec(index = 10)]
+		TooManyHolders,
+		/// A failure this enum has no variant for yet.
+		#[codec(index = 255)]
+		Other,
 	}
 
+	impl<T> From<Error<T>> for Erc20Error {
+		fn from(error: Error<T>) -> Self {
+			match error {
+				Error::InsufficientFunds | Error::FundsUnavailable => Self::InsufficientFunds,
+				Error::AccountNotFound => Self::AccountNotFound,
+				Error::BelowMinimum => Self::BelowMinimum,
+				Error::Frozen => Self::Frozen,
+				Error::TooManyHolders => Self::TooManyHolders,
+				Error::RecipientNotAccepting => Self::RecipientNotAccepting,
+				Error::TransferLimitExceeded => Self::TransferLimitExceeded,
+				Error::AttestationRequired => Self::AttestationRequired,
+				Error::KycLimitExceeded { tier } => Self::KycLimitExceeded { tier },
+				Error::StillReferenced => Self::StillReferenced,
+				Error::StorageOverflow | Error::Overflow => Self::Overflow,
+				_ => Self::Other,
+			}
+		}
+	}
+
+	/// One step of a `multicall`, run with the caller as the sender, owner or spender.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub enum TokenAction<T: Config> {
+		/// Move `value` of the caller's tokens to `to`.
+		Transfer { to: T::AccountId, value: T::Balance },
+		/// Set the caller's allowance for `spender` to `value`, with no deadline.
+		Approve { spender: T::AccountId, value: T::Balance },
+		/// Move `value` of `owner`'s tokens to `to` out of the caller's allowance.
+		TransferFrom { owner: T::AccountId, to: T::AccountId, value: T::Balance },
+	}
+
+	/// Balances by asset, then holder.
+	#[pallet::storage]
+	pub(super) type BalanceOf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+	>;
+
+	/// Allowances by asset, owner, then spender.
 	#[pallet::storage]
-	#[pallet::getter(fn get_balance_of)]
-	pub(super) type BalanceOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64>;
+	pub(super) type Allowances<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		T::Balance,
+	>;
 
+	/// The pre-v3 allowance layout, keyed by the (owner, spender) pair. Only non-empty while
+	/// [`migrations::v3::RekeyAllowances`] moves its entries into `Allowances`.
 	#[pallet::storage]
 	pub(super) type Allowance<T: Config> =
-		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), u64>;
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), T::Balance>;
+
+	/// Allowances for a purpose other than [`DEFAULT_PURPOSE`], with the deposit held for each.
+	#[pallet::storage]
+	pub(super) type ScopedAllowances<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, Purpose>,
+		),
+		(T::Balance, DepositBalanceOf<T>),
+	>;
+
+	/// Number of `ScopedAllowances` entries per owner. Counts against `MaxApprovalsPerOwner`
+	/// together with the owner's `Approvals` and `AssetApprovalCount`.
+	#[pallet::storage]
+	pub(super) type ScopedApprovalCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of allowances each owner has granted in launched tokens. Counts against
+	/// `MaxApprovalsPerOwner` like the others.
+	#[pallet::storage]
+	pub(super) type AssetApprovalCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Native deposit held for each allowance in a launched token, by (asset, owner, spender).
+	/// Entries created while the deposit was zero hold none.
+	#[pallet::storage]
+	pub(super) type AssetAllowanceDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AssetId, T::AccountId, T::AccountId),
+		DepositBalanceOf<T>,
+	>;
+
+	/// One-shot tickets from the compliance oracle, by the exact (from, to, value) they allow,
+	/// with the last block they can be used in.
+	#[pallet::storage]
+	pub(super) type Attestations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId, T::Balance),
+		BlockNumberFor<T>,
+	>;
+
+	/// Part of each account's balance that cannot be moved out, set by `FreezeOrigin`. May be
+	/// more than the balance.
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_balance)]
+	pub(super) type FrozenBalance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance>;
+
+	/// Accounts in savings mode, with how many blocks what they receive stays locked for.
+	#[pallet::storage]
+	pub(super) type SavingsLock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// Tokens each account received in savings mode, by the block they unlock at, earliest
+	/// first. Pending tranches cannot be moved out, like frozen tokens; matured ones stay
+	/// until `release_matured` clears them or a new tranche needs the room.
+	#[pallet::storage]
+	pub(super) type SavingTranches<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(BlockNumberFor<T>, T::Balance), T::MaxSavingTranches>,
+		ValueQuery,
+	>;
+
+	/// Vesting schedules each account is under. Their `locked` tokens cannot be moved out,
+	/// like frozen ones, until `vest` unlocks what has vested.
+	#[pallet::storage]
+	pub(super) type VestingSchedules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingSchedule<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// Tokens each account set aside through `ReservableCurrency`, named reserves included.
+	/// They are part of the total supply but not of the balance, so no transfer can move them.
+	#[pallet::storage]
+	pub(super) type ReservedBalance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance>;
+
+	/// The named parts of each account's `ReservedBalance`, sorted by identifier.
+	#[pallet::storage]
+	pub(super) type NamedReserves<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::ReserveIdentifier, T::Balance), T::MaxReserves>,
+	>;
+
+	/// Start of each sender's current `KycWindow` and what it has sent in it, for tiers with
+	/// a finite `max_per_window`.
+	#[pallet::storage]
+	pub(super) type KycOutflow<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, T::Balance)>;
+
+	/// All-time tokens each spender has moved out of each owner's balance, through any kind of
+	/// allowance or a subscription. Outlives the allowances themselves.
+	#[pallet::storage]
+	pub(super) type SpentBy<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Entries moved so far by the allowance re-keying, present only while it runs.
+	#[pallet::storage]
+	pub(super) type AllowanceRekey<T: Config> = StorageValue<_, u64>;
+
+	/// Native deposit held for each allowance entry, by (owner, spender). Entries created
+	/// while the deposit was zero, or before deposits existed, hold none.
+	#[pallet::storage]
+	pub(super) type AllowanceDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), DepositBalanceOf<T>>;
+
+	/// The last block in which each allowance approved with a deadline can be spent, by
+	/// (owner, spender). Allowances without an entry never expire.
+	#[pallet::storage]
+	#[pallet::getter(fn allowance_expiry)]
+	pub(super) type AllowanceExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), BlockNumberFor<T>>;
+
+	/// The (owner, spender) pairs whose allowance can last be spent in each block, i.e.
+	/// `AllowanceExpiry` indexed by deadline, so the sweep only visits deadlines that passed.
+	#[pallet::storage]
+	pub(super) type ExpiringAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		(),
+	>;
+
+	/// The earliest deadline `on_idle` has not finished sweeping; every allowance past an
+	/// earlier one is gone. Set when the first deadline is approved.
+	#[pallet::storage]
+	pub(super) type AllowanceSweepCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	/// The spenders each owner currently has a non-zero allowance for.
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxApprovalsPerOwner>,
+		ValueQuery,
+	>;
+
+	/// The parts of each (owner, spender) allowance the spender delegated, oldest first. They
+	/// are carved out of the allowance rather than added to it, so they never sum to more.
+	#[pallet::storage]
+	pub(super) type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, T::Balance), T::MaxDelegations>,
+		ValueQuery,
+	>;
+
+	/// The spender whose allowance each delegate spends, by owner, then delegate. A delegate
+	/// holds at most one delegation from each owner's allowances.
+	#[pallet::storage]
+	pub(super) type DelegatedAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+	>;
+
+	/// Tokens in existence, by asset.
+	#[pallet::storage]
+	pub(super) type TotalSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance>;
+
+	/// Tokens launched with `create_asset`.
+	#[pallet::storage]
+	pub(super) type Asset<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, AssetDetails<T>>;
+
+	/// Accounts, such as the treasury or team pots, whose balances do not count as
+	/// circulating.
+	#[pallet::storage]
+	#[pallet::getter(fn excluded_from_circulating)]
+	pub(super) type ExcludedFromCirculating<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxExcludedAccounts>, ValueQuery>;
+
+	/// Start of each account's current free transfer window and the fee-less transfers it has
+	/// made in it.
+	#[pallet::storage]
+	pub(super) type FreeTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32)>;
+
+	/// The block each account last used the faucet in.
+	#[cfg(feature = "testnet-faucet")]
+	#[pallet::storage]
+	pub(super) type LastFaucetDrip<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// All-time number of tokens destroyed.
+	#[pallet::storage]
+	#[pallet::getter(fn total_burned)]
+	pub(super) type TotalBurned<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Number of accounts holding a non-zero balance.
+	#[pallet::storage]
+	#[pallet::getter(fn holder_count)]
+	pub(super) type HolderCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// Number of successful transfers, all time.
 	#[pallet::storage]
-	#[pallet::getter(fn get_total_supply)]
-	pub(super) type TotalSupply<T: Config> = StorageValue<_, u64>;
+	#[pallet::getter(fn transfer_count)]
+	pub(super) type TransferCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Total amount moved by transfers, all time.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_volume)]
+	pub(super) type TransferVolume<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// Amount moved by transfers in the current block.
+	#[pallet::storage]
+	#[pallet::getter(fn block_volume)]
+	pub(super) type BlockVolume<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// Tokens minted in the current block that count against `MaxMintPerBlock`.
+	#[pallet::storage]
+	pub(super) type MintedThisBlock<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Mints above `MintAnnouncementThreshold` waiting out `MintDelay`, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn announced_mints)]
+	pub(super) type AnnouncedMints<T: Config> =
+		StorageValue<_, BoundedVec<AnnouncedMint<T>, T::MaxAnnouncedMints>, ValueQuery>;
+
+	/// Id given to the next announced mint.
+	#[pallet::storage]
+	pub(super) type NextMintId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The parameter change scheduled by `schedule_parameter_change`, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_parameters)]
+	pub(super) type PendingParameters<T: Config> = StorageValue<_, ParameterChange<T>>;
+
+	/// Ring buffer of the transfer volume of the last `StatsWindow` blocks, keyed by
+	/// `block_number % StatsWindow`.
+	#[pallet::storage]
+	pub(super) type RecentVolume<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (BlockNumberFor<T>, u128)>;
+
+	/// `TotalSupply` at the start of each of the last `MaxSupplyHistory` tracking periods, keyed
+	/// by period index.
+	#[pallet::storage]
+	pub(super) type SupplyHistory<T: Config> = StorageMap<_, Twox64Concat, u32, T::Balance>;
+
+	/// Block in which each holder's balance last changed. Demurrage accrues from here and is
+	/// charged the next time the balance is touched. Only kept while demurrage is on.
+	#[pallet::storage]
+	pub(super) type LastActivity<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// Staked and unbonding tokens per account. Tokens in here are out of `BalanceOf` and
+	/// cannot be transferred.
+	#[pallet::storage]
+	pub(super) type Ledgers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, StakingLedger<T>>;
+
+	/// Sum of every ledger's `active` stake.
+	#[pallet::storage]
+	#[pallet::getter(fn total_staked)]
+	pub(super) type TotalStaked<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Rewards earned per staked token since staking began, scaled by `REWARD_PRECISION`.
+	#[pallet::storage]
+	pub(super) type RewardPerShare<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// Part of the `StakingPot` balance already shared out through `RewardPerShare` and not yet
+	/// paid.
+	#[pallet::storage]
+	pub(super) type RewardsAccounted<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Nonce the next outbound bridge transfer is tagged with.
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_nonce)]
+	pub(super) type OutboundNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Inbound bridge nonces already released, with the block they were released in.
+	#[pallet::storage]
+	pub(super) type ProcessedInbound<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>>;
+
+	/// Accounts allowed to vote on inbound bridge transfers.
+	#[pallet::storage]
+	#[pallet::getter(fn relayers)]
+	pub(super) type Relayers<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxRelayers>, ValueQuery>;
+
+	/// Number of relayer votes needed to release an inbound transfer.
+	#[pallet::storage]
+	pub(super) type RelayerThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Inbound transfers relayers have voted on but not yet released, by inbound nonce.
+	#[pallet::storage]
+	pub(super) type InboundVotes<T: Config> = StorageMap<_, Twox64Concat, u64, InboundProposal<T>>;
+
+	/// Accounts that only accept tokens from senders on their allow-list.
+	#[pallet::storage]
+	pub(super) type StrictReceivers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Senders a recipient accepts tokens from while in strict mode, by (recipient, sender).
+	/// Entries are kept when strict mode is switched off.
+	#[pallet::storage]
+	pub(super) type AllowedSenders<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), ()>;
+
+	/// Accounts frozen by `freeze_account`: they can neither send nor receive tokens, nor
+	/// spend allowances, until `thaw_account`.
+	#[pallet::storage]
+	pub(super) type Frozen<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Recurring allowances, by (owner, spender). `transfer_from` draws on these before the
+	/// regular allowance.
+	#[pallet::storage]
+	#[pallet::getter(fn subscription)]
+	pub(super) type Subscriptions<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), Subscription<T>>;
+
+	/// Outflow caps the admin origin put on individual accounts.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_limit)]
+	pub(super) type TransferLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TransferLimit<T>>;
+
+	/// Number of entries in `TransferLimits`. While it is zero, transfers skip the lookup.
+	#[pallet::storage]
+	pub(super) type LimitedAccounts<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Id given to the next payment request.
+	#[pallet::storage]
+	pub(super) type NextRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Open payment requests, by payer. Expired ones are dropped when the payer gets a new
+	/// request.
+	#[pallet::storage]
+	#[pallet::getter(fn payment_requests)]
+	pub(super) type PaymentRequests<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<PaymentRequest<T>, T::MaxPendingRequests>,
+		ValueQuery,
+	>;
+
+	/// Tokens minted to `RewardBeneficiary` in every block's `on_initialize`.
+	#[pallet::storage]
+	#[pallet::getter(fn block_reward)]
+	pub(super) type RewardPerBlock<T: Config> =
+		StorageValue<_, T::Balance, ValueQuery, T::BlockReward>;
+
+	/// Hash every signed token payload commits to, binding it to this chain and this pallet
+	/// instance. See [`Pallet::domain_separator`].
+	#[pallet::storage]
+	pub(super) type DomainSeparator<T: Config> = StorageValue<_, [u8; 32], ValueQuery>;
+
+	/// Nonce the next permit signed by each owner must commit to.
+	#[pallet::storage]
+	#[pallet::getter(fn nonces)]
+	pub(super) type Nonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Block in which a sender used each id passed to `transfer_with_id`.
+	#[pallet::storage]
+	pub(super) type UsedTransferIds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		TransferId,
+		BlockNumberFor<T>,
+	>;
+
+	/// The most recent reserve attestation, which mints are checked against.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_reserve_attestation)]
+	pub(super) type LatestReserveAttestation<T: Config> = StorageValue<_, ReserveAttestation<T>>;
+
+	/// Superseded reserve attestations, oldest first; at most `MaxReserveHistory` are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_attestation_history)]
+	pub(super) type ReserveAttestationHistory<T: Config> =
+		StorageValue<_, BoundedVec<ReserveAttestation<T>, T::MaxReserveHistory>, ValueQuery>;
+
+	/// Merkle root of the Ethereum snapshot `claim` mints against, if one was committed.
+	#[pallet::storage]
+	#[pallet::getter(fn claims_root)]
+	pub(super) type ClaimsRoot<T: Config> = StorageValue<_, [u8; 32]>;
+
+	/// Tokens of the committed snapshot's total that have not been claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn claims_left)]
+	pub(super) type ClaimsLeft<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Ethereum addresses whose snapshot leaf has been claimed.
+	#[pallet::storage]
+	pub(super) type ClaimedAddresses<T: Config> =
+		StorageMap<_, Blake2_128Concat, EthereumAddress, ()>;
+
+	/// The running `migrate_to_assets`, if any. Every call into this pallet fails while it is
+	/// set.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_migration)]
+	pub(super) type AssetMigration<T: Config> = StorageValue<_, AssetMigrationStatus<T>>;
+
+	/// The `initiate_sunset` under way or finished, if any. Every call into this pallet fails
+	/// once it is set.
+	#[pallet::storage]
+	#[pallet::getter(fn sunset)]
+	pub(super) type Sunset<T: Config> = StorageValue<_, SunsetStatus<T>>;
+
+	/// The holders with the largest balances, with their balances, keyed by
+	/// [`Pallet::holder_rank`]. The keys are not hashed, so storage order is ascending
+	/// balance and the smallest entry is the first. Every holder missing from it holds at most
+	/// `TopHoldersFloor`.
+	#[pallet::storage]
+	pub(super) type TopHolders<T: Config> =
+		StorageMap<_, Identity, HolderRank<T::AccountId>, T::Balance>;
+
+	/// Number of entries in `TopHolders`, at most `TopHoldersTracked`.
+	#[pallet::storage]
+	pub(super) type TopHoldersCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The most a holder missing from `TopHolders` may hold, and the least an entry of it
+	/// holds. Raised whenever a holder is evicted from the index.
+	#[pallet::storage]
+	pub(super) type TopHoldersFloor<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Name, symbol and decimals by asset. The native token's are set at genesis or by
+	/// `set_metadata`, those of a launched token by `set_asset_metadata`.
+	#[pallet::storage]
+	pub(super) type Metadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, TokenMetadata<T>, ValueQuery>;
+
+	/// Whether `pause` has stopped every transfer, mint, burn and allowance change until
+	/// `unpause`.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub(super) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Most tokens that may ever be in existence at once, set once by `set_max_supply`. No cap
+	/// until then.
+	#[pallet::storage]
+	#[pallet::getter(fn max_supply)]
+	pub(super) type MaxSupply<T: Config> = StorageValue<_, T::Balance>;
+
+	#[pallet::type_value]
+	pub(super) fn DefaultFeeRate() -> FixedU128 {
+		FixedU128::one()
+	}
+
+	/// Tokens [`crate::fees::TokenFees`] charges for each unit of native fee, set by
+	/// `set_fee_rate`. One until then.
+	#[pallet::storage]
+	#[pallet::getter(fn tokens_per_native_unit)]
+	pub(super) type TokensPerNativeUnit<T: Config> =
+		StorageValue<_, FixedU128, ValueQuery, DefaultFeeRate>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Token name, at most `StringLimit` bytes.
+		pub name: Vec<u8>,
+		/// Token symbol, at most `StringLimit` bytes.
+		pub symbol: Vec<u8>,
+		pub decimals: u8,
+		#[serde(skip)]
+		pub _config: sp_std::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			<DomainSeparator<T>>::put(Pallet::<T>::compute_domain_separator());
+			<Metadata<T>>::insert(
+				T::NativeAsset::get(),
+				TokenMetadata::<T> {
+					name: self
+						.name
+						.clone()
+						.try_into()
+						.expect("token name is longer than `StringLimit`"),
+					symbol: self
+						.symbol
+						.clone()
+						.try_into()
+						.expect("token symbol is longer than `StringLimit`"),
+					decimals: self.decimals,
+				},
+			);
+		}
+	}
 
 	// Pallets use events to inform users when important changes are made
 	#[pallet::event]
@@ -49,150 +1375,5639 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		TotalSupply {
-			value: u64,
+			value: T::Balance,
 		},
 		BalanceOf {
 			who: T::AccountId,
-			balance: u64,
+			balance: T::Balance,
 		},
-		BalanceSet {
+		/// `force_set_balance` set `who`'s balance to `balance`, moving `TotalSupply` by the
+		/// difference.
+		BalanceForced {
 			who: T::AccountId,
-			balance: u64,
+			balance: T::Balance,
 		},
 		Transfer {
 			from: T::AccountId,
 			to: T::AccountId,
-			value: u64,
+			value: T::Balance,
 		},
 		Approval {
 			from: T::AccountId,
 			to: T::AccountId,
-			value: u64,
+			value: T::Balance,
 		},
 		TransferFrom {
 			from: T::AccountId,
 			to: T::AccountId,
-			value: u64,
+			value: T::Balance,
 		},
-	}
-
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		AccountNotExist,
-		StorageOverflow,
-		InsufficientFunds,
-		ApprovalNotGranted,
-	}
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000)]
-		pub fn total_supply(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-			let total_supply = Self::_total_supply();
-			Self::deposit_event(Event::TotalSupply { value: total_supply });
-			Ok(())
-		}
-		#[pallet::weight(10_000)]
-		pub fn balance_of(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-			let balance = Self::_balance_of(&user);
-			Self::deposit_event(Event::BalanceOf { who: user, balance });
-			Ok(())
-		}
-		#[pallet::weight(10_000)]
-		pub fn set_balance(origin: OriginFor<T>, balance: u64) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			Self::_balance_set(&who, balance);
-			Self::deposit_event(Event::BalanceSet { who, balance });
-			Ok(())
-		}
-		#[pallet::weight(10_000)]
-		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, value: u64) -> DispatchResult {
-			let from = ensure_signed(origin)?;
-			Self::_transfer(from, to, value)?;
-			Ok(())
-		}
-		#[pallet::weight(10_000)]
-		pub fn approve(
-			origin: OriginFor<T>,
+		/// Tokens were sent with attached data. Also reported as a `Transfer`, or a
+		/// `TransferDetailed`.
+		Sent {
 			from: T::AccountId,
 			to: T::AccountId,
-			value: u64,
-		) -> DispatchResult {
+			value: T::Balance,
+			data: BoundedVec<u8, T::MaxDataLen>,
+			operator_data: BoundedVec<u8, T::MaxDataLen>,
+		},
+		/// The full balance of deposit sub-account `index` was moved to its master.
+		Swept {
+			master: T::AccountId,
+			index: u32,
+			value: T::Balance,
+		},
+		/// The admin origin minted `amount` to `to`.
+		Minted {
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+		/// A mint of `amount` to `to` was announced and can be executed from `executable_at`.
+		MintAnnounced {
+			id: u64,
+			to: T::AccountId,
+			amount: T::Balance,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// Announced mint `id` was carried out.
+		MintExecuted {
+			id: u64,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Announced mint `id` was called off.
+		MintCancelled {
+			id: u64,
+		},
+		/// `who`'s balance no longer counts as circulating.
+		ExcludedFromCirculating {
+			who: T::AccountId,
+		},
+		/// `who`'s balance counts as circulating again.
+		IncludedInCirculating {
+			who: T::AccountId,
+		},
+		/// Every token amount in storage was multiplied by `factor`.
+		RedenominationComplete {
+			factor: T::Balance,
+		},
+		/// The allowance re-keying finished after moving `moved` entries.
+		AllowancesRekeyed {
+			moved: u64,
+		},
+		/// `owner` allowed `spender` to spend `value` for `purpose`.
+		ScopedApproval {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			purpose: Purpose,
+			value: T::Balance,
+		},
+		/// `spender` moved `value` of `owner`'s tokens to `to` out of its `purpose` allowance.
+		ScopedTransfer {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			purpose: Purpose,
+			to: T::AccountId,
+			value: T::Balance,
+		},
+		/// The oracle allowed one transfer of `value` from `from` to `to` until block `expiry`.
+		TransferAttested {
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			expiry: BlockNumberFor<T>,
+		},
+		/// `amount` more of `who`'s balance was frozen, making `frozen` in total.
+		AmountFrozen {
+			who: T::AccountId,
+			amount: T::Balance,
+			frozen: T::Balance,
+		},
+		/// `amount` of `who`'s frozen balance was released, leaving `frozen`.
+		AmountUnfrozen {
+			who: T::AccountId,
+			amount: T::Balance,
+			frozen: T::Balance,
+		},
+		/// `amount` of `who`'s balance was reserved.
+		Reserved {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `amount` of `who`'s reserved tokens went back to its balance.
+		Unreserved {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `amount` of `from`'s reserved tokens were moved to `to`'s balance or reserve.
+		ReserveRepatriated {
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			destination_status: BalanceStatus,
+		},
+		/// `old`'s token position now belongs to `new`.
+		AccountMigrated {
+			old: T::AccountId,
+			new: T::AccountId,
+		},
+		/// The faucet minted `amount` to `who`.
+		FaucetDrip {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The per-block reward was minted.
+		RewardMinted {
+			beneficiary: T::AccountId,
+			value: T::Balance,
+		},
+		/// Decay accrued on an idle balance was moved to the demurrage pool.
+		DemurrageCharged {
+			who: T::AccountId,
+			value: T::Balance,
+		},
+		/// Tokens were moved from the free balance into the staked bucket.
+		Staked {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Staked tokens started unbonding and can be withdrawn from `unlock_at`.
+		Unbonded {
+			who: T::AccountId,
+			amount: T::Balance,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// Unbonded tokens were returned to the free balance.
+		Withdrawn {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Staking rewards were paid out of the staking pot.
+		RewardPaid {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Tokens of `who` were burned as a penalty.
+		Slashed {
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Tokens left for `dest_address` on chain `dest_chain`. Relayers deliver transfers in
+		/// `nonce` order.
+		BridgedOut {
+			nonce: u64,
+			from: T::AccountId,
+			dest_chain: u32,
+			dest_address: BoundedVec<u8, T::MaxAddressLen>,
+			amount: T::Balance,
+		},
+		/// Inbound bridge transfer `nonce` was released to `to`.
+		BridgedIn {
+			nonce: u64,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The admin origin replaced the relayer set.
+		RelayersSet {
+			threshold: u32,
+		},
+		/// `relayer` voted to release inbound transfer `nonce`.
+		InboundVoted {
+			nonce: u64,
+			relayer: T::AccountId,
+		},
+		/// `relayer` voted for a different payload than earlier votes on `nonce`, which is now
+		/// frozen until governance settles it.
+		InboundConflict {
+			nonce: u64,
+			relayer: T::AccountId,
+		},
+		/// `parameters` will replace the current ones in block `activates_at`.
+		ParameterChangeScheduled {
+			parameters: Parameters<T::Balance>,
+			activates_at: BlockNumberFor<T>,
+		},
+		/// `who` switched between accepting all incoming tokens and only those from allowed
+		/// senders.
+		IncomingPolicySet {
+			who: T::AccountId,
+			accept_all: bool,
+		},
+		/// `recipient` accepts tokens from `sender` while in strict mode.
+		SenderAllowed {
+			recipient: T::AccountId,
+			sender: T::AccountId,
+		},
+		/// `recipient` no longer accepts tokens from `sender` while in strict mode.
+		SenderDisallowed {
+			recipient: T::AccountId,
+			sender: T::AccountId,
+		},
+		/// `owner` lets `spender` pull up to `limit` tokens every `period` blocks.
+		SubscriptionApproved {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			limit: T::Balance,
+			period: BlockNumberFor<T>,
+		},
+		/// `owner` ended `spender`'s subscription.
+		SubscriptionCancelled {
+			owner: T::AccountId,
+			spender: T::AccountId,
+		},
+		/// The admin origin capped what `who` may send in every `period` blocks.
+		TransferLimitSet {
+			who: T::AccountId,
+			max_per_period: T::Balance,
+			period: BlockNumberFor<T>,
+		},
+		/// The admin origin lifted `who`'s transfer limit.
+		TransferLimitCleared {
+			who: T::AccountId,
+		},
+		/// `requester` asked `payer` for `amount`.
+		PaymentRequested {
+			id: u64,
+			requester: T::AccountId,
+			payer: T::AccountId,
+			amount: T::Balance,
+			memo: BoundedVec<u8, T::MaxDataLen>,
+		},
+		/// `payer` paid request `id`.
+		PaymentRequestAccepted {
+			id: u64,
+			payer: T::AccountId,
+		},
+		/// `payer` turned down request `id`.
+		PaymentRequestRejected {
+			id: u64,
+			payer: T::AccountId,
+		},
+		/// The requester withdrew request `id`.
+		PaymentRequestCancelled {
+			id: u64,
+			payer: T::AccountId,
+		},
+		/// The oracle attested `reserves` backing the token as of block `as_of`.
+		ReservesAttested {
+			reserves: T::Balance,
+			as_of: BlockNumberFor<T>,
+		},
+		/// `TotalSupply` went over the latest attested reserves, after an attestation or a mint.
+		UnderCollateralized {
+			supply: T::Balance,
+			reserves: T::Balance,
+		},
+		/// A snapshot of `total` tokens with Merkle root `root` can now be claimed.
+		ClaimsRootSet {
+			root: [u8; 32],
+			total: T::Balance,
+		},
+		/// `who` claimed the `amount` the snapshot granted `eth_address`.
+		Claimed {
+			who: T::AccountId,
+			eth_address: EthereumAddress,
+			amount: T::Balance,
+		},
+		/// Balances and allowances started moving into `asset`, freezing the pallet.
+		AssetMigrationStarted {
+			asset: AssetIdOf<T>,
+		},
+		/// The asset migration has so far moved `holders` balances, holding `tokens` in
+		/// total, and `allowances` allowances.
+		AssetMigrationProgress {
+			holders: u32,
+			tokens: T::Balance,
+			allowances: u32,
+		},
+		/// The asset migration finished and the pallet is no longer frozen. `stranded`
+		/// balances could not be moved and are still held here; `dropped_allowances`
+		/// allowances could not be approved in `asset` and were removed.
+		AssetMigrationCompleted {
+			asset: AssetIdOf<T>,
+			holders: u32,
+			tokens: T::Balance,
+			allowances: u32,
+			stranded: u32,
+			dropped_allowances: u32,
+		},
+		/// The scheduled change to `parameters` was called off before it took effect.
+		ParameterChangeCancelled {
+			parameters: Parameters<T::Balance>,
+		},
+		/// The scheduled `parameters` took effect.
+		ParametersActivated {
+			parameters: Parameters<T::Balance>,
+		},
+		/// `value` of `from`'s tokens were burned, by `AdminOrigin` or on the way to
+		/// `BurnAddress`.
+		Burned {
+			from: T::AccountId,
+			value: T::Balance,
+		},
+		/// `Transfer` under `DetailedEvents`. `demurrage` is what `from` and `to` owed and
+		/// settled into `DemurragePool` before the move; the balances are those after it.
+		TransferDetailed {
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			id: Option<TransferId>,
+			demurrage: T::Balance,
+			from_balance: T::Balance,
+			to_balance: T::Balance,
+		},
+		/// `TransferFrom` under `DetailedEvents`, with the same details as `TransferDetailed`.
+		TransferFromDetailed {
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			demurrage: T::Balance,
+			from_balance: T::Balance,
+			to_balance: T::Balance,
+		},
+		/// `spender` delegated `amount` more of its allowance from `owner` to `delegate`.
+		AllowanceDelegated {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The `amount` left of what `spender` delegated to `delegate` was revoked with the
+		/// allowance it was part of.
+		DelegationRevoked {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `who` now locks what it receives for `lock_blocks`, or no longer does if `None`.
+		SavingsModeSet {
+			who: T::AccountId,
+			lock_blocks: Option<BlockNumberFor<T>>,
+		},
+		/// `value` credited to `who` in savings mode is locked until block `until`.
+		SavingsLocked {
+			who: T::AccountId,
+			value: T::Balance,
+			until: BlockNumberFor<T>,
+		},
+		/// `who` cleared its matured savings tranches, holding `value`.
+		SavingsReleased {
+			who: T::AccountId,
+			value: T::Balance,
+		},
+		/// The pallet is being wound down into `destination`, freezing it for good.
+		SunsetInitiated {
+			destination: SunsetDestination<T::AccountId>,
+		},
+		/// The sunset moved `who`'s `value` tokens to the successor `to`.
+		SunsetMoved {
+			who: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+		},
+		/// The sunset burned `who`'s `value` tokens and paid it `refund` in native currency.
+		SunsetRefunded {
+			who: T::AccountId,
+			value: T::Balance,
+			refund: DepositBalanceOf<T>,
+		},
+		/// Every holder has been walked. `stranded` of them could not be wound down and still
+		/// hold their tokens here.
+		SunsetComplete {
+			holders: u32,
+			tokens: T::Balance,
+			refunded: DepositBalanceOf<T>,
+			stranded: u32,
+		},
+		/// `owner`'s allowance for `spender` was raised or lowered to `allowance`.
+		AllowanceChanged {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			allowance: T::Balance,
+		},
+		/// The token's metadata was replaced.
+		MetadataSet {
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
+			decimals: u8,
+		},
+		/// A new token `asset` was launched, managed by `admin`.
+		AssetCreated {
+			asset: T::AssetId,
+			admin: T::AccountId,
+			min_balance: T::Balance,
+		},
+		/// The admin of `asset` minted `amount` of it to `to`.
+		AssetIssued {
+			asset: T::AssetId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The admin of `asset` burned `amount` of it from `from`.
+		AssetBurned {
+			asset: T::AssetId,
+			from: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `value` of `asset` moved from `from` to `to`.
+		AssetTransferred {
+			asset: T::AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+		},
+		/// `owner` allowed `spender` to move `value` of its `asset`.
+		AssetApproval {
+			asset: T::AssetId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+		},
+		/// The admin of `asset` set its name, symbol and decimals.
+		AssetMetadataSet {
+			asset: T::AssetId,
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
+			decimals: u8,
+		},
+		/// The token was paused.
+		Paused,
+		/// The token was unpaused.
+		Unpaused,
+		/// `who` was frozen.
+		AccountFrozen {
+			who: T::AccountId,
+		},
+		/// `who` was thawed.
+		AccountThawed {
+			who: T::AccountId,
+		},
+		/// `from` sent `total` to `count` recipients with `transfer_batch`.
+		BatchTransfer {
+			from: T::AccountId,
+			count: u32,
+			total: T::Balance,
+		},
+		/// A `BestEffort` `transfer_batch` of `from` made `succeeded` transfers; each of
+		/// `failures` is the index in the batch of one that failed and changed nothing, with
+		/// why it failed.
+		BatchCompleted {
+			from: T::AccountId,
+			succeeded: u32,
+			failures: BoundedVec<(u32, DispatchError), T::MaxBatchSize>,
+		},
+		/// `account` fell below `MinBalance` and lost the `amount` left, to `DustReceiver` or
+		/// burned.
+		DustLost {
+			account: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `from` sent `to` `locked` tokens that vest by `per_block` from `starting_block` on.
+		VestingScheduleAdded {
+			from: T::AccountId,
+			to: T::AccountId,
+			locked: T::Balance,
+			per_block: T::Balance,
+			starting_block: BlockNumberFor<T>,
+		},
+		/// `TotalSupply` was capped at `cap`.
+		SupplyCapSet {
+			cap: T::Balance,
+		},
+		/// `who` unlocked what had vested; its schedules still lock `locked`.
+		Vested {
+			who: T::AccountId,
+			locked: T::Balance,
+		},
+		/// `spender` burned `value` of `owner`'s tokens out of its allowance.
+		BurnedFrom {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+		},
+		/// `owner`'s allowance for `spender` passed its deadline and was removed.
+		AllowanceExpired {
+			owner: T::AccountId,
+			spender: T::AccountId,
+		},
+		/// `Transfer` sent by `transfer_with_id`, with the client's `id`.
+		TransferWithId {
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			id: TransferId,
+		},
+		/// Fees paid in the token now cost `rate` tokens per unit of native fee.
+		FeeRateSet {
+			rate: FixedU128,
+		},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		AccountNotExist,
+		/// Deprecated alias of `Overflow`, no longer returned. Removed in the next release.
+		StorageOverflow,
+		/// Deprecated: split into `AccountNotFound` and `FundsUnavailable`, and no longer
+		/// returned. Removed in the next release.
+		InsufficientFunds,
+		/// The spender has no allowance from the owner.
+		ApprovalNotGranted,
+		/// The owner already has `MaxApprovalsPerOwner` spenders approved.
+		TooManyApprovals,
+		/// The stored allowance is not the one the caller expected to replace.
+		AllowanceMismatch,
+		/// The same spender appears more than once in a batch.
+		DuplicateSpender,
+		/// The account's last tokens cannot be moved out while other pallets still depend on
+		/// the account existing.
+		StillReferenced,
+		/// The account has less active stake than requested.
+		InsufficientStake,
+		/// The account already has `MaxUnbondingChunks` chunks unbonding.
+		TooManyUnbondingChunks,
+		/// No unbonding chunk has reached its unlock block yet.
+		NothingToWithdraw,
+		/// This inbound bridge nonce was already released.
+		InboundNonceProcessed,
+		/// The threshold must be between one and the number of relayers.
+		InvalidThreshold,
+		/// The owner cannot reserve the native deposit for a new allowance.
+		CannotReserveDeposit,
+		/// The account to migrate to already holds token state.
+		DestinationNotEmpty,
+		/// Only the spending history of owners without a balance can be pruned.
+		OwnerNotReaped,
+		/// The address does not resolve to an account.
+		UnknownAddress,
+		/// The `multicall` action at `index` failed, so none of them took effect.
+		MulticallFailed {
+			index: u16,
+		},
+		/// The transfer is at or above `AttestationThreshold` and no unexpired ticket matches it.
+		AttestationRequired,
+		/// The ticket would already have expired.
+		AttestationExpired,
+		/// The transfer is over what the sender's KYC tier allows at once or in this window.
+		KycLimitExceeded {
+			tier: KycTier,
+		},
+		/// The caller used the faucet less than `FaucetCooldown` blocks ago.
+		FaucetCooldownActive,
+		/// The same relayer appears more than once in the set.
+		DuplicateRelayer,
+		/// Only relayers may vote on inbound transfers.
+		NotRelayer,
+		/// This relayer already voted on the nonce.
+		DuplicateVote,
+		/// Conflicting votes froze this nonce; only governance can release it.
+		InboundNonceFrozen,
+		/// The recipient only accepts tokens from senders on its allow-list.
+		RecipientNotAccepting,
+		/// The payer already has `MaxPendingRequests` open payment requests.
+		TooManyRequests,
+		/// No open payment request with this id for the payer, or not made by the caller.
+		UnknownRequest,
+		/// The payment request is past its expiry block.
+		RequestExpired,
+		/// A period must be at least one block.
+		ZeroPeriod,
+		/// There is no subscription for this spender.
+		NoSubscription,
+		/// Minting this much would go over `MaxMintPerBlock` for the current block.
+		MintRateExceeded,
+		/// Mints above `MintAnnouncementThreshold` must go through `announce_mint`.
+		MintNeedsAnnouncement,
+		/// `MaxAnnouncedMints` mints are already waiting.
+		TooManyAnnouncedMints,
+		/// No announced mint with this id.
+		UnknownMint,
+		/// The announced mint's delay has not passed yet.
+		MintNotDue,
+		/// A parameter change is already scheduled; cancel it first.
+		ParameterChangePending,
+		/// No parameter change is scheduled.
+		NoParameterChange,
+		/// `BurnAddress` cannot send tokens.
+		BurnAddressCannotSend,
+		/// None of the account's savings tranches has matured.
+		NoMaturedSavings,
+		/// A spender cannot delegate its allowance to itself or to the owner.
+		InvalidDelegate,
+		/// Nothing to delegate.
+		EmptyDelegation,
+		/// The delegate already spends another spender's allowance from this owner.
+		DelegateTaken,
+		/// The spender already delegated its allowance to `MaxDelegations` delegates.
+		TooManyDelegations,
+		/// The account is already left out of the circulating supply.
+		AlreadyExcluded,
+		/// The account is not left out of the circulating supply.
+		NotExcluded,
+		/// `MaxExcludedAccounts` accounts are already left out.
+		TooManyExcluded,
+		/// The sender would go over its transfer limit for the current window.
+		TransferLimitExceeded,
+		/// The account has no transfer limit.
+		NoTransferLimit,
+		/// The balance is lower than the amount.
+		FundsUnavailable,
+		/// The account holds no tokens.
+		AccountNotFound,
+		/// The account would be left with less than the minimum balance.
+		BelowMinimum,
+		/// The tokens are frozen, or locked in savings.
+		Frozen,
+		/// A balance or the total supply would go over the largest `Balance` or the supply cap.
+		Overflow,
+		/// The amount is more than the spender's remaining allowance.
+		AllowanceExceeded,
+		/// Less than that is frozen.
+		NotEnoughFrozen,
+		/// The account already holds `MaxReserves` named reserves.
+		TooManyReserves,
+		/// The recipient holds no tokens yet and `MaxHolders` accounts already do.
+		TooManyHolders,
+		/// The proof does not lead from the claimed leaf to the claims root, or no root is set.
+		InvalidClaimProof,
+		/// The signature is not from the claimed Ethereum address over the caller's account.
+		InvalidEthereumSignature,
+		/// The Ethereum address has already claimed its leaf.
+		AlreadyClaimed,
+		/// The claim is for more than what is left of the snapshot's total.
+		ClaimsExhausted,
+		/// The sender already made a transfer with this id.
+		DuplicateTransferId,
+		/// The id was used less than `TransferIdRetention` blocks ago.
+		TransferIdRetained,
+		/// The permit's deadline has passed.
+		PermitExpired,
+		/// The signature is not the owner's over this permit, its current nonce and this chain.
+		InvalidPermitSignature,
+		/// The attestation is for a future block, or older than the latest one.
+		ReserveAttestationOutOfOrder,
+		/// The mint would leave the supply above the attested reserves.
+		InsufficientCollateral,
+		/// Balances and allowances are being moved into `Assets`, or the pallet is being or has
+		/// been sunset, which freezes it.
+		MigrationInProgress,
+		/// A token with this id exists already.
+		AssetExists,
+		/// No token was launched with this id.
+		UnknownAsset,
+		/// Only the admin of the token may do this.
+		NotAssetAdmin,
+		/// The token is paused.
+		TokenPaused,
+		/// The token is not paused.
+		TokenNotPaused,
+		/// The account is frozen.
+		AccountFrozen,
+		/// A vesting schedule must lock something and vest something in every block.
+		InvalidVestingSchedule,
+		/// The recipient is already under `MaxVestingSchedules` vesting schedules.
+		TooManyVestingSchedules,
+		/// The account is under no vesting schedule.
+		NotVesting,
+		/// `OnTransfer` vetoed the movement.
+		TransferVetoed,
+		/// The mint would take `TotalSupply` over `MaxSupply`, or the cap is below it already.
+		SupplyCapExceeded,
+		/// `MaxSupply` was set already.
+		SupplyCapAlreadySet,
+		/// The allowance is past its deadline, or the deadline given has passed already.
+		AllowanceExpired,
+		/// The transfer at `index` of an `AllOrNothing` `transfer_batch` failed, so none of
+		/// them took effect.
+		BatchTransferFailed {
+			index: u16,
+		},
+		/// A fee rate of zero would make every transaction free.
+		ZeroFeeRate,
+	}
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		/// Admits a `permit_unsigned` only if it would succeed in the current state, so that
+		/// free transactions cannot fill blocks with failures. It is tagged with the owner's
+		/// nonce, which identical or conflicting permits share, and lives until its deadline.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::permit_unsigned { owner, spender, value, deadline, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+			let nonce = Self::nonces(owner);
+			with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+				TransactionOutcome::Rollback(Ok(Self::do_permit(
+					owner.clone(),
+					spender.clone(),
+					*value,
+					*deadline,
+					signature,
+				)))
+			})
+			.map_err(|_| InvalidTransaction::Call)?
+			.map_err(|e| match e {
+				Error::<T>::PermitExpired => InvalidTransaction::Stale,
+				Error::<T>::InvalidPermitSignature => InvalidTransaction::BadProof,
+				_ => InvalidTransaction::Call,
+			})?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ValidTransaction::with_tag_prefix("TemplatePermit")
+				.priority(T::PermitPriority::get())
+				.and_provides((owner, nonce))
+				.longevity(deadline.saturating_sub(now).saturated_into::<u64>().saturating_add(1))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			<BlockVolume<T>>::kill();
+			<MintedThisBlock<T>>::kill();
+			let mut weight = T::DbWeight::get().writes(2);
+			if T::StatsWindow::get() > 0 {
+				// `on_finalize` reads the block volume and writes one ring buffer slot.
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			if n == BlockNumberFor::<T>::one() {
+				// The genesis hash is only known once block 1 is being built.
+				weight = weight.saturating_add(Self::refresh_domain_separator());
+			}
+			weight = weight.saturating_add(Self::checkpoint_supply(n));
+			weight = weight.saturating_add(Self::step_allowance_rekey());
+			weight = weight.saturating_add(Self::step_asset_migration());
+			weight = weight.saturating_add(Self::step_sunset());
+			weight = weight.saturating_add(Self::activate_parameters(n));
+			if <AssetMigration<T>>::exists() || <Sunset<T>>::exists() {
+				// Nothing is minted while the pallet is frozen.
+				return weight
+			}
+			weight.saturating_add(Self::mint_block_reward())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			Self::refresh_domain_separator()
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if Self::ensure_not_migrating().is_err() {
+				// Allowances are left alone while the pallet is frozen.
+				return T::DbWeight::get().reads(2)
+			}
+			Self::sweep_expired_allowances(remaining_weight)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			let window = T::StatsWindow::get();
+			if window > 0 {
+				let slot = n.saturated_into::<u32>() % window;
+				<RecentVolume<T>>::insert(slot, (n, <BlockVolume<T>>::get()));
+			}
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			let interval = T::MetricsInterval::get();
+			if interval == 0 || n.saturated_into::<u32>() % interval != 0 {
+				return
+			}
+			if let Err(e) = Self::publish_metrics(n) {
+				log::error!(target: LOG_TARGET, "Failed to publish token metrics at {:?}: {}", n, e);
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	// Indices follow the order calls were added and are never reused: 2 was `set_balance` and
+	// 10 `set_block_reward`.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deprecated: read `Erc20Api::total_supply` instead, which needs no transaction.
+		/// Removed in the next release.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::total_supply())]
+		pub fn total_supply(origin: OriginFor<T>) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let total_supply = Self::_total_supply();
+			Self::deposit_event(Event::TotalSupply { value: total_supply });
+			Ok(())
+		}
+		/// Deprecated: read `Erc20Api::balance_of` instead, which needs no transaction.
+		/// Removed in the next release.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::balance_of())]
+		pub fn balance_of(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
 			let _who = ensure_signed(origin)?;
-			Self::_approve(from, to, value)?;
+			Self::ensure_not_migrating()?;
+			let balance = Self::_balance_of(&user);
+			Self::deposit_event(Event::BalanceOf { who: user, balance });
+			Ok(())
+		}
+		/// Set `who`'s balance to `balance`, minting or burning the difference. Bypasses
+		/// freezes, hooks and every transfer check, but not `MaxHolders` or the supply cap.
+		#[pallet::call_index(72)]
+		#[pallet::weight(T::WeightInfo::force_set_balance())]
+		pub fn force_set_balance(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			balance: T::Balance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let previous = Self::_balance_of(&who);
+			let supply = if balance >= previous {
+				let minted = balance - previous;
+				let supply =
+					Self::_total_supply().checked_add(&minted).ok_or(Error::<T>::Overflow)?;
+				ensure!(minted <= Self::mintable(), Error::<T>::SupplyCapExceeded);
+				supply
+			} else {
+				Self::_total_supply().saturating_sub(previous - balance)
+			};
+			Self::ensure_can_endow(&who, balance)?;
+			Self::_balance_set(&who, balance)?;
+			Self::_total_supply_set(supply);
+			Self::deposit_event(Event::BalanceForced { who, balance });
+			Ok(())
+		}
+		/// Transfer `value` to `to`. The first `FreeTransfersPerPeriod` transfers of at most
+		/// `FreeTransferMaxValue` in each window are refunded their fee.
+		///
+		/// The fee is still charged up front and only refunded afterwards, so a fee-less
+		/// transfer needs funds for the fee like any other. Every account has its own quota:
+		/// spreading transfers over many accounts multiplies the free ones, but each account
+		/// must first be endowed with the native existential deposit, which bounds the
+		/// abuse to what those deposits are worth.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let to = Self::lookup(to)?;
+			Self::_transfer(from.clone(), to, value)?;
+			if value <= T::FreeTransferMaxValue::get() && Self::use_free_transfer(&from) {
+				return Ok(Pays::No.into())
+			}
+			Ok(().into())
+		}
+		/// Set `owner`'s allowance for `spender` to `value` with `owner`'s signature over
+		/// [`Pallet::payload_for_permit`], valid until block `deadline`. Unsigned, so the owner
+		/// needs no funds to pay a fee; the allowance deposit is still reserved from them.
+		///
+		/// The transaction pool only admits permits that would succeed, and each one uses up
+		/// the owner's nonce.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::permit_unsigned())]
+		pub fn permit_unsigned(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::do_permit(owner, spender, value, deadline, &signature)?;
+			Ok(())
+		}
+		/// Like `transfer`, but fails with `DuplicateTransferId` if the caller already sent a
+		/// transfer with `id`, so a retried submission can never pay twice. The id stays used
+		/// until `prune_transfer_ids` frees it, at least `TransferIdRetention` blocks later.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::transfer_with_id())]
+		pub fn transfer_with_id(
+			origin: OriginFor<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+			id: TransferId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let to = Self::lookup(to)?;
+			ensure!(
+				!<UsedTransferIds<T>>::contains_key(&from, id),
+				Error::<T>::DuplicateTransferId
+			);
+			<UsedTransferIds<T>>::insert(&from, id, frame_system::Pallet::<T>::block_number());
+			Self::_transfer_with_id(from.clone(), to, value, Some(id))?;
+			if value <= T::FreeTransferMaxValue::get() && Self::use_free_transfer(&from) {
+				return Ok(Pays::No.into())
+			}
+			Ok(().into())
+		}
+		/// Free ids `sender` used with `transfer_with_id` at least `TransferIdRetention` blocks
+		/// ago, so that state does not grow forever. Anyone may prune; ids that are not in use
+		/// are skipped.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::prune_transfer_ids(ids.len() as u32))]
+		pub fn prune_transfer_ids(
+			origin: OriginFor<T>,
+			sender: T::AccountId,
+			ids: BoundedVec<TransferId, T::MaxBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let now = frame_system::Pallet::<T>::block_number();
+			for id in ids {
+				if let Some(used_at) = <UsedTransferIds<T>>::get(&sender, id) {
+					ensure!(
+						now >= used_at.saturating_add(T::TransferIdRetention::get()),
+						Error::<T>::TransferIdRetained
+					);
+					<UsedTransferIds<T>>::remove(&sender, id);
+				}
+			}
+			Ok(())
+		}
+		/// Set the caller's allowance for `spender` to `value`. No tokens move until `spender`
+		/// spends the allowance with `transfer_from`.
+		///
+		/// With a `deadline`, the allowance can only be spent up to and including that block,
+		/// and `on_idle` removes it some time after. Without one it never expires, whatever
+		/// deadline it had before.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			spender: AccountIdLookupOf<T>,
+			value: T::Balance,
+			deadline: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let spender = Self::lookup(spender)?;
+			Self::_approve(owner, spender, value, deadline)?;
+			Ok(())
+		}
+		/// Move `value` of `from`'s tokens to `to`, drawing on the caller's subscription to
+		/// `from`'s tokens if it covers `value`, and on its allowance otherwise.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let (from, to) = (Self::lookup(from)?, Self::lookup(to)?);
+			Self::_transfer_from(spender, from, to, value)?;
+			Ok(())
+		}
+		/// Set the caller's allowance for `spender` to `new_value`, but only if it currently
+		/// equals `expected_current`. A missing allowance counts as zero.
+		///
+		/// Lets a front-end change an allowance without racing a `transfer_from` that spends
+		/// the old one in the same block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::approve_if())]
+		pub fn approve_if(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			expected_current: T::Balance,
+			new_value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let current = Self::allowance_of(&owner, &spender).unwrap_or_default();
+			ensure!(current == expected_current, Error::<T>::AllowanceMismatch);
+			Self::_allowance_set(&owner, &spender, new_value)?;
+			Self::deposit_event(Event::Approval { from: owner, to: spender, value: new_value });
+			Ok(())
+		}
+		/// Move the full unfrozen balance of each of the caller's deposit sub-accounts at
+		/// `indices` into the caller. Sub-accounts with nothing to move are skipped.
+		///
+		/// No signature from the sub-accounts is needed: they are derived from the caller by
+		/// [`Pallet::derive_deposit_account`], so only their master can reach them. Each move is
+		/// an ordinary transfer, so freezes, `OnTransfer` and the master's receiving policy apply
+		/// and it is reported as one before `Swept`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::sweep(indices.len() as u32))]
+		pub fn sweep(
+			origin: OriginFor<T>,
+			indices: BoundedVec<u32, T::MaxBatchSize>,
+		) -> DispatchResult {
+			let master = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			for index in indices {
+				let deposit = Self::derive_deposit_account(master.clone(), index);
+				Self::settle_demurrage(&deposit)?;
+				let value = Self::_balance_of(&deposit).saturating_sub(Self::locked_of(&deposit));
+				if value.is_zero() {
+					continue
+				}
+				Self::_transfer(deposit, master.clone(), value)?;
+				Self::deposit_event(Event::Swept { master: master.clone(), index, value });
+			}
+			Ok(())
+		}
+		/// Transfer `value` to `to` with attached `data` and `operator_data`, then let
+		/// `Config::TokensReceived` accept or reject the tokens.
+		///
+		/// Use `transfer` when no data is needed: it never calls the handler.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::send())]
+		pub fn send(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			value: T::Balance,
+			data: BoundedVec<u8, T::MaxDataLen>,
+			operator_data: BoundedVec<u8, T::MaxDataLen>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::_transfer(from.clone(), to.clone(), value)?;
+			T::TokensReceived::on_tokens_received(&from, &to, value, &data, &operator_data)?;
+			Self::deposit_event(Event::Sent { from, to, value, data, operator_data });
+			Ok(())
+		}
+		/// Mint `amount` to `to` right away. Only up to `MintAnnouncementThreshold`; larger
+		/// mints must be announced.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(origin: OriginFor<T>, to: T::AccountId, amount: T::Balance) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(
+				amount <= T::MintAnnouncementThreshold::get(),
+				Error::<T>::MintNeedsAnnouncement
+			);
+			Self::ensure_mint_rate(amount)?;
+			Self::_mint(&to, amount)?;
+			Self::deposit_event(Event::Minted { to, amount });
+			Ok(())
+		}
+		/// Queue a mint of `amount` to `to` that `execute_mint` can carry out once `MintDelay`
+		/// blocks have passed.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::announce_mint())]
+		pub fn announce_mint(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let id = <NextMintId<T>>::get();
+			let executable_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::MintDelay::get());
+			<AnnouncedMints<T>>::try_append(AnnouncedMint {
+				id,
+				to: to.clone(),
+				amount,
+				executable_at,
+			})
+			.map_err(|_| Error::<T>::TooManyAnnouncedMints)?;
+			<NextMintId<T>>::put(id.wrapping_add(1));
+			Self::deposit_event(Event::MintAnnounced { id, to, amount, executable_at });
+			Ok(())
+		}
+		/// Carry out announced mint `id` once its delay has passed.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::execute_mint())]
+		pub fn execute_mint(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let announced = Self::take_announced_mint(id)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= announced.executable_at,
+				Error::<T>::MintNotDue
+			);
+			Self::ensure_mint_rate(announced.amount)?;
+			Self::_mint(&announced.to, announced.amount)?;
+			Self::deposit_event(Event::MintExecuted {
+				id,
+				to: announced.to,
+				amount: announced.amount,
+			});
+			Ok(())
+		}
+		/// Call off announced mint `id`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::cancel_mint())]
+		pub fn cancel_mint(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::take_announced_mint(id)?;
+			Self::deposit_event(Event::MintCancelled { id });
+			Ok(())
+		}
+		/// Stop counting `who`'s balance as circulating.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::exclude_from_circulating())]
+		pub fn exclude_from_circulating(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<ExcludedFromCirculating<T>>::try_mutate(|excluded| {
+				ensure!(!excluded.contains(&who), Error::<T>::AlreadyExcluded);
+				excluded.try_push(who.clone()).map_err(|_| Error::<T>::TooManyExcluded)
+			})?;
+			Self::deposit_event(Event::ExcludedFromCirculating { who });
+			Ok(())
+		}
+		/// Count `who`'s balance as circulating again.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::include_in_circulating())]
+		pub fn include_in_circulating(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<ExcludedFromCirculating<T>>::try_mutate(|excluded| {
+				let index =
+					excluded.iter().position(|a| a == &who).ok_or(Error::<T>::NotExcluded)?;
+				excluded.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::IncludedInCirculating { who });
+			Ok(())
+		}
+		/// Replace the token's parameters with `parameters` once `ParameterDelay` blocks have
+		/// passed, giving holders notice of the change. One change is scheduled at a time.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::schedule_parameter_change())]
+		pub fn schedule_parameter_change(
+			origin: OriginFor<T>,
+			parameters: Parameters<T::Balance>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!<PendingParameters<T>>::exists(), Error::<T>::ParameterChangePending);
+			let activates_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ParameterDelay::get());
+			<PendingParameters<T>>::put(ParameterChange {
+				parameters: parameters.clone(),
+				activates_at,
+			});
+			Self::deposit_event(Event::ParameterChangeScheduled { parameters, activates_at });
+			Ok(())
+		}
+		/// Set the caller's allowance for every `(spender, value)` in `approvals`.
+		///
+		/// Each spender may appear only once. Either every allowance is written or, if any of
+		/// them fails, none is.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::batch_approve(approvals.len() as u32))]
+		pub fn batch_approve(
+			origin: OriginFor<T>,
+			approvals: BoundedVec<(T::AccountId, T::Balance), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			for (i, (spender, _)) in approvals.iter().enumerate() {
+				ensure!(
+					!approvals[..i].iter().any(|(seen, _)| seen == spender),
+					Error::<T>::DuplicateSpender
+				);
+			}
+			for (spender, value) in approvals {
+				Self::_allowance_set(&owner, &spender, value)?;
+				Self::deposit_event(Event::Approval { from: owner.clone(), to: spender, value });
+			}
+			Ok(())
+		}
+		/// Move `amount` of the caller's tokens into its staked bucket, paying out any rewards
+		/// earned so far.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::stake())]
+		pub fn stake(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::settle_demurrage(&who)?;
+			let remaining = Self::balance_after_debit(&who, amount)?;
+
+			let mut ledger = match <Ledgers<T>>::get(&who) {
+				Some(ledger) => ledger,
+				None => {
+					frame_system::Pallet::<T>::inc_providers(&who);
+					StakingLedger {
+						active: Zero::zero(),
+						unlocking: Default::default(),
+						reward_debt: 0,
+					}
+				},
+			};
+			Self::_balance_set(&who, remaining)?;
+			Self::pay_rewards(&who, &mut ledger)?;
+			ledger.active = ledger.active.saturating_add(amount);
+			ledger.reward_debt = Self::reward_debt(ledger.active);
+			<Ledgers<T>>::insert(&who, ledger);
+			<TotalStaked<T>>::mutate(|total| *total = total.saturating_add(amount));
+			Self::deposit_event(Event::Staked { who, amount });
+			Ok(())
+		}
+		/// Stop `amount` of the caller's stake from earning rewards. It can be withdrawn
+		/// `UnbondingBlocks` blocks from now.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::unstake())]
+		pub fn unstake(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let mut ledger = <Ledgers<T>>::get(&who).ok_or(Error::<T>::InsufficientStake)?;
+			ensure!(ledger.active >= amount, Error::<T>::InsufficientStake);
+
+			Self::pay_rewards(&who, &mut ledger)?;
+			let unlock_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingBlocks::get());
+			ledger
+				.unlocking
+				.try_push((unlock_at, amount))
+				.map_err(|_| Error::<T>::TooManyUnbondingChunks)?;
+			ledger.active -= amount;
+			ledger.reward_debt = Self::reward_debt(ledger.active);
+			<Ledgers<T>>::insert(&who, ledger);
+			<TotalStaked<T>>::mutate(|total| *total = total.saturating_sub(amount));
+			Self::deposit_event(Event::Unbonded { who, amount, unlock_at });
+			Ok(())
+		}
+		/// Return every unbonding chunk of the caller that has reached its unlock block to the
+		/// free balance.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let mut ledger = <Ledgers<T>>::get(&who).ok_or(Error::<T>::NothingToWithdraw)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut amount = T::Balance::zero();
+			ledger.unlocking.retain(|(unlock_at, value)| {
+				let matured = *unlock_at <= now;
+				if matured {
+					amount = amount.saturating_add(*value);
+				}
+				!matured
+			});
+			ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+			Self::settle_demurrage(&who)?;
+			Self::_balance_set(&who, Self::_balance_of(&who).saturating_add(amount))?;
+			if ledger.active.is_zero() && ledger.unlocking.is_empty() {
+				<Ledgers<T>>::remove(&who);
+				let _ = frame_system::Pallet::<T>::dec_providers(&who);
+			} else {
+				<Ledgers<T>>::insert(&who, ledger);
+			}
+			Self::deposit_event(Event::Withdrawn { who, amount });
+			Ok(())
+		}
+		/// Send `amount` of the caller's tokens to `dest_address` on chain `dest_chain`.
+		///
+		/// The tokens are locked in `BridgePot`, or burned when `BridgeBurns` is set, and the
+		/// transfer is tagged with the next outbound nonce.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::bridge_out())]
+		pub fn bridge_out(
+			origin: OriginFor<T>,
+			dest_chain: u32,
+			dest_address: BoundedVec<u8, T::MaxAddressLen>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			Self::settle_demurrage(&from)?;
+			let remaining = Self::balance_after_debit(&from, amount)?;
+			Self::record_outflow(&from, amount)?;
+			Self::_balance_set(&from, remaining)?;
+			if T::BridgeBurns::get() {
+				Self::burn_supply(&from, amount);
+			} else {
+				let pot = T::BridgePot::get();
+				Self::_balance_set(&pot, Self::_balance_of(&pot).saturating_add(amount))?;
+			}
+			let nonce = <OutboundNonce<T>>::mutate(|next| {
+				let nonce = *next;
+				*next = next.saturating_add(1);
+				nonce
+			});
+			Self::deposit_event(Event::BridgedOut {
+				nonce,
+				from,
+				dest_chain,
+				dest_address,
+				amount,
+			});
+			Ok(())
+		}
+		/// Release inbound bridge transfer `inbound_nonce` to `to`, from `BridgePot` or by
+		/// minting when `BridgeBurns` is set. Each nonce is released at most once.
+		///
+		/// Relayers release transfers with `vote_bridge_in`; this is the governance path,
+		/// e.g. to settle a nonce frozen by conflicting votes. It discards any votes on the
+		/// nonce.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::bridge_in())]
+		pub fn bridge_in(
+			origin: OriginFor<T>,
+			inbound_nonce: u64,
+			to: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			T::BridgeOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<InboundVotes<T>>::remove(inbound_nonce);
+			Self::release_inbound(inbound_nonce, to, amount)
+		}
+		/// Replace the bridge relayer set. Inbound transfers are released once `threshold`
+		/// relayers have voted for the same payload.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_relayers(relayers.len() as u32))]
+		pub fn set_relayers(
+			origin: OriginFor<T>,
+			relayers: BoundedVec<T::AccountId, T::MaxRelayers>,
+			threshold: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(
+				threshold > 0 && threshold as usize <= relayers.len(),
+				Error::<T>::InvalidThreshold
+			);
+			for (i, relayer) in relayers.iter().enumerate() {
+				ensure!(!relayers[..i].contains(relayer), Error::<T>::DuplicateRelayer);
+			}
+			<Relayers<T>>::put(relayers);
+			<RelayerThreshold<T>>::put(threshold);
+			Self::deposit_event(Event::RelayersSet { threshold });
+			Ok(())
+		}
+		/// Vote, as a relayer, to release inbound transfer `nonce` of `amount` to `to`.
+		///
+		/// The transfer is released by the vote that brings the payload to the threshold. A
+		/// vote for a different payload than the first one freezes the nonce until governance
+		/// settles it with `bridge_in`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::vote_bridge_in())]
+		pub fn vote_bridge_in(
+			origin: OriginFor<T>,
+			nonce: u64,
+			to: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let relayers = <Relayers<T>>::get();
+			ensure!(relayers.contains(&relayer), Error::<T>::NotRelayer);
+			ensure!(!<ProcessedInbound<T>>::contains_key(nonce), Error::<T>::InboundNonceProcessed);
+
+			let mut proposal = <InboundVotes<T>>::get(nonce).unwrap_or_else(|| InboundProposal {
+				to: to.clone(),
+				amount,
+				voters: Default::default(),
+				frozen: false,
+			});
+			ensure!(!proposal.frozen, Error::<T>::InboundNonceFrozen);
+			ensure!(!proposal.voters.contains(&relayer), Error::<T>::DuplicateVote);
+			if proposal.to != to || proposal.amount != amount {
+				proposal.frozen = true;
+				<InboundVotes<T>>::insert(nonce, proposal);
+				Self::deposit_event(Event::InboundConflict { nonce, relayer });
+				// Keep the freeze: returning an error would roll it back.
+				return Ok(())
+			}
+			// Votes of relayers removed since they voted no longer count.
+			proposal.voters.retain(|voter| relayers.contains(voter));
+			proposal.voters.try_push(relayer.clone()).map_err(|_| Error::<T>::NotRelayer)?;
+			Self::deposit_event(Event::InboundVoted { nonce, relayer });
+
+			if proposal.voters.len() < <RelayerThreshold<T>>::get() as usize {
+				<InboundVotes<T>>::insert(nonce, proposal);
+				return Ok(())
+			}
+			<InboundVotes<T>>::remove(nonce);
+			Self::release_inbound(nonce, to, amount)
+		}
+		/// Accept tokens from anyone (`enabled`, the default) or only from senders allowed with
+		/// `allow_sender`. Minted tokens have no sender and are refused in strict mode.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::accept_incoming())]
+		pub fn accept_incoming(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			if enabled {
+				<StrictReceivers<T>>::remove(&who);
+			} else {
+				<StrictReceivers<T>>::insert(&who, ());
+			}
+			Self::deposit_event(Event::IncomingPolicySet { who, accept_all: enabled });
+			Ok(())
+		}
+		/// Accept tokens from `who` while in strict mode.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::allow_sender())]
+		pub fn allow_sender(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			let recipient = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			<AllowedSenders<T>>::insert((&recipient, &who), ());
+			Self::deposit_event(Event::SenderAllowed { recipient, sender: who });
+			Ok(())
+		}
+		/// Stop accepting tokens from `who` while in strict mode.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::disallow_sender())]
+		pub fn disallow_sender(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			let recipient = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			<AllowedSenders<T>>::remove((&recipient, &who));
+			Self::deposit_event(Event::SenderDisallowed { recipient, sender: who });
+			Ok(())
+		}
+		/// Let `spender` pull up to `amount_per_period` of the caller's tokens with
+		/// `transfer_from` in every `period_blocks` blocks, starting with the current block.
+		/// Replaces any existing subscription for `spender`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::approve_subscription())]
+		pub fn approve_subscription(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			amount_per_period: T::Balance,
+			period_blocks: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!period_blocks.is_zero(), Error::<T>::ZeroPeriod);
+			<Subscriptions<T>>::insert(
+				(&owner, &spender),
+				Subscription {
+					limit: amount_per_period,
+					period: period_blocks,
+					window_start: frame_system::Pallet::<T>::block_number(),
+					spent_in_window: Zero::zero(),
+				},
+			);
+			Self::deposit_event(Event::SubscriptionApproved {
+				owner,
+				spender,
+				limit: amount_per_period,
+				period: period_blocks,
+			});
+			Ok(())
+		}
+		/// End `spender`'s subscription to the caller's tokens. The regular allowance is left
+		/// as it is.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::cancel_subscription())]
+		pub fn cancel_subscription(origin: OriginFor<T>, spender: T::AccountId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			<Subscriptions<T>>::take((&owner, &spender)).ok_or(Error::<T>::NoSubscription)?;
+			Self::deposit_event(Event::SubscriptionCancelled { owner, spender });
+			Ok(())
+		}
+		/// Cap what `who` may send with `transfer`, `transfer_from`, `approve` and `bridge_out`
+		/// to `max_per_period` in every `period_blocks` blocks, starting a fresh window now.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_transfer_limit())]
+		pub fn set_transfer_limit(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			max_per_period: T::Balance,
+			period_blocks: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!period_blocks.is_zero(), Error::<T>::ZeroPeriod);
+			let limit = TransferLimit {
+				max_per_period,
+				period: period_blocks,
+				window_start: frame_system::Pallet::<T>::block_number(),
+				sent_in_window: Zero::zero(),
+			};
+			if <TransferLimits<T>>::mutate(&who, |old| old.replace(limit).is_none()) {
+				<LimitedAccounts<T>>::mutate(|count| *count = count.saturating_add(1));
+			}
+			Self::deposit_event(Event::TransferLimitSet {
+				who,
+				max_per_period,
+				period: period_blocks,
+			});
+			Ok(())
+		}
+		/// Lift `who`'s transfer limit.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::clear_transfer_limit())]
+		pub fn clear_transfer_limit(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<TransferLimits<T>>::take(&who).ok_or(Error::<T>::NoTransferLimit)?;
+			<LimitedAccounts<T>>::mutate(|count| *count = count.saturating_sub(1));
+			Self::deposit_event(Event::TransferLimitCleared { who });
+			Ok(())
+		}
+		/// Ask `from` to pay `amount` to the caller. The request can be accepted for
+		/// `RequestTtl` blocks.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::request_payment())]
+		pub fn request_payment(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			amount: T::Balance,
+			memo: BoundedVec<u8, T::MaxDataLen>,
+		) -> DispatchResult {
+			let requester = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let id = <NextRequestId<T>>::get();
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RequestTtl::get());
+			<PaymentRequests<T>>::try_mutate(&from, |requests| {
+				Self::prune_expired_requests(requests);
+				requests
+					.try_push(PaymentRequest {
+						id,
+						requester: requester.clone(),
+						amount,
+						memo: memo.clone(),
+						expires_at,
+					})
+					.map_err(|_| Error::<T>::TooManyRequests)
+			})?;
+			<NextRequestId<T>>::put(id.wrapping_add(1));
+			Self::deposit_event(Event::PaymentRequested {
+				id,
+				requester,
+				payer: from,
+				amount,
+				memo,
+			});
+			Ok(())
+		}
+		/// Pay the caller's open request `request_id`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::accept_request())]
+		pub fn accept_request(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let request = Self::take_request(&payer, request_id)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= request.expires_at,
+				Error::<T>::RequestExpired
+			);
+			Self::_transfer(payer.clone(), request.requester, request.amount)?;
+			Self::deposit_event(Event::PaymentRequestAccepted { id: request_id, payer });
+			Ok(())
+		}
+		/// Turn down the caller's open request `request_id`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::reject_request())]
+		pub fn reject_request(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::take_request(&payer, request_id)?;
+			Self::deposit_event(Event::PaymentRequestRejected { id: request_id, payer });
+			Ok(())
+		}
+		/// Withdraw request `request_id` the caller made of `payer`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::cancel_request())]
+		pub fn cancel_request(
+			origin: OriginFor<T>,
+			payer: T::AccountId,
+			request_id: u64,
+		) -> DispatchResult {
+			let requester = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let request = Self::take_request(&payer, request_id)?;
+			ensure!(request.requester == requester, Error::<T>::UnknownRequest);
+			Self::deposit_event(Event::PaymentRequestCancelled { id: request_id, payer });
+			Ok(())
+		}
+		/// Move everything the caller may still spend of `owner`'s tokens to `to`, and remove the
+		/// allowance but for what the caller delegated of it.
+		///
+		/// Moves the smaller of the allowance and `owner`'s balance rather than failing on a
+		/// shortfall; `TransferFrom` carries the amount actually moved.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::spend_all_allowance())]
+		pub fn spend_all_allowance(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_frozen(&spender)?;
+			let allowance =
+				Self::allowance_of(&owner, &spender).ok_or(Error::<T>::ApprovalNotGranted)?;
+			Self::ensure_allowance_live(&owner, &spender)?;
+			let delegated = Self::delegated_of(&owner, &spender);
+			let settled = Self::settle_demurrage(&owner)?;
+			let value = allowance.saturating_sub(delegated).min(Self::_balance_of(&owner));
+			Self::_allowance_set(&owner, &spender, delegated.min(allowance))?;
+			let demurrage = settled + Self::_spend(&owner, &to, value)?;
+			Self::record_spend(&owner, &spender, value);
+			Self::deposit_transfer_from_event(owner, to, value, demurrage);
+			Ok(())
+		}
+		/// Allow `spender` to spend `value` of the caller's tokens for `purpose` only. Scoped
+		/// allowances count against `MaxApprovalsPerOwner` like plain ones, and
+		/// [`DEFAULT_PURPOSE`] sets the plain allowance.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::approve_scoped())]
+		pub fn approve_scoped(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			purpose: Purpose,
+			value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			if purpose == DEFAULT_PURPOSE {
+				Self::_allowance_set(&owner, &spender, value)?;
+			} else {
+				Self::_scoped_allowance_set(&owner, &spender, purpose, value)?;
+			}
+			Self::deposit_event(Event::ScopedApproval { owner, spender, purpose, value });
+			Ok(())
+		}
+		/// Move `value` of `owner`'s tokens to `to` out of the caller's `purpose` allowance.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::transfer_from_scoped())]
+		pub fn transfer_from_scoped(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			purpose: Purpose,
+			to: T::AccountId,
+			value: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_frozen(&spender)?;
+			if purpose == DEFAULT_PURPOSE {
+				Self::charge_allowance(&owner, &spender, value)?;
+			} else {
+				let (allowance, _) = <ScopedAllowances<T>>::get((&owner, &spender, purpose))
+					.ok_or(Error::<T>::ApprovalNotGranted)?;
+				ensure!(allowance >= value, Error::<T>::AllowanceExceeded);
+				Self::_scoped_allowance_set(&owner, &spender, purpose, allowance - value)?;
+			}
+			Self::_spend(&owner, &to, value)?;
+			Self::record_spend(&owner, &spender, value);
+			Self::deposit_event(Event::ScopedTransfer { owner, spender, purpose, to, value });
+			Ok(())
+		}
+		/// Move the caller's whole token position to `new_account`, which must hold none: the
+		/// balance, the allowances it granted, scoped or not, with their deposits, and its stake,
+		/// transfer limit, free transfer quota, frozen amount, savings mode and tranches, vesting
+		/// schedules, what it sent in the current `KycWindow`, open payment requests and
+		/// circulating supply exclusion.
+		///
+		/// Nothing indexes allowances by spender, so the plain ones granted to the caller move
+		/// only for the owners listed in `approvers`, and scoped ones not at all. Delegations of
+		/// the allowances that move go with them, but those made to the caller stay. Subscriptions,
+		/// the receiving policy and launched-token positions are not moved, and `SpentBy` history
+		/// as an owner is dropped. `new_account` must not have granted launched-token allowances
+		/// either, so the moved ones still fit `MaxApprovalsPerOwner`.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::migrate_account(approvers.len() as u32))]
+		pub fn migrate_account(
+			origin: OriginFor<T>,
+			new_account: T::AccountId,
+			approvers: BoundedVec<T::AccountId, T::MaxBatchSize>,
+		) -> DispatchResult {
+			let old = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let new = new_account;
+			Self::ensure_not_frozen(&old)?;
+			Self::ensure_not_frozen(&new)?;
+			ensure!(
+				!<BalanceOf<T>>::contains_key(T::NativeAsset::get(), &new) &&
+					!<Approvals<T>>::contains_key(&new) &&
+					!<ScopedApprovalCount<T>>::contains_key(&new) &&
+					!<AssetApprovalCount<T>>::contains_key(&new) &&
+					!<Ledgers<T>>::contains_key(&new) &&
+					!<TransferLimits<T>>::contains_key(&new) &&
+					!<FreeTransfers<T>>::contains_key(&new) &&
+					!<KycOutflow<T>>::contains_key(&new) &&
+					!<FrozenBalance<T>>::contains_key(&new) &&
+					!<SavingsLock<T>>::contains_key(&new) &&
+					!<SavingTranches<T>>::contains_key(&new) &&
+					!<VestingSchedules<T>>::contains_key(&new) &&
+					!<ReservedBalance<T>>::contains_key(&new) &&
+					!<PaymentRequests<T>>::contains_key(&new) &&
+					!<ExcludedFromCirculating<T>>::get().contains(&new),
+				Error::<T>::DestinationNotEmpty
+			);
+
+			let spenders = <Approvals<T>>::take(&old);
+			for spender in spenders.iter() {
+				let value = Self::take_allowance(&old, spender).unwrap_or_default();
+				<Allowances<T>>::insert((T::NativeAsset::get(), &new, spender), value);
+				let deadline = <AllowanceExpiry<T>>::get((&old, spender));
+				Self::set_allowance_expiry(&old, spender, None);
+				Self::set_allowance_expiry(&new, spender, deadline);
+				if let Some(deposit) = <AllowanceDeposits<T>>::take((&old, spender)) {
+					T::Currency::unreserve(&old, deposit);
+					T::Currency::reserve(&new, deposit)
+						.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+					<AllowanceDeposits<T>>::insert((&new, spender), deposit);
+				}
+				let delegations = <Delegations<T>>::take(&old, spender);
+				for (delegate, _) in delegations.iter() {
+					<DelegatedAllowances<T>>::remove(&old, delegate);
+					<DelegatedAllowances<T>>::insert(&new, delegate, spender);
+				}
+				Self::put_delegations(&new, spender, delegations);
+			}
+			if !spenders.is_empty() {
+				<Approvals<T>>::insert(&new, spenders);
+			}
+			// Bounded by `ScopedApprovalCount`, and so by `MaxApprovalsPerOwner`.
+			let scoped: Vec<_> = <ScopedAllowances<T>>::drain_prefix((&old,)).collect();
+			for ((spender, purpose), (value, deposit)) in scoped {
+				T::Currency::unreserve(&old, deposit);
+				T::Currency::reserve(&new, deposit)
+					.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+				<ScopedAllowances<T>>::insert((&new, &spender, purpose), (value, deposit));
+			}
+			let count = <ScopedApprovalCount<T>>::take(&old);
+			if count > 0 {
+				<ScopedApprovalCount<T>>::insert(&new, count);
+			}
+
+			for owner in approvers {
+				ensure!(
+					Self::allowance_of(&owner, &new).is_none(),
+					Error::<T>::DestinationNotEmpty
+				);
+				let value =
+					Self::take_allowance(&owner, &old).ok_or(Error::<T>::ApprovalNotGranted)?;
+				<Allowances<T>>::insert((T::NativeAsset::get(), &owner, &new), value);
+				<Approvals<T>>::mutate(&owner, |spenders| {
+					spenders.iter_mut().filter(|s| **s == old).for_each(|s| *s = new.clone())
+				});
+				if let Some(deposit) = <AllowanceDeposits<T>>::take((&owner, &old)) {
+					<AllowanceDeposits<T>>::insert((&owner, &new), deposit);
+				}
+				let deadline = <AllowanceExpiry<T>>::get((&owner, &old));
+				Self::set_allowance_expiry(&owner, &old, None);
+				Self::set_allowance_expiry(&owner, &new, deadline);
+				let delegations = <Delegations<T>>::take(&owner, &old);
+				for (delegate, _) in delegations.iter() {
+					<DelegatedAllowances<T>>::insert(&owner, delegate, &new);
+				}
+				Self::put_delegations(&owner, &new, delegations);
+			}
+
+			// After the deposits are released, so that reaping `old` is not blocked by them.
+			Self::settle_demurrage(&old)?;
+			let balance = Self::_balance_of(&old);
+			Self::_balance_set(&new, balance)?;
+			Self::_balance_set(&old, Zero::zero())?;
+			if let Some(ledger) = <Ledgers<T>>::take(&old) {
+				// The ledger's provider reference moves with it.
+				frame_system::Pallet::<T>::inc_providers(&new);
+				let _ = frame_system::Pallet::<T>::dec_providers(&old);
+				<Ledgers<T>>::insert(&new, ledger);
+			}
+			if let Some(limit) = <TransferLimits<T>>::take(&old) {
+				<TransferLimits<T>>::insert(&new, limit);
+			}
+			if let Some(quota) = <FreeTransfers<T>>::take(&old) {
+				<FreeTransfers<T>>::insert(&new, quota);
+			}
+			if let Some(outflow) = <KycOutflow<T>>::take(&old) {
+				<KycOutflow<T>>::insert(&new, outflow);
+			}
+			if let Some(frozen) = <FrozenBalance<T>>::take(&old) {
+				<FrozenBalance<T>>::insert(&new, frozen);
+			}
+			if let Some(lock) = <SavingsLock<T>>::take(&old) {
+				<SavingsLock<T>>::insert(&new, lock);
+			}
+			let tranches = <SavingTranches<T>>::take(&old);
+			if !tranches.is_empty() {
+				<SavingTranches<T>>::insert(&new, tranches);
+			}
+			let schedules = <VestingSchedules<T>>::take(&old);
+			if !schedules.is_empty() {
+				<VestingSchedules<T>>::insert(&new, schedules);
+			}
+			if let Some(reserved) = <ReservedBalance<T>>::take(&old) {
+				// Like the ledger's, the reserved bucket's provider reference moves with it.
+				frame_system::Pallet::<T>::inc_providers(&new);
+				let _ = frame_system::Pallet::<T>::dec_providers(&old);
+				<ReservedBalance<T>>::insert(&new, reserved);
+			}
+			if let Some(reserves) = <NamedReserves<T>>::take(&old) {
+				<NamedReserves<T>>::insert(&new, reserves);
+			}
+			if let Some(requests) = <PaymentRequests<T>>::take(&old) {
+				<PaymentRequests<T>>::insert(&new, requests);
+			}
+			<ExcludedFromCirculating<T>>::mutate(|excluded| {
+				excluded
+					.iter_mut()
+					.filter(|who| **who == old)
+					.for_each(|who| *who = new.clone())
+			});
+			// Spending history stays with the key it happened to; anything past the batch is
+			// left to `prune_spent_by`.
+			let _ = <SpentBy<T>>::clear_prefix(&old, T::MaxBatchSize::get(), None);
+
+			Self::deposit_event(Event::AccountMigrated { old, new });
+			Ok(())
+		}
+		/// Allow one transfer of exactly `value` from `from` to `to`, up to and including block
+		/// `expiry`. Replaces any ticket for the same transfer.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::attest_transfer())]
+		pub fn attest_transfer(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ComplianceOracle::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(
+				expiry >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::AttestationExpired
+			);
+			<Attestations<T>>::insert((&from, &to, value), expiry);
+			Self::deposit_event(Event::TransferAttested { from, to, value, expiry });
+			Ok(())
+		}
+		/// Remove up to `MaxBatchSize` entries of the spending history of `owner`, which must
+		/// hold no balance, e.g. after it was reaped or migrated with `migrate_account`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::prune_spent_by())]
+		pub fn prune_spent_by(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(
+				!<BalanceOf<T>>::contains_key(T::NativeAsset::get(), &owner),
+				Error::<T>::OwnerNotReaped
+			);
+			let _ = <SpentBy<T>>::clear_prefix(&owner, T::MaxBatchSize::get(), None);
+			Ok(())
+		}
+		/// Stop `amount` more of `who`'s balance from being moved out, on top of what is already
+		/// frozen. Only the part of the balance above the frozen amount can be transferred,
+		/// spent by others, staked or bridged out.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::freeze_amount())]
+		pub fn freeze_amount(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let frozen = Self::frozen_of(&who).checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			<FrozenBalance<T>>::insert(&who, frozen);
+			Self::deposit_event(Event::AmountFrozen { who, amount, frozen });
+			Ok(())
+		}
+		/// Release `amount` of `who`'s frozen balance.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::unfreeze_amount())]
+		pub fn unfreeze_amount(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let frozen =
+				Self::frozen_of(&who).checked_sub(&amount).ok_or(Error::<T>::NotEnoughFrozen)?;
+			if frozen.is_zero() {
+				<FrozenBalance<T>>::remove(&who);
+			} else {
+				<FrozenBalance<T>>::insert(&who, frozen);
+			}
+			Self::deposit_event(Event::AmountUnfrozen { who, amount, frozen });
+			Ok(())
+		}
+		/// Freeze `who` as a whole: it can no longer send or receive tokens, spend allowances
+		/// or move its position away with `migrate_account`. Its balance can still be burned.
+		#[pallet::call_index(65)]
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<Frozen<T>>::insert(&who, ());
+			Self::deposit_event(Event::AccountFrozen { who });
+			Ok(())
+		}
+		/// Lift `freeze_account` from `who`.
+		#[pallet::call_index(66)]
+		#[pallet::weight(T::WeightInfo::thaw_account())]
+		pub fn thaw_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<Frozen<T>>::remove(&who);
+			Self::deposit_event(Event::AccountThawed { who });
+			Ok(())
+		}
+		/// Run `calls` in order as the caller, all or nothing: the first failing action rolls
+		/// back the ones before it and fails with its index. The reason is logged.
+		///
+		/// Weighs the sum of its actions; a failure refunds the actions it did not reach.
+		#[pallet::call_index(42)]
+		#[pallet::weight(Self::multicall_weight(calls))]
+		pub fn multicall(
+			origin: OriginFor<T>,
+			calls: BoundedVec<TokenAction<T>, T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let mut used = Weight::zero();
+			for (index, action) in calls.into_iter().enumerate() {
+				used.saturating_accrue(Self::action_weight(&action));
+				Self::do_action(&who, action).map_err(|e| {
+					log::debug!(target: LOG_TARGET, "Multicall action {} failed: {:?}", index, e);
+					Error::<T>::MulticallFailed { index: index as u16 }.with_weight(used)
+				})?;
+			}
+			Ok(().into())
+		}
+		/// Record that `reserves` back the token as of block `as_of_block`, superseding the
+		/// previous attestation. Emits `UnderCollateralized` if the supply is above them.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::post_reserve_attestation())]
+		pub fn post_reserve_attestation(
+			origin: OriginFor<T>,
+			reserves: T::Balance,
+			as_of_block: BlockNumberFor<T>,
+			reference: BoundedVec<u8, T::MaxDataLen>,
+		) -> DispatchResult {
+			T::ReserveOracle::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(
+				as_of_block <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ReserveAttestationOutOfOrder
+			);
+			let attestation = ReserveAttestation { reserves, as_of: as_of_block, reference };
+			if let Some(previous) = <LatestReserveAttestation<T>>::get() {
+				ensure!(previous.as_of <= as_of_block, Error::<T>::ReserveAttestationOutOfOrder);
+				<ReserveAttestationHistory<T>>::mutate(|history| {
+					if history.is_full() && !history.is_empty() {
+						history.remove(0);
+					}
+					// Only fails if no history is kept at all.
+					let _ = history.try_push(previous);
+				});
+			}
+			<LatestReserveAttestation<T>>::put(attestation);
+			Self::deposit_event(Event::ReservesAttested { reserves, as_of: as_of_block });
+			let supply = Self::_total_supply();
+			if supply > reserves {
+				Self::deposit_event(Event::UnderCollateralized { supply, reserves });
+			}
+			Ok(())
+		}
+		/// Commit to a snapshot of Ethereum balances: `merkle_root` over its `(eth_address,
+		/// amount)` leaves, of which at most `total` tokens may be claimed. Replaces the previous
+		/// snapshot; addresses that already claimed stay claimed.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_claims_root())]
+		pub fn set_claims_root(
+			origin: OriginFor<T>,
+			merkle_root: [u8; 32],
+			total: T::Balance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<ClaimsRoot<T>>::put(merkle_root);
+			<ClaimsLeft<T>>::put(total);
+			Self::deposit_event(Event::ClaimsRootSet { root: merkle_root, total });
+			Ok(())
+		}
+		/// Mint to the caller the `amount` the claims snapshot grants `eth_address`. `proof` leads
+		/// from the leaf to the root, and `eth_signature` is `eth_address`'s `personal_sign` of
+		/// the caller's account, see [`Pallet::claim_message`]. Each address claims once.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			eth_address: EthereumAddress,
+			amount: T::Balance,
+			proof: BoundedVec<[u8; 32], T::MaxClaimProofLen>,
+			eth_signature: EcdsaSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!<ClaimedAddresses<T>>::contains_key(eth_address), Error::<T>::AlreadyClaimed);
+			ensure!(
+				Self::claims_root() ==
+					Some(Self::claims_root_of(Self::claim_leaf(&eth_address, amount), &proof)),
+				Error::<T>::InvalidClaimProof
+			);
+			ensure!(
+				Self::eth_recover(&eth_signature, &who) == Some(eth_address),
+				Error::<T>::InvalidEthereumSignature
+			);
+			let left =
+				Self::claims_left().checked_sub(&amount).ok_or(Error::<T>::ClaimsExhausted)?;
+			Self::ensure_mint_rate(amount)?;
+			Self::_mint(&who, amount)?;
+			<ClaimsLeft<T>>::put(left);
+			<ClaimedAddresses<T>>::insert(eth_address, ());
+			Self::deposit_event(Event::Claimed { who, eth_address, amount });
+			Ok(())
+		}
+		/// Start moving every balance and allowance into `asset` of `T::Assets`, creating it
+		/// as a sufficient asset owned by the pallet account if it does not exist yet.
+		///
+		/// `on_initialize` then moves `AssetMigrationBatch` balances, then allowances, per
+		/// block, deleting each from this pallet as it arrives in `asset`. Every call into
+		/// this pallet fails with `MigrationInProgress` until it finishes. There is no way
+		/// back. Reserved, frozen and staked tokens, scoped allowances and every other item
+		/// stay where they are.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::migrate_to_assets())]
+		pub fn migrate_to_assets(origin: OriginFor<T>, asset: AssetIdOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_not_migrating()?;
+			if !T::Assets::asset_exists(asset) {
+				let admin = T::PalletId::get().into_account_truncating();
+				T::Assets::create(asset, admin, true, One::one())?;
+			}
+			<AssetMigration<T>>::put(AssetMigrationStatus {
+				asset,
+				supply: Self::_total_supply(),
+				issuance: T::Assets::total_issuance(asset),
+				balances_done: false,
+				resume_after: None,
+				holders: 0,
+				tokens: Zero::zero(),
+				allowances: 0,
+				stranded: 0,
+				dropped_allowances: 0,
+			});
+			Self::deposit_event(Event::AssetMigrationStarted { asset });
+			Ok(())
+		}
+		/// Call off the scheduled parameter change before it takes effect.
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::cancel_parameter_change())]
+		pub fn cancel_parameter_change(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let change = <PendingParameters<T>>::take().ok_or(Error::<T>::NoParameterChange)?;
+			Self::deposit_event(Event::ParameterChangeCancelled { parameters: change.parameters });
+			Ok(())
+		}
+		/// Delegate `amount` of the caller's allowance from `owner` to `delegate`, which can then
+		/// spend it through `transfer_from` as its own allowance. Delegating to the same delegate
+		/// again adds to its share.
+		///
+		/// Delegated tokens stay part of the caller's allowance, but the caller can no longer
+		/// spend them itself. Lowering or revoking the allowance revokes the newest delegations
+		/// until the rest fit in it.
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::delegate_allowance())]
+		pub fn delegate_allowance(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(delegate != spender && delegate != owner, Error::<T>::InvalidDelegate);
+			ensure!(!amount.is_zero(), Error::<T>::EmptyDelegation);
+			let allowance =
+				Self::allowance_of(&owner, &spender).ok_or(Error::<T>::ApprovalNotGranted)?;
+			if let Some(delegator) = <DelegatedAllowances<T>>::get(&owner, &delegate) {
+				ensure!(delegator == spender, Error::<T>::DelegateTaken);
+			}
+			let mut delegations = <Delegations<T>>::get(&owner, &spender);
+			let delegated = delegations
+				.iter()
+				.fold(T::Balance::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			ensure!(allowance.saturating_sub(delegated) >= amount, Error::<T>::AllowanceExceeded);
+			match delegations.iter_mut().find(|(d, _)| *d == delegate) {
+				Some((_, share)) => *share += amount,
+				None => delegations
+					.try_push((delegate.clone(), amount))
+					.map_err(|_| Error::<T>::TooManyDelegations)?,
+			}
+			<Delegations<T>>::insert(&owner, &spender, delegations);
+			<DelegatedAllowances<T>>::insert(&owner, &delegate, &spender);
+			Self::deposit_event(Event::AllowanceDelegated { owner, spender, delegate, amount });
+			Ok(())
+		}
+		/// Lock everything the caller receives from now on for `lock_blocks`, or stop doing so
+		/// with `None`. Each transfer or mint to the caller becomes a tranche that cannot be moved
+		/// out until it matures, so a leaked key cannot spend new funds straight away.
+		///
+		/// Leaving savings mode or shortening the lock leaves pending tranches as they are.
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::set_savings_mode())]
+		pub fn set_savings_mode(
+			origin: OriginFor<T>,
+			lock_blocks: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			match lock_blocks {
+				Some(lock) => {
+					ensure!(!lock.is_zero(), Error::<T>::ZeroPeriod);
+					<SavingsLock<T>>::insert(&who, lock);
+				},
+				None => <SavingsLock<T>>::remove(&who),
+			}
+			Self::deposit_event(Event::SavingsModeSet { who, lock_blocks });
+			Ok(())
+		}
+		/// Clear the caller's matured savings tranches. Their tokens are already free to move;
+		/// this only frees the storage.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::release_matured())]
+		pub fn release_matured(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut tranches = <SavingTranches<T>>::get(&who);
+			let value = tranches
+				.iter()
+				.filter(|(until, _)| *until <= now)
+				.fold(T::Balance::zero(), |total, (_, value)| total.saturating_add(*value));
+			ensure!(!value.is_zero(), Error::<T>::NoMaturedSavings);
+			tranches.retain(|(until, _)| *until > now);
+			if tranches.is_empty() {
+				<SavingTranches<T>>::remove(&who);
+			} else {
+				<SavingTranches<T>>::insert(&who, tranches);
+			}
+			Self::deposit_event(Event::SavingsReleased { who, value });
+			Ok(())
+		}
+		/// Wind the pallet down for good. Every call into it fails with `MigrationInProgress`
+		/// from now on, and `on_initialize` walks `SunsetBatch` holders per block, clearing the
+		/// allowances they granted, scoped or not, with their deposits, and their frozen amount,
+		/// savings, vesting schedules, transfer limit, free transfer quota and `KycWindow`
+		/// outflow, then moving their balance to the `Successor` or burning it and paying them
+		/// their share of the `Refund` account's native balance.
+		///
+		/// A holder that cannot be wound down keeps its tokens. Reserved and staked tokens stay
+		/// where they are.
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::initiate_sunset())]
+		pub fn initiate_sunset(
+			origin: OriginFor<T>,
+			destination: SunsetDestination<T::AccountId>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_not_migrating()?;
+			let pool = match &destination {
+				SunsetDestination::Successor(_) => Zero::zero(),
+				SunsetDestination::Refund(pot) => T::Currency::free_balance(pot),
+			};
+			<Sunset<T>>::put(SunsetStatus {
+				destination: destination.clone(),
+				supply: Self::_total_supply(),
+				pool,
+				resume_after: None,
+				holders: 0,
+				tokens: Zero::zero(),
+				refunded: Zero::zero(),
+				stranded: 0,
+				finished: false,
+			});
+			Self::deposit_event(Event::SunsetInitiated { destination });
+			Ok(())
+		}
+		/// Destroy `value` of `from`'s tokens, taking them out of `TotalSupply`. Frozen tokens
+		/// and locked savings cannot be burned.
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, from: T::AccountId, value: T::Balance) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			Self::before_transfer(Some(&from), None, value)?;
+			Self::settle_demurrage(&from)?;
+			let remaining = Self::balance_after_debit(&from, value)?;
+			Self::_balance_set(&from, remaining)?;
+			Self::burn_supply(&from, value);
+			Self::remove_dust(&from)?;
+			T::OnTransfer::after_transfer(Some(&from), None, value);
+			Self::deposit_event(Event::Burned { from, value });
+			Ok(())
+		}
+		/// Raise the caller's allowance for `spender` by `added_value`. A missing allowance
+		/// counts as zero.
+		///
+		/// Unlike `approve`, this cannot race a `transfer_from` that spends the old allowance
+		/// in the same block: whatever was spent stays spent.
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::increase_allowance())]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			added_value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let allowance = Self::allowance_of(&owner, &spender)
+				.unwrap_or_default()
+				.checked_add(&added_value)
+				.ok_or(Error::<T>::Overflow)?;
+			Self::_allowance_set(&owner, &spender, allowance)?;
+			Self::deposit_event(Event::AllowanceChanged { owner, spender, allowance });
+			Ok(())
+		}
+		/// Lower the caller's allowance for `spender` by `subtracted_value`, revoking it once
+		/// it reaches zero. Fails with `AllowanceExceeded` if less than that is left.
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::decrease_allowance())]
+		pub fn decrease_allowance(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			subtracted_value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let allowance = Self::allowance_of(&owner, &spender)
+				.ok_or(Error::<T>::ApprovalNotGranted)?
+				.checked_sub(&subtracted_value)
+				.ok_or(Error::<T>::AllowanceExceeded)?;
+			Self::_allowance_set(&owner, &spender, allowance)?;
+			Self::deposit_event(Event::AllowanceChanged { owner, spender, allowance });
+			Ok(())
+		}
+		/// Replace the token's name, symbol and decimals.
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
+			decimals: u8,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			<Metadata<T>>::insert(
+				T::NativeAsset::get(),
+				TokenMetadata::<T> { name: name.clone(), symbol: symbol.clone(), decimals },
+			);
+			Self::deposit_event(Event::MetadataSet { name, symbol, decimals });
+			Ok(())
+		}
+		/// Launch a further token `id`, with `admin` as the account that mints and burns it.
+		/// Every balance of it is either zero or at least `min_balance`.
+		///
+		/// Holders move and approve it with the calls taking an asset id. It has balances,
+		/// allowances, a supply and metadata of its own. Its allowances hold the same deposit
+		/// and count against the same `MaxApprovalsPerOwner` as native ones.
+		///
+		/// Account freezes and the pause apply to it, as they are kept per account. Everything
+		/// else is native only: frozen amounts, vesting, savings, staking, KYC and transfer
+		/// limits, attestations, fees, subscriptions, delegations, deadlines on allowances, and
+		/// the holder and transfer statistics.
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::create_asset())]
+		pub fn create_asset(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			admin: AccountIdLookupOf<T>,
+			min_balance: T::Balance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			let admin = Self::lookup(admin)?;
+			ensure!(
+				id != T::NativeAsset::get() && !<Asset<T>>::contains_key(id),
+				Error::<T>::AssetExists
+			);
+			<Asset<T>>::insert(id, AssetDetails { admin: admin.clone(), min_balance });
+			Self::deposit_event(Event::AssetCreated { asset: id, admin, min_balance });
+			Ok(())
+		}
+		/// Stop every transfer, mint, burn and allowance change until `unpause`. Expired
+		/// allowances are still swept.
+		#[pallet::call_index(63)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			<Paused<T>>::put(true);
+			Self::deposit_event(Event::Paused);
+			Ok(())
+		}
+		/// Lift a `pause`.
+		#[pallet::call_index(64)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(<Paused<T>>::get(), Error::<T>::TokenNotPaused);
+			<Paused<T>>::kill();
+			Self::deposit_event(Event::Unpaused);
+			Ok(())
+		}
+		/// Like `permit_unsigned`, but submitted and paid for by any signed account, such as a
+		/// relayer or the spender itself, without going through the pool's unsigned checks.
+		#[pallet::call_index(67)]
+		#[pallet::weight(T::WeightInfo::permit())]
+		pub fn permit(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_permit(owner, spender, value, deadline, &signature)?;
+			Ok(())
+		}
+		/// Transfer to each of `recipients` in turn. Each transfer is reported as usual.
+		///
+		/// With `AllOrNothing`, the first failing transfer fails the call with its index and
+		/// rolls back the ones before it, and one `BatchTransfer` follows for the whole batch.
+		/// With `BestEffort`, a failing transfer is rolled back on its own and the rest go
+		/// ahead; `BatchCompleted` then lists the ones that failed and why.
+		///
+		/// Transfers that never ran, after the failing one, are refunded. So are the writes of
+		/// those rolled back by `BestEffort`, which never reach the database.
+		#[pallet::call_index(68)]
+		#[pallet::weight(T::WeightInfo::transfer_batch(recipients.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			recipients: BoundedVec<(T::AccountId, T::Balance), T::MaxBatchSize>,
+			mode: BatchMode,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			let count = recipients.len() as u32;
+			if mode == BatchMode::BestEffort {
+				let mut failures = BoundedVec::<_, T::MaxBatchSize>::default();
+				for (index, (to, value)) in recipients.into_iter().enumerate() {
+					let sent = with_storage_layer(|| -> DispatchResult {
+						Ok(Self::_transfer(from.clone(), to, value)?)
+					});
+					if let Err(error) = sent {
+						// Cannot fail: there are no more failures than recipients.
+						let _ = failures.try_push((index as u32, error));
+					}
+				}
+				let failed = failures.len() as u32;
+				let succeeded = count - failed;
+				Self::deposit_event(Event::BatchCompleted { from, succeeded, failures });
+				let refund =
+					T::DbWeight::get().writes(BATCH_LEG_WRITES.saturating_mul(failed.into()));
+				return Ok(Some(T::WeightInfo::transfer_batch(count).saturating_sub(refund)).into())
+			}
+			let mut total = T::Balance::zero();
+			for (index, (to, value)) in recipients.into_iter().enumerate() {
+				Self::_transfer(from.clone(), to, value).map_err(|e| {
+					log::debug!(target: LOG_TARGET, "Batch transfer {} failed: {:?}", index, e);
+					Error::<T>::BatchTransferFailed { index: index as u16 }
+						.with_weight(T::WeightInfo::transfer_batch(index as u32 + 1))
+				})?;
+				total = total.saturating_add(value);
+			}
+			Self::deposit_event(Event::BatchTransfer { from, count, total });
+			Ok(().into())
+		}
+		/// Transfer `value` to `to`, locked there until it vests: `per_block` of it in every block
+		/// from `starting_block` on. The recipient unlocks what has vested with `vest`.
+		#[pallet::call_index(69)]
+		#[pallet::weight(T::WeightInfo::transfer_with_vesting())]
+		pub fn transfer_with_vesting(
+			origin: OriginFor<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+			per_block: T::Balance,
+			starting_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let to = Self::lookup(to)?;
+			ensure!(!value.is_zero() && !per_block.is_zero(), Error::<T>::InvalidVestingSchedule);
+			Self::_transfer(from.clone(), to.clone(), value)?;
+			let schedule = VestingSchedule { locked: value, per_block, starting_block };
+			<VestingSchedules<T>>::try_append(&to, schedule)
+				.map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+			Self::deposit_event(Event::VestingScheduleAdded {
+				from,
+				to,
+				locked: value,
+				per_block,
+				starting_block,
+			});
+			Ok(())
+		}
+		/// Unlock what has vested of the caller's vesting schedules, dropping those that are
+		/// done.
+		#[pallet::call_index(70)]
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let mut schedules = <VestingSchedules<T>>::get(&who);
+			ensure!(!schedules.is_empty(), Error::<T>::NotVesting);
+			let now = frame_system::Pallet::<T>::block_number();
+			for schedule in schedules.iter_mut() {
+				schedule.locked = schedule.locked_at(now);
+				schedule.starting_block = schedule.starting_block.max(now);
+			}
+			schedules.retain(|schedule| !schedule.locked.is_zero());
+			let locked = schedules
+				.iter()
+				.fold(T::Balance::zero(), |total, schedule| total.saturating_add(schedule.locked));
+			if schedules.is_empty() {
+				<VestingSchedules<T>>::remove(&who);
+			} else {
+				<VestingSchedules<T>>::insert(&who, schedules);
+			}
+			Self::deposit_event(Event::Vested { who, locked });
+			Ok(())
+		}
+		/// Cap `TotalSupply` at `cap` for good: mints that would take it higher fail with
+		/// `SupplyCapExceeded`. The cap can only be set once, and not below the current supply.
+		#[pallet::call_index(71)]
+		#[pallet::weight(T::WeightInfo::set_max_supply())]
+		pub fn set_max_supply(origin: OriginFor<T>, cap: T::Balance) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!<MaxSupply<T>>::exists(), Error::<T>::SupplyCapAlreadySet);
+			ensure!(cap >= Self::_total_supply(), Error::<T>::SupplyCapExceeded);
+			<MaxSupply<T>>::put(cap);
+			Self::deposit_event(Event::SupplyCapSet { cap });
+			Ok(())
+		}
+		/// Destroy `value` of `owner`'s tokens out of the caller's allowance, taking them out of
+		/// `TotalSupply`. As with `burn`, frozen tokens and locked savings cannot be burned.
+		#[pallet::call_index(73)]
+		#[pallet::weight(T::WeightInfo::burn_from())]
+		pub fn burn_from(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			value: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&spender)?;
+			Self::ensure_not_frozen(&owner)?;
+			Self::before_transfer(Some(&owner), None, value)?;
+			Self::settle_demurrage(&owner)?;
+			let remaining = Self::balance_after_debit(&owner, value)?;
+			Self::charge_allowance(&owner, &spender, value)?;
+			Self::record_spend(&owner, &spender, value);
+			Self::_balance_set(&owner, remaining)?;
+			Self::burn_supply(&owner, value);
+			Self::remove_dust(&owner)?;
+			T::OnTransfer::after_transfer(Some(&owner), None, value);
+			Self::deposit_event(Event::BurnedFrom { owner, spender, value });
+			Ok(())
+		}
+		/// Reap each of `accounts` whose balance is below `MinBalance`, e.g. one left there
+		/// before the minimum was raised, sending its dust to `DustReceiver` or burning it.
+		/// Anyone may call it. Accounts at or above the minimum, frozen ones and those with a
+		/// frozen or locked part are skipped.
+		///
+		/// The fee is charged for every listed account and refunded for those not reaped.
+		#[pallet::call_index(74)]
+		#[pallet::weight(T::WeightInfo::sweep_dust(accounts.len() as u32))]
+		pub fn sweep_dust(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxBatchSize>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			let mut reaped = 0u32;
+			for who in accounts {
+				if !<Frozen<T>>::contains_key(&who) && Self::remove_dust(&who)? {
+					reaped += 1;
+				}
+			}
+			Ok(Some(T::WeightInfo::sweep_dust(reaped)).into())
+		}
+		/// Mint `amount` of the launched token `id` to `to`. Only the token's admin may.
+		#[pallet::call_index(75)]
+		#[pallet::weight(T::WeightInfo::mint_asset())]
+		pub fn mint_asset(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			to: AccountIdLookupOf<T>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			let to = Self::lookup(to)?;
+			let details = Self::asset_admin(id, &who)?;
+			Self::ensure_not_frozen(&to)?;
+			let supply = Self::asset_supply(id).checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			let balance = Self::asset_balance(id, &to).saturating_add(amount);
+			Self::asset_balance_set(id, &details, &to, balance)?;
+			<TotalSupply<T>>::insert(id, supply);
+			Self::deposit_event(Event::AssetIssued { asset: id, to, amount });
+			Ok(())
+		}
+		/// Burn `amount` of the launched token `id` from `from`. Only the token's admin may.
+		#[pallet::call_index(76)]
+		#[pallet::weight(T::WeightInfo::burn_asset())]
+		pub fn burn_asset(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			from: AccountIdLookupOf<T>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			let from = Self::lookup(from)?;
+			let details = Self::asset_admin(id, &who)?;
+			let balance = Self::asset_balance(id, &from)
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::FundsUnavailable)?;
+			Self::asset_balance_set(id, &details, &from, balance)?;
+			<TotalSupply<T>>::insert(id, Self::asset_supply(id).saturating_sub(amount));
+			Self::deposit_event(Event::AssetBurned { asset: id, from, amount });
+			Ok(())
+		}
+		/// Transfer `value` of the launched token `id` to `to`.
+		#[pallet::call_index(77)]
+		#[pallet::weight(T::WeightInfo::transfer_asset())]
+		pub fn transfer_asset(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let to = Self::lookup(to)?;
+			Self::transfer_launched(id, &from, &to, value)?;
+			Ok(())
+		}
+		/// Set the caller's allowance for `spender` in the launched token `id` to `value`.
+		#[pallet::call_index(78)]
+		#[pallet::weight(T::WeightInfo::approve_asset())]
+		pub fn approve_asset(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			spender: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::ensure_not_paused()?;
+			let spender = Self::lookup(spender)?;
+			ensure!(<Asset<T>>::contains_key(id), Error::<T>::UnknownAsset);
+			Self::write_asset_allowance(id, &owner, &spender, value)?;
+			Self::deposit_event(Event::AssetApproval { asset: id, owner, spender, value });
+			Ok(())
+		}
+		/// Transfer `value` of `owner`'s launched token `id` to `to`, out of the caller's
+		/// allowance.
+		#[pallet::call_index(79)]
+		#[pallet::weight(T::WeightInfo::transfer_asset_from())]
+		pub fn transfer_asset_from(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			owner: AccountIdLookupOf<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let owner = Self::lookup(owner)?;
+			let to = Self::lookup(to)?;
+			Self::ensure_not_frozen(&spender)?;
+			let remaining = <Allowances<T>>::get((id, &owner, &spender))
+				.unwrap_or_default()
+				.checked_sub(&value)
+				.ok_or(Error::<T>::AllowanceExceeded)?;
+			Self::transfer_launched(id, &owner, &to, value)?;
+			Self::write_asset_allowance(id, &owner, &spender, remaining)?;
+			Self::deposit_event(Event::AssetApproval {
+				asset: id,
+				owner,
+				spender,
+				value: remaining,
+			});
+			Ok(())
+		}
+		/// Replace the name, symbol and decimals of the launched token `id`. Only the token's
+		/// admin may.
+		#[pallet::call_index(80)]
+		#[pallet::weight(T::WeightInfo::set_asset_metadata())]
+		pub fn set_asset_metadata(
+			origin: OriginFor<T>,
+			id: T::AssetId,
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
+			decimals: u8,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			Self::asset_admin(id, &who)?;
+			<Metadata<T>>::insert(
+				id,
+				TokenMetadata::<T> { name: name.clone(), symbol: symbol.clone(), decimals },
+			);
+			Self::deposit_event(Event::AssetMetadataSet { asset: id, name, symbol, decimals });
+			Ok(())
+		}
+		/// Charge fees paid in the token at `rate` tokens per unit of native fee, e.g. as the
+		/// token's market value moves. Only has an effect on runtimes that charge fees through
+		/// [`crate::fees::TokenFees`].
+		#[pallet::call_index(81)]
+		#[pallet::weight(T::WeightInfo::set_fee_rate())]
+		pub fn set_fee_rate(origin: OriginFor<T>, rate: FixedU128) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!rate.is_zero(), Error::<T>::ZeroFeeRate);
+			<TokensPerNativeUnit<T>>::put(rate);
+			Self::deposit_event(Event::FeeRateSet { rate });
+			Ok(())
+		}
+		/// Mint `FaucetAmount` to the caller, at most once every `FaucetCooldown` blocks. Only
+		/// built with the `testnet-faucet` feature.
+		///
+		/// Same path as `mint`, plus the cooldown entry. Its index is pinned like every other
+		/// call's, so toggling the feature shifts none of them.
+		#[cfg(feature = "testnet-faucet")]
+		#[pallet::call_index(35)]
+		#[pallet::weight(
+			T::WeightInfo::mint().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn faucet(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(last) = <LastFaucetDrip<T>>::get(&who) {
+				ensure!(
+					now >= last.saturating_add(T::FaucetCooldown::get()),
+					Error::<T>::FaucetCooldownActive
+				);
+			}
+			let amount = T::FaucetAmount::get();
+			Self::ensure_mint_rate(amount)?;
+			Self::_mint(&who, amount)?;
+			<LastFaucetDrip<T>>::insert(&who, now);
+			Self::deposit_event(Event::FaucetDrip { who, amount });
+			Ok(())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// `who`'s balance of the native token, `None` if it holds none.
+		pub fn get_balance_of(who: impl EncodeLike<T::AccountId>) -> Option<T::Balance> {
+			<BalanceOf<T>>::get(T::NativeAsset::get(), who)
+		}
+
+		/// Native tokens in existence, `None` before the first mint.
+		pub fn get_total_supply() -> Option<T::Balance> {
+			<TotalSupply<T>>::get(T::NativeAsset::get())
+		}
+
+		/// The native token's name, symbol and decimals.
+		pub fn metadata() -> TokenMetadata<T> {
+			<Metadata<T>>::get(T::NativeAsset::get())
+		}
+
+		/// `who`'s balance of the token `id`.
+		pub fn asset_balance(id: T::AssetId, who: &T::AccountId) -> T::Balance {
+			<BalanceOf<T>>::get(id, who).unwrap_or_default()
+		}
+
+		/// Tokens `id` in existence.
+		pub fn asset_supply(id: T::AssetId) -> T::Balance {
+			<TotalSupply<T>>::get(id).unwrap_or_default()
+		}
+
+		/// Balances of `accounts` in input order, zero for accounts holding no tokens.
+		///
+		/// Only the first [`MAX_BALANCES_QUERY`] accounts are answered so a single runtime API
+		/// call stays bounded; the rest of the input is ignored and callers must split larger
+		/// sets across several queries.
+		pub fn balances_of(accounts: Vec<T::AccountId>) -> Vec<T::Balance> {
+			accounts.iter().take(MAX_BALANCES_QUERY).map(Self::effective_balance).collect()
+		}
+
+		/// The parameters in effect.
+		pub fn parameters() -> Parameters<T::Balance> {
+			Parameters { block_reward: Self::block_reward() }
+		}
+
+		/// The `n` largest holders with their balances, largest first, capped at
+		/// `TopHoldersTracked`. Fewer are returned while the index cannot tell who comes next.
+		///
+		/// Balances are ranked as last settled, before any demurrage owed since.
+		pub fn top_holders(n: u32) -> Vec<(T::AccountId, T::Balance)> {
+			// Bounded by `TopHoldersTracked`.
+			let mut top: Vec<_> =
+				<TopHolders<T>>::iter().map(|((_, who), balance)| (who, balance)).collect();
+			top.reverse();
+			top.truncate(n as usize);
+			top
+		}
+
+		/// One page of token holders and their balances, in storage order.
+		///
+		/// Iteration starts right after the raw storage key `start_key` (or at the beginning of
+		/// `BalanceOf` when `None`) and yields at most `limit` entries, capped at
+		/// [`MAX_HOLDERS_PAGE`]. The second element is the cursor to pass in for the next page,
+		/// `None` once the map is exhausted. Storage order is fixed for a given state, so paging
+		/// against one block hash visits every holder exactly once.
+		pub fn holders(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(T::AccountId, T::Balance)>, Option<Vec<u8>>) {
+			let limit = limit.min(MAX_HOLDERS_PAGE) as usize;
+			let native = T::NativeAsset::get();
+			let mut iter = match start_key {
+				Some(key) => <BalanceOf<T>>::iter_prefix_from(native, key),
+				None => <BalanceOf<T>>::iter_prefix(native),
+			};
+			let page: Vec<_> = iter
+				.by_ref()
+				.take(limit)
+				.map(|(who, balance)| {
+					let effective = balance - Self::demurrage_owed(&who, balance);
+					(who, effective)
+				})
+				.collect();
+			if page.len() < limit || page.is_empty() {
+				return (page, None)
+			}
+			let cursor = iter.last_raw_key().to_vec();
+			let next = iter.next().map(|_| cursor);
+			(page, next)
+		}
+
+		/// What `who` would hold right now once the demurrage accrued since its last activity
+		/// is charged.
+		pub fn effective_balance(who: &T::AccountId) -> T::Balance {
+			let balance = Self::_balance_of(who);
+			balance - Self::demurrage_owed(who, balance)
+		}
+
+		/// `TotalSupply` less what the accounts excluded with `exclude_from_circulating` hold.
+		pub fn circulating_supply() -> T::Balance {
+			<ExcludedFromCirculating<T>>::get()
+				.iter()
+				.fold(Self::_total_supply(), |supply, who| {
+					supply.saturating_sub(Self::effective_balance(who))
+				})
+		}
+
+		/// Staking rewards `who` would be paid by its next stake or unstake, including its share
+		/// of anything paid into the staking pot since the last update.
+		pub fn pending_rewards(who: &T::AccountId) -> T::Balance {
+			let Some(ledger) = <Ledgers<T>>::get(who) else { return Zero::zero() };
+			let mut per_share = <RewardPerShare<T>>::get();
+			let total = Self::total_staked();
+			if !total.is_zero() {
+				let fresh = Self::_balance_of(&T::StakingPot::get())
+					.saturating_sub(<RewardsAccounted<T>>::get());
+				per_share = per_share.saturating_add(Self::reward_per_share_of(fresh, total));
+			}
+			let earned = ledger
+				.active
+				.saturated_into::<u128>()
+				.saturating_mul(per_share)
+				.saturating_sub(ledger.reward_debt) /
+				REWARD_PRECISION;
+			earned.saturated_into()
+		}
+
+		/// Transfer volume of each of the last `StatsWindow` blocks, oldest first.
+		pub fn recent_volume() -> Vec<(BlockNumberFor<T>, u128)> {
+			let mut recent: Vec<_> = (0..T::StatsWindow::get())
+				.filter_map(|slot| <RecentVolume<T>>::get(slot))
+				.collect();
+			recent.sort_by_key(|(block, _)| *block);
+			recent
+		}
+
+		/// `TotalSupply` as recorded at the start of tracking period `index`, if that period is
+		/// still within the kept history.
+		pub fn supply_at_period(index: u32) -> Option<T::Balance> {
+			<SupplyHistory<T>>::get(index)
+		}
+
+		/// Deposit sub-account number `index` of `master`, which only `master` can [`sweep`].
+		///
+		/// [`sweep`]: Pallet::sweep
+		pub fn derive_deposit_account(master: T::AccountId, index: u32) -> T::AccountId {
+			let entropy = (b"modl", T::PalletId::get(), master, index).using_encoded(blake2_256);
+			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+				.expect("infinite length input; no invalid inputs for type; qed")
+		}
+
+		/// The domain separator wallets must include in every payload they sign for this pallet.
+		///
+		/// It hashes `DomainName`, `DomainVersion`, the genesis hash, the runtime's spec version
+		/// and this pallet's index and name, so a signature made for another chain or fork,
+		/// another instance of the pallet or an older runtime is never valid here.
+		pub fn domain_separator() -> [u8; 32] {
+			<DomainSeparator<T>>::get()
+		}
+
+		/// The exact bytes a wallet signs to authorize `message`, itself the SCALE encoding of
+		/// what is authorized: the domain separator followed by `message`.
+		pub fn signing_payload(message: &[u8]) -> Vec<u8> {
+			[&Self::domain_separator()[..], message].concat()
+		}
+
+		/// Whether `signature` is `owner`'s over the [`Pallet::signing_payload`] of `message`,
+		/// signed either as is or wrapped in `<Bytes>`..`</Bytes>` the way polkadot-js `signRaw`
+		/// does. Every scheme `OffchainSignature` covers is accepted.
+		pub fn verify_payload(
+			owner: &T::AccountId,
+			message: &[u8],
+			signature: &T::OffchainSignature,
+		) -> bool {
+			let payload = Self::signing_payload(message);
+			signature.verify(&payload[..], owner) ||
+				signature.verify(
+					&[WRAPPED_BYTES_PREFIX, &payload, WRAPPED_BYTES_POSTFIX].concat()[..],
+					owner,
+				)
+		}
+
+		/// The message of a permit letting `spender` spend `value` of `owner`'s tokens, valid
+		/// until block `deadline` and only while `owner`'s nonce is `nonce`.
+		pub fn permit_message(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+			nonce: u64,
+			deadline: BlockNumberFor<T>,
+		) -> Vec<u8> {
+			(b"permit", owner, spender, value, nonce, deadline).encode()
+		}
+
+		/// The exact bytes `owner` signs for a permit with their current nonce.
+		pub fn payload_for_permit(
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+		) -> Vec<u8> {
+			let nonce = Self::nonces(&owner);
+			Self::signing_payload(&Self::permit_message(&owner, &spender, value, nonce, deadline))
+		}
+
+		/// Leaf of the claims snapshot granting `amount` to `eth_address`: the Keccak-256 hash
+		/// of the pair's SCALE encoding.
+		pub fn claim_leaf(eth_address: &EthereumAddress, amount: T::Balance) -> [u8; 32] {
+			(eth_address, amount).using_encoded(keccak_256)
+		}
+
+		/// What `personal_sign` hashes when an Ethereum account signs for `who` to claim: the
+		/// SCALE encoding of `who` behind the EIP-191 prefix and its length in decimal.
+		pub fn claim_message(who: &T::AccountId) -> Vec<u8> {
+			let account = who.encode();
+			let mut length = Vec::new();
+			let mut rest = account.len();
+			loop {
+				length.insert(0, b'0' + (rest % 10) as u8);
+				rest /= 10;
+				if rest == 0 {
+					break
+				}
+			}
+			let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+			message.extend(length);
+			message.extend(account);
+			message
+		}
+
+		/// `Ok` if `from` could transfer `value` to `to` right now, or why it could not.
+		///
+		/// Runs the same code as `transfer` and rolls every change back, so the answer cannot
+		/// drift from what a submitted transfer would do.
+		pub fn simulate_transfer(
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Erc20Error> {
+			with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+				TransactionOutcome::Rollback(Ok(Self::_transfer(from, to, value)))
+			})
+			.map_err(|_| Erc20Error::Other)?
+			.map_err(Into::into)
+		}
+		/// All-time tokens `spender` has moved out of `owner`'s balance.
+		pub fn spent_by(owner: T::AccountId, spender: T::AccountId) -> T::Balance {
+			<SpentBy<T>>::get(owner, spender)
+		}
+
+		/// What is left of `owner`'s allowance for `spender`, zero if it approved none or the
+		/// allowance expired.
+		pub fn allowance(owner: T::AccountId, spender: T::AccountId) -> T::Balance {
+			if Self::allowance_expired(&owner, &spender) {
+				return Zero::zero()
+			}
+			Self::allowance_of(&owner, &spender).unwrap_or_default()
+		}
+
+		/// Every spender `owner` has an unexpired allowance for, with what is left of it.
+		pub fn approvals_of(owner: T::AccountId) -> Vec<(T::AccountId, T::Balance)> {
+			<Approvals<T>>::get(&owner)
+				.into_iter()
+				.filter(|spender| !Self::allowance_expired(&owner, spender))
+				.map(|spender| {
+					let allowance = Self::allowance_of(&owner, &spender).unwrap_or_default();
+					(spender, allowance)
+				})
+				.collect()
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The JSON document the offchain worker publishes for block `n`. `transfers` and
+		/// `transfer_volume` are all-time totals: the difference between two reports is what
+		/// moved in the blocks between them.
+		pub fn metrics_json(n: BlockNumberFor<T>) -> Vec<u8> {
+			alloc::format!(
+				"{{\"block\":{},\"total_supply\":{},\"holders\":{},\"block_volume\":{},\
+				 \"transfers\":{},\"transfer_volume\":{}}}",
+				n.saturated_into::<u64>(),
+				Self::_total_supply().saturated_into::<u128>(),
+				Self::holder_count(),
+				Self::block_volume(),
+				Self::transfer_count(),
+				Self::transfer_volume(),
+			)
+			.into_bytes()
+		}
+
+		/// POST [`Self::metrics_json`] to the endpoint stored under [`METRICS_ENDPOINT_KEY`], if
+		/// this node has configured one.
+		fn publish_metrics(n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			let Some(endpoint) =
+				sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, METRICS_ENDPOINT_KEY)
+			else {
+				return Ok(())
+			};
+			let endpoint = sp_std::str::from_utf8(&endpoint)
+				.map_err(|_| "metrics endpoint is not valid UTF-8")?;
+
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(METRICS_HTTP_TIMEOUT_MS));
+			let pending = http::Request::post(endpoint, sp_std::vec![Self::metrics_json(n)])
+				.add_header("Content-Type", "application/json")
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "failed to send the metrics request")?;
+			let response = pending
+				.try_wait(deadline)
+				.map_err(|_| "metrics request timed out")?
+				.map_err(|_| "metrics request failed")?;
+			if response.code != 200 {
+				log::warn!(target: LOG_TARGET, "Metrics endpoint answered with {}", response.code);
+				return Err("unexpected status code from the metrics endpoint")
+			}
+			Ok(())
+		}
+	}
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config> Pallet<T> {
+		pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			let native = T::NativeAsset::get();
+			let mut holders = 0u32;
+			for balance in <BalanceOf<T>>::iter_prefix_values(native) {
+				ensure!(!balance.is_zero(), "Zero balances must be reaped");
+				holders += 1;
+			}
+			ensure!(holders == Self::holder_count(), "HolderCount does not match BalanceOf");
+			// The index only exists from storage version 4 on.
+			if Self::on_chain_storage_version() >= 4 {
+				let floor = <TopHoldersFloor<T>>::get();
+				let mut tracked = 0u32;
+				for (rank, balance) in <TopHolders<T>>::iter() {
+					ensure!(
+						rank == Self::holder_rank(balance, &rank.1),
+						"TopHolders key out of date"
+					);
+					ensure!(balance >= floor, "TopHolders entry below the floor");
+					ensure!(
+						Self::_balance_of(&rank.1) == balance,
+						"TopHolders balance out of date"
+					);
+					tracked += 1;
+				}
+				ensure!(tracked == <TopHoldersCount<T>>::get(), "TopHoldersCount out of date");
+				ensure!(tracked <= T::TopHoldersTracked::get(), "TopHolders over its bound");
+				ensure!(
+					<BalanceOf<T>>::iter_prefix(native).all(|(who, balance)| {
+						balance <= floor ||
+							<TopHolders<T>>::contains_key(Self::holder_rank(balance, &who))
+					}),
+					"Holder missing from TopHolders above the floor"
+				);
+			}
+			ensure!(
+				<ReservedBalance<T>>::iter_values().all(|reserved| !reserved.is_zero()),
+				"Zero reserves must be removed"
+			);
+			for (who, reserves) in <NamedReserves<T>>::iter() {
+				ensure!(!reserves.is_empty(), "Empty NamedReserves must be removed");
+				ensure!(
+					reserves.windows(2).all(|pair| pair[0].0 < pair[1].0),
+					"NamedReserves not sorted by identifier"
+				);
+				let named =
+					reserves.iter().fold(T::Balance::zero(), |total, (_, amount)| total + *amount);
+				ensure!(named <= Self::reserved_of(&who), "Named reserves over ReservedBalance");
+			}
+			for tranches in <SavingTranches<T>>::iter_values() {
+				ensure!(!tranches.is_empty(), "Empty savings tranches must be removed");
+				ensure!(
+					tranches.windows(2).all(|pair| pair[0].0 < pair[1].0),
+					"SavingTranches not sorted by unlock block"
+				);
+				ensure!(
+					tranches.iter().all(|(_, value)| !value.is_zero()),
+					"Empty savings tranche"
+				);
+			}
+			ensure!(
+				<VestingSchedules<T>>::iter_values()
+					.all(|schedules| schedules.iter().all(|schedule| !schedule.locked.is_zero())),
+				"Vesting schedules must lock something"
+			);
+
+			let mut indexed = 0usize;
+			for (owner, spenders) in <Approvals<T>>::iter() {
+				for (i, spender) in spenders.iter().enumerate() {
+					ensure!(!spenders[..i].contains(spender), "Approvals lists a spender twice");
+					ensure!(
+						Self::allowance_of(&owner, spender).is_some(),
+						"Approvals lists a spender without an allowance"
+					);
+				}
+				indexed += spenders.len();
+			}
+			let mut allowances = 0usize;
+			ensure!(
+				<AllowanceRekey<T>>::exists() || <Allowance<T>>::iter_keys().next().is_none(),
+				"Legacy allowances left after the re-keying"
+			);
+			for ((owner, spender), allowance) in <Allowances<T>>::iter_prefix((native,)) {
+				ensure!(
+					!<Allowance<T>>::contains_key((&owner, &spender)),
+					"Allowance stored in both layouts"
+				);
+				ensure!(!allowance.is_zero(), "Zero allowances must be removed");
+				ensure!(
+					<Approvals<T>>::get(&owner).contains(&spender),
+					"Allowance missing from the owner's Approvals"
+				);
+				allowances += 1;
+			}
+			for ((owner, spender), allowance) in <Allowance<T>>::iter() {
+				ensure!(!allowance.is_zero(), "Zero allowances must be removed");
+				ensure!(
+					<Approvals<T>>::get(&owner).contains(&spender),
+					"Allowance missing from the owner's Approvals"
+				);
+				allowances += 1;
+			}
+			ensure!(indexed == allowances, "Approvals does not match Allowance");
+			ensure!(
+				<AllowanceDeposits<T>>::iter_keys()
+					.all(|(owner, spender)| Self::allowance_of(&owner, &spender).is_some()),
+				"Deposit held for a removed allowance"
+			);
+			ensure!(
+				<AllowanceExpiry<T>>::iter_keys()
+					.all(|(owner, spender)| Self::allowance_of(&owner, &spender).is_some()),
+				"Deadline kept for a removed allowance"
+			);
+			let cursor = <AllowanceSweepCursor<T>>::get();
+			let mut expiring = 0usize;
+			for (deadline, (owner, spender), ()) in <ExpiringAllowances<T>>::iter() {
+				ensure!(
+					<AllowanceExpiry<T>>::get((&owner, &spender)) == Some(deadline),
+					"ExpiringAllowances does not match AllowanceExpiry"
+				);
+				ensure!(cursor.map_or(false, |cursor| cursor <= deadline), "Deadline left unswept");
+				expiring += 1;
+			}
+			ensure!(
+				expiring == <AllowanceExpiry<T>>::iter_keys().count(),
+				"Deadline missing from ExpiringAllowances"
+			);
+			let mut delegated = 0usize;
+			for (owner, spender, delegations) in <Delegations<T>>::iter() {
+				ensure!(!delegations.is_empty(), "Empty delegations must be removed");
+				ensure!(
+					delegations
+						.iter()
+						.fold(T::Balance::zero(), |total, (_, amount)| total + *amount) <=
+						Self::allowance_of(&owner, &spender).unwrap_or_default(),
+					"Delegations over the allowance"
+				);
+				for (delegate, amount) in delegations.iter() {
+					ensure!(!amount.is_zero(), "Zero delegations must be removed");
+					ensure!(
+						<DelegatedAllowances<T>>::get(&owner, delegate).as_ref() == Some(&spender),
+						"Delegation missing from DelegatedAllowances"
+					);
+				}
+				delegated += delegations.len();
+			}
+			ensure!(
+				<DelegatedAllowances<T>>::iter_keys().count() == delegated,
+				"DelegatedAllowances does not match Delegations"
+			);
+
+			let mut counted = 0usize;
+			for (owner, count) in <ScopedApprovalCount<T>>::iter() {
+				ensure!(
+					<ScopedAllowances<T>>::iter_prefix((&owner,)).count() == count as usize,
+					"ScopedApprovalCount does not match ScopedAllowances"
+				);
+				ensure!(
+					Self::approval_entries(&owner) <= T::MaxApprovalsPerOwner::get(),
+					"Owner over MaxApprovalsPerOwner"
+				);
+				counted += count as usize;
+			}
+			ensure!(
+				<ScopedAllowances<T>>::iter_values().all(|(value, _)| !value.is_zero()) &&
+					<ScopedAllowances<T>>::iter_keys().count() == counted,
+				"Zero or uncounted scoped allowance"
+			);
+			let mut asset_allowances = sp_std::collections::btree_map::BTreeMap::new();
+			for ((asset, owner, _), allowance) in <Allowances<T>>::iter() {
+				if asset != native {
+					ensure!(!allowance.is_zero(), "Zero allowances must be removed");
+					*asset_allowances.entry(owner).or_insert(0u32) += 1;
+				}
+			}
+			ensure!(
+				<AssetApprovalCount<T>>::iter()
+					.collect::<sp_std::collections::btree_map::BTreeMap<_, _>>() ==
+					asset_allowances,
+				"AssetApprovalCount does not match Allowances"
+			);
+			ensure!(
+				asset_allowances
+					.keys()
+					.all(|owner| Self::approval_entries(owner) <= T::MaxApprovalsPerOwner::get()),
+				"Owner over MaxApprovalsPerOwner"
+			);
+			ensure!(
+				<AssetAllowanceDeposits<T>>::iter_keys()
+					.all(|key| <Allowances<T>>::contains_key(key)),
+				"Deposit held for a removed allowance"
+			);
+
+			if let Some(status) = <Sunset<T>>::get() {
+				// Tokens only leave balances for the successor's or, burned, for a refund.
+				match &status.destination {
+					SunsetDestination::Successor(to) => {
+						ensure!(
+							Self::_total_supply() == status.supply,
+							"TotalSupply changed while sunsetting to a successor"
+						);
+						ensure!(
+							Self::_balance_of(to) >= status.tokens,
+							"Successor holds less than the moved tokens"
+						);
+					},
+					SunsetDestination::Refund(_) => ensure!(
+						Self::_total_supply().saturating_add(status.tokens) == status.supply,
+						"TotalSupply lost more than the refunded tokens"
+					),
+				}
+				ensure!(status.refunded <= status.pool, "Sunset refunded more than its pool");
+			}
+			if let Some(status) = <AssetMigration<T>>::get() {
+				// Every token that left a balance here arrived in the asset, and nothing else
+				// changed either supply.
+				ensure!(
+					Self::_total_supply().saturating_add(status.tokens) == status.supply,
+					"TotalSupply lost more than the migrated tokens"
+				);
+				ensure!(
+					T::Assets::total_issuance(status.asset) ==
+						status.issuance.saturating_add(
+							status.tokens.saturated_into::<u128>().saturated_into()
+						),
+					"Asset issuance grew by other than the migrated tokens"
+				);
+			}
+			for (id, details) in <Asset<T>>::iter() {
+				let mut total = T::Balance::zero();
+				for balance in <BalanceOf<T>>::iter_prefix_values(id) {
+					ensure!(balance >= details.min_balance, "Launched balance below min_balance");
+					total = total.saturating_add(balance);
+				}
+				ensure!(total == Self::asset_supply(id), "TotalSupply does not match the balances");
+			}
+			Ok(())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		fn _total_supply() -> T::Balance {
+			<TotalSupply<T>>::get(T::NativeAsset::get()).unwrap_or_default()
+		}
+		fn _total_supply_set(supply: T::Balance) {
+			<TotalSupply<T>>::insert(T::NativeAsset::get(), supply);
+		}
+		fn _balance_of(who: &T::AccountId) -> T::Balance {
+			<BalanceOf<T>>::get(T::NativeAsset::get(), who).unwrap_or_default()
+		}
+		/// Every balance write goes through here so that `HolderCount` stays exact: an account
+		/// whose balance drops to zero is reaped, and a new entry counts as a new holder.
+		///
+		/// Each holder also keeps a provider reference in `frame_system`, so an account holding
+		/// only this token is not reaped there when its native balance goes away.
+		fn _balance_set(who: &T::AccountId, balance: T::Balance) -> Result<(), Error<T>> {
+			let native = T::NativeAsset::get();
+			let previous = <BalanceOf<T>>::get(native, who);
+			if balance.is_zero() {
+				if let Some(previous) = previous {
+					frame_system::Pallet::<T>::dec_providers(who)
+						.map_err(|_| Error::<T>::StillReferenced)?;
+					<BalanceOf<T>>::remove(native, who);
+					if !T::DemurrageRate::get().is_zero() {
+						<LastActivity<T>>::remove(who);
+					}
+					<HolderCount<T>>::mutate(|count| *count = count.saturating_sub(1));
+					Self::update_top_holders(who, previous, Zero::zero());
+				}
+				return Ok(())
+			}
+			if previous.is_none() {
+				frame_system::Pallet::<T>::inc_providers(who);
+				<HolderCount<T>>::mutate(|count| *count = count.saturating_add(1));
+			}
+			<BalanceOf<T>>::insert(native, who, balance);
+			if !T::DemurrageRate::get().is_zero() {
+				<LastActivity<T>>::insert(who, frame_system::Pallet::<T>::block_number());
+			}
+			Self::update_top_holders(who, previous.unwrap_or_default(), balance);
+			Ok(())
+		}
+		fn asset_admin(id: T::AssetId, who: &T::AccountId) -> Result<AssetDetails<T>, Error<T>> {
+			let details = <Asset<T>>::get(id).ok_or(Error::<T>::UnknownAsset)?;
+			ensure!(details.admin == *who, Error::<T>::NotAssetAdmin);
+			Ok(details)
+		}
+		/// Balances of launched tokens are either zero or at least the token's minimum. They
+		/// take no provider reference and count towards none of the native token's indices.
+		fn asset_balance_set(
+			id: T::AssetId,
+			details: &AssetDetails<T>,
+			who: &T::AccountId,
+			balance: T::Balance,
+		) -> Result<(), Error<T>> {
+			if balance.is_zero() {
+				<BalanceOf<T>>::remove(id, who);
+				return Ok(())
+			}
+			ensure!(balance >= details.min_balance, Error::<T>::BelowMinimum);
+			<BalanceOf<T>>::insert(id, who, balance);
+			Ok(())
+		}
+		fn transfer_launched(
+			id: T::AssetId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			Self::ensure_not_paused()?;
+			let details = <Asset<T>>::get(id).ok_or(Error::<T>::UnknownAsset)?;
+			Self::ensure_not_frozen(from)?;
+			Self::ensure_not_frozen(to)?;
+			let remaining = Self::asset_balance(id, from)
+				.checked_sub(&value)
+				.ok_or(Error::<T>::FundsUnavailable)?;
+			Self::asset_balance_set(id, &details, from, remaining)?;
+			let balance =
+				Self::asset_balance(id, to).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+			Self::asset_balance_set(id, &details, to, balance)?;
+			Self::deposit_event(Event::AssetTransferred {
+				asset: id,
+				from: from.clone(),
+				to: to.clone(),
+				value,
+			});
+			Ok(())
+		}
+		/// Key of `who` in `TopHolders` while it holds `balance`: the balance in big-endian,
+		/// which orders the keys by it, then the account.
+		pub(crate) fn holder_rank(
+			balance: T::Balance,
+			who: &T::AccountId,
+		) -> HolderRank<T::AccountId> {
+			(balance.saturated_into::<u128>().to_be_bytes(), who.clone())
+		}
+		/// Keeps `TopHolders` exact as `who`'s balance goes from `old` to `new`. Its entry is
+		/// found by key, and when the index overflows the smallest entry, the first in storage
+		/// order, is evicted, so at most three entries are touched.
+		///
+		/// Which holders come right after the index is not tracked, so an entry that falls
+		/// below `TopHoldersFloor` leaves it rather than risk ranking above someone outside.
+		/// The index then holds fewer entries until other balances reach the floor. Once it
+		/// holds every holder, the floor drops back to zero.
+		pub(crate) fn update_top_holders(who: &T::AccountId, old: T::Balance, new: T::Balance) {
+			if old == new || T::TopHoldersTracked::get() == 0 {
+				return
+			}
+			let mut floor = <TopHoldersFloor<T>>::get();
+			let mut count = <TopHoldersCount<T>>::get();
+			let was_tracked = <TopHolders<T>>::take(Self::holder_rank(old, who)).is_some();
+			if was_tracked {
+				count = count.saturating_sub(1);
+			}
+			let enters = !new.is_zero() && new >= floor;
+			if enters {
+				<TopHolders<T>>::insert(Self::holder_rank(new, who), new);
+				count = count.saturating_add(1);
+				if count > T::TopHoldersTracked::get() {
+					// `who` itself when it is the smallest.
+					if let Some((smallest, evicted)) = <TopHolders<T>>::iter().next() {
+						<TopHolders<T>>::remove(smallest);
+						count = count.saturating_sub(1);
+						floor = floor.max(evicted);
+					}
+				}
+			}
+			if count >= Self::holder_count() {
+				floor = Zero::zero();
+			} else if !was_tracked && !enters {
+				// Neither in the index before nor after: nothing to write.
+				return
+			}
+			<TopHoldersCount<T>>::put(count);
+			<TopHoldersFloor<T>>::put(floor);
+		}
+		/// Demurrage `who` owes on `balance` for every block since its last activity, at most
+		/// the whole balance.
+		fn demurrage_owed(who: &T::AccountId, balance: T::Balance) -> T::Balance {
+			let rate = T::DemurrageRate::get();
+			if rate.is_zero() || *who == T::DemurragePool::get() {
+				return Zero::zero()
+			}
+			let Some(since) = <LastActivity<T>>::get(who) else { return Zero::zero() };
+			let blocks = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(since)
+				.saturated_into::<u128>();
+			let balance = balance.saturated_into::<u128>();
+			let owed =
+				u128::from(rate.deconstruct()).saturating_mul(blocks).saturating_mul(balance) /
+					u128::from(Perbill::ACCURACY);
+			owed.min(balance).saturated_into()
+		}
+		/// Charges `who` the demurrage it owes, moving it to `DemurragePool`, and returns the
+		/// amount charged. Every operation that changes a balance settles the accounts it
+		/// touches first.
+		fn settle_demurrage(who: &T::AccountId) -> Result<T::Balance, Error<T>> {
+			if T::DemurrageRate::get().is_zero() {
+				return Ok(Zero::zero())
+			}
+			let balance = Self::_balance_of(who);
+			let owed = Self::demurrage_owed(who, balance);
+			if owed.is_zero() {
+				return Ok(Zero::zero())
+			}
+			let pool = T::DemurragePool::get();
+			let pool_balance =
+				Self::_balance_of(&pool).checked_add(&owed).ok_or(Error::<T>::Overflow)?;
+			Self::_balance_set(who, balance - owed)?;
+			Self::_balance_set(&pool, pool_balance)?;
+			Self::deposit_event(Event::DemurrageCharged { who: who.clone(), value: owed });
+			Ok(owed)
+		}
+		/// Reports a completed transfer as `Transfer`, or `TransferWithId` if it carries an id,
+		/// or as `TransferDetailed` under `DetailedEvents`. A transfer to `BurnAddress` is
+		/// reported as `Burned`.
+		fn deposit_transfer_event(
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			id: Option<TransferId>,
+			demurrage: T::Balance,
+		) {
+			let event = if Self::is_burn_address(&to) {
+				Event::Burned { from, value }
+			} else if T::DetailedEvents::get() {
+				let (from_balance, to_balance) = (Self::_balance_of(&from), Self::_balance_of(&to));
+				Event::TransferDetailed { from, to, value, id, demurrage, from_balance, to_balance }
+			} else if let Some(id) = id {
+				Event::TransferWithId { from, to, value, id }
+			} else {
+				Event::Transfer { from, to, value }
+			};
+			Self::deposit_event(event);
+		}
+		/// Reports a completed spend of an allowance as `TransferFrom`, or as
+		/// `TransferFromDetailed` under `DetailedEvents`. A spend to `BurnAddress` is
+		/// reported as `Burned`.
+		fn deposit_transfer_from_event(
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			demurrage: T::Balance,
+		) {
+			let event = if Self::is_burn_address(&to) {
+				Event::Burned { from, value }
+			} else if T::DetailedEvents::get() {
+				let (from_balance, to_balance) = (Self::_balance_of(&from), Self::_balance_of(&to));
+				Event::TransferFromDetailed { from, to, value, demurrage, from_balance, to_balance }
+			} else {
+				Event::TransferFrom { from, to, value }
+			};
+			Self::deposit_event(event);
+		}
+		fn lookup(address: AccountIdLookupOf<T>) -> Result<T::AccountId, Error<T>> {
+			T::Lookup::lookup(address).map_err(|_| Error::<T>::UnknownAddress)
+		}
+		fn action_weight(action: &TokenAction<T>) -> Weight {
+			match action {
+				TokenAction::Transfer { .. } => T::WeightInfo::transfer(),
+				TokenAction::Approve { .. } => T::WeightInfo::approve(),
+				TokenAction::TransferFrom { .. } => T::WeightInfo::transfer_from(),
+			}
+		}
+		fn multicall_weight(calls: &[TokenAction<T>]) -> Weight {
+			calls.iter().fold(Weight::zero(), |total, action| {
+				total.saturating_add(Self::action_weight(action))
+			})
+		}
+		fn do_action(who: &T::AccountId, action: TokenAction<T>) -> DispatchResult {
+			match action {
+				TokenAction::Transfer { to, value } => Self::_transfer(who.clone(), to, value)?,
+				TokenAction::Approve { spender, value } =>
+					Self::_approve(who.clone(), spender, value, None)?,
+				TokenAction::TransferFrom { owner, to, value } => {
+					Self::ensure_not_frozen(who)?;
+					Self::charge_allowance(&owner, who, value)?;
+					let demurrage = Self::_spend(&owner, &to, value)?;
+					Self::record_spend(&owner, who, value);
+					Self::deposit_transfer_from_event(owner, to, value, demurrage);
+				},
+			}
+			Ok(())
+		}
+		/// Sets an allowance on its owner's behalf, which the pause stops.
+		fn _allowance_set(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			Self::ensure_not_paused()?;
+			Self::write_allowance(owner, spender, value)
+		}
+		/// Every allowance write goes through here so that `Approvals` lists exactly the spenders
+		/// holding a non-zero allowance from each owner, and their delegations fit in it.
+		///
+		/// Expiry, the asset migration and the sunset call it directly, as they clear
+		/// allowances whether or not the token is paused.
+		fn write_allowance(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			Self::atomically(|| {
+				// Writes land in the new layout, so a legacy entry must not be left to shadow them.
+				let key = (T::NativeAsset::get(), owner, spender);
+				let existed = <Allowances<T>>::contains_key(key) ||
+					<Allowance<T>>::take((owner, spender)).is_some();
+				if value.is_zero() {
+					if existed {
+						<Allowances<T>>::remove(key);
+						if let Some(deposit) = <AllowanceDeposits<T>>::take((owner, spender)) {
+							T::Currency::unreserve(owner, deposit);
+						}
+						Self::set_allowance_expiry(owner, spender, None);
+						<Approvals<T>>::mutate_exists(owner, |maybe_spenders| {
+							if let Some(spenders) = maybe_spenders {
+								spenders.retain(|s| s != spender);
+								if spenders.is_empty() {
+									*maybe_spenders = None;
+								}
+							}
+						});
+						Self::trim_delegations(owner, spender, Zero::zero());
+					}
+					return Ok(())
+				}
+				if !existed {
+					let others =
+						<ScopedApprovalCount<T>>::get(owner) + <AssetApprovalCount<T>>::get(owner);
+					<Approvals<T>>::try_mutate(owner, |spenders| {
+						if spenders.len() as u32 + others >= T::MaxApprovalsPerOwner::get() {
+							return Err(())
+						}
+						spenders.try_push(spender.clone()).map_err(|_| ())
+					})
+					.map_err(|_| Error::<T>::TooManyApprovals)?;
+					let deposit = T::AllowanceDeposit::get();
+					if !deposit.is_zero() {
+						T::Currency::reserve(owner, deposit)
+							.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+						<AllowanceDeposits<T>>::insert((owner, spender), deposit);
+					}
+				}
+				<Allowances<T>>::insert(key, value);
+				Self::trim_delegations(owner, spender, value);
+				Ok(())
+			})
+		}
+		/// Revokes the newest of `spender`'s delegations of its allowance from `owner` until the
+		/// rest add up to at most `allowance`.
+		fn trim_delegations(owner: &T::AccountId, spender: &T::AccountId, allowance: T::Balance) {
+			let mut delegations = <Delegations<T>>::get(owner, spender);
+			let mut delegated = delegations
+				.iter()
+				.fold(T::Balance::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			if delegated <= allowance {
+				return
+			}
+			while delegated > allowance {
+				let Some((delegate, amount)) = delegations.pop() else { break };
+				delegated -= amount;
+				<DelegatedAllowances<T>>::remove(owner, &delegate);
+				Self::deposit_event(Event::DelegationRevoked {
+					owner: owner.clone(),
+					spender: spender.clone(),
+					delegate,
+					amount,
+				});
+			}
+			Self::put_delegations(owner, spender, delegations);
+		}
+		fn put_delegations(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			delegations: BoundedVec<(T::AccountId, T::Balance), T::MaxDelegations>,
+		) {
+			if delegations.is_empty() {
+				<Delegations<T>>::remove(owner, spender);
+			} else {
+				<Delegations<T>>::insert(owner, spender, delegations);
+			}
+		}
+		/// What `spender` delegated of its allowance from `owner`, and so cannot spend itself.
+		fn delegated_of(owner: &T::AccountId, spender: &T::AccountId) -> T::Balance {
+			<Delegations<T>>::get(owner, spender)
+				.iter()
+				.fold(Zero::zero(), |total, (_, amount)| total.saturating_add(*amount))
+		}
+		/// Charges `value` to the part of its allowance from `owner` that `spender` has not
+		/// delegated, or if it holds none, to what was delegated to it, and to the delegating
+		/// spender's allowance with it.
+		fn charge_allowance(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			if let Some(allowance) = Self::allowance_of(owner, spender) {
+				Self::ensure_allowance_live(owner, spender)?;
+				let delegated = Self::delegated_of(owner, spender);
+				ensure!(
+					allowance.saturating_sub(delegated) >= value,
+					Error::<T>::AllowanceExceeded
+				);
+				return Self::_allowance_set(owner, spender, allowance - value)
+			}
+			let delegator = <DelegatedAllowances<T>>::get(owner, spender)
+				.ok_or(Error::<T>::ApprovalNotGranted)?;
+			// Delegated shares live as long as the allowance they were carved from.
+			Self::ensure_allowance_live(owner, &delegator)?;
+			let mut delegations = <Delegations<T>>::get(owner, &delegator);
+			let index = delegations
+				.iter()
+				.position(|(delegate, _)| delegate == spender)
+				.ok_or(Error::<T>::ApprovalNotGranted)?;
+			let share = delegations[index].1;
+			ensure!(share >= value, Error::<T>::AllowanceExceeded);
+			if share == value {
+				delegations.remove(index);
+				<DelegatedAllowances<T>>::remove(owner, spender);
+			} else {
+				delegations[index].1 = share - value;
+			}
+			Self::put_delegations(owner, &delegator, delegations);
+			let allowance =
+				Self::allowance_of(owner, &delegator).ok_or(Error::<T>::ApprovalNotGranted)?;
+			Self::_allowance_set(owner, &delegator, allowance - value)
+		}
+		/// Fails with `AllowanceExpired` if `owner`'s allowance for `spender` is past its
+		/// deadline.
+		fn ensure_allowance_live(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+		) -> Result<(), Error<T>> {
+			ensure!(!Self::allowance_expired(owner, spender), Error::<T>::AllowanceExpired);
+			Ok(())
+		}
+		fn allowance_expired(owner: &T::AccountId, spender: &T::AccountId) -> bool {
+			<AllowanceExpiry<T>>::get((owner, spender))
+				.map_or(false, |deadline| deadline < frame_system::Pallet::<T>::block_number())
+		}
+		/// Sets or clears the deadline of `owner`'s allowance for `spender`, keeping
+		/// `ExpiringAllowances` in step.
+		fn set_allowance_expiry(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			deadline: Option<BlockNumberFor<T>>,
+		) {
+			if let Some(old) = <AllowanceExpiry<T>>::take((owner, spender)) {
+				<ExpiringAllowances<T>>::remove(old, (owner, spender));
+			}
+			if let Some(deadline) = deadline {
+				// Deadlines are never in the past, so the sweep starts no later than this one.
+				if !<AllowanceSweepCursor<T>>::exists() {
+					<AllowanceSweepCursor<T>>::put(frame_system::Pallet::<T>::block_number());
+				}
+				<AllowanceExpiry<T>>::insert((owner, spender), deadline);
+				<ExpiringAllowances<T>>::insert(deadline, (owner, spender), ());
+			}
+		}
+		/// Removes the allowances whose deadline passed, one deadline at a time from where the
+		/// last sweep stopped, for as long as `remaining_weight` lasts. Deadlines still to come
+		/// are never visited.
+		fn sweep_expired_allowances(remaining_weight: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let mut used = db.reads(1);
+			let Some(mut deadline) = <AllowanceSweepCursor<T>>::get() else { return used };
+			used = used.saturating_add(db.writes(1));
+			// Looking up the next allowance of a deadline, and removing it if there is one.
+			let (per_lookup, per_entry) = (db.reads(1), T::WeightInfo::expire_allowance());
+			let now = frame_system::Pallet::<T>::block_number();
+			while deadline < now &&
+				!remaining_weight
+					.any_lt(used.saturating_add(per_lookup).saturating_add(per_entry))
+			{
+				used = used.saturating_add(per_lookup);
+				let Some((owner, spender)) =
+					<ExpiringAllowances<T>>::iter_key_prefix(deadline).next()
+				else {
+					deadline = deadline.saturating_add(One::one());
+					continue
+				};
+				used = used.saturating_add(per_entry);
+				// Unindexed first, so that a failed removal cannot stall the sweep here.
+				<ExpiringAllowances<T>>::remove(deadline, (&owner, &spender));
+				// Removing an allowance cannot fail, and returns its deposit.
+				let _ = Self::write_allowance(&owner, &spender, Zero::zero());
+				Self::deposit_event(Event::AllowanceExpired { owner, spender });
+			}
+			<AllowanceSweepCursor<T>>::put(deadline);
+			used
+		}
+		/// Like `_allowance_set`, for a purpose other than [`DEFAULT_PURPOSE`].
+		fn _scoped_allowance_set(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			purpose: Purpose,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			Self::ensure_not_paused()?;
+			Self::write_scoped_allowance(owner, spender, purpose, value)
+		}
+		/// Like `write_allowance`, for a purpose other than [`DEFAULT_PURPOSE`].
+		fn write_scoped_allowance(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			purpose: Purpose,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			let key = (owner, spender, purpose);
+			if value.is_zero() {
+				if let Some((_, deposit)) = <ScopedAllowances<T>>::take(key) {
+					T::Currency::unreserve(owner, deposit);
+					<ScopedApprovalCount<T>>::mutate_exists(owner, |count| {
+						*count = count.map(|count| count - 1).filter(|count| *count > 0)
+					});
+				}
+				return Ok(())
+			}
+			if let Some((_, deposit)) = <ScopedAllowances<T>>::get(key) {
+				<ScopedAllowances<T>>::insert(key, (value, deposit));
+				return Ok(())
+			}
+			ensure!(
+				Self::approval_entries(owner) < T::MaxApprovalsPerOwner::get(),
+				Error::<T>::TooManyApprovals
+			);
+			let deposit = T::AllowanceDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::reserve(owner, deposit)
+					.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+			}
+			<ScopedAllowances<T>>::insert(key, (value, deposit));
+			<ScopedApprovalCount<T>>::mutate(owner, |count| *count += 1);
+			Ok(())
+		}
+		/// Like `write_allowance`, for a launched token. Such allowances have no delegations
+		/// or deadlines, but hold a deposit and count against `MaxApprovalsPerOwner`.
+		fn write_asset_allowance(
+			id: T::AssetId,
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			let key = (id, owner, spender);
+			let existed = <Allowances<T>>::contains_key(key);
+			if value.is_zero() {
+				if existed {
+					<Allowances<T>>::remove(key);
+					if let Some(deposit) = <AssetAllowanceDeposits<T>>::take(key) {
+						T::Currency::unreserve(owner, deposit);
+					}
+					<AssetApprovalCount<T>>::mutate_exists(owner, |count| {
+						*count = count.map(|count| count - 1).filter(|count| *count > 0)
+					});
+				}
+				return Ok(())
+			}
+			if !existed {
+				ensure!(
+					Self::approval_entries(owner) < T::MaxApprovalsPerOwner::get(),
+					Error::<T>::TooManyApprovals
+				);
+				let deposit = T::AllowanceDeposit::get();
+				if !deposit.is_zero() {
+					T::Currency::reserve(owner, deposit)
+						.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+					<AssetAllowanceDeposits<T>>::insert(key, deposit);
+				}
+				<AssetApprovalCount<T>>::mutate(owner, |count| *count += 1);
+			}
+			<Allowances<T>>::insert(key, value);
+			Ok(())
+		}
+		/// Allowances of every kind `owner` has granted, as bounded by `MaxApprovalsPerOwner`.
+		fn approval_entries(owner: &T::AccountId) -> u32 {
+			<Approvals<T>>::decode_len(owner).unwrap_or(0) as u32 +
+				<ScopedApprovalCount<T>>::get(owner) +
+				<AssetApprovalCount<T>>::get(owner)
+		}
+		/// Moves `value` of `owner`'s tokens to `to` for a spender whose allowance has already
+		/// been charged. Returns the demurrage both settled first.
+		fn _spend(
+			owner: &T::AccountId,
+			to: &T::AccountId,
+			value: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			Self::atomically(|| {
+				Self::ensure_not_paused()?;
+				Self::ensure_not_burn_address(owner)?;
+				Self::ensure_accepts(Some(owner), to)?;
+				Self::before_transfer(Some(owner), Some(to), value)?;
+				Self::consume_attestation(owner, to, value)?;
+				Self::check_kyc(owner, value)?;
+				let demurrage =
+					Self::settle_demurrage(owner)?.saturating_add(Self::settle_demurrage(to)?);
+				let remaining = Self::balance_after_debit(owner, value)?;
+				Self::record_outflow(owner, value)?;
+				Self::_balance_set(owner, remaining)?;
+				Self::credit(owner, to, value)?;
+				Self::remove_dust(owner)?;
+				T::OnTransfer::after_transfer(Some(owner), Some(to), value);
+				Ok(demurrage)
+			})
+		}
+		fn record_spend(owner: &T::AccountId, spender: &T::AccountId, value: T::Balance) {
+			if !value.is_zero() {
+				<SpentBy<T>>::mutate(owner, spender, |spent| *spent = spent.saturating_add(value));
+			}
+		}
+		/// Removes and returns whichever layout holds the allowance.
+		fn take_allowance(owner: &T::AccountId, spender: &T::AccountId) -> Option<T::Balance> {
+			<Allowances<T>>::take((T::NativeAsset::get(), owner, spender))
+				.or_else(|| <Allowance<T>>::take((owner, spender)))
+		}
+		/// Reads the new layout first, then the legacy one while the re-keying runs.
+		fn allowance_of(owner: &T::AccountId, spender: &T::AccountId) -> Option<T::Balance> {
+			<Allowances<T>>::get((T::NativeAsset::get(), owner, spender))
+				.or_else(|| <Allowance<T>>::get((owner, spender)))
+		}
+		/// Moves up to `AllowanceMigrationBatch` legacy allowances into `Allowances`, finishing
+		/// the re-keying once a block finds fewer than that left.
+		///
+		/// The upgrade that starts the re-keying also runs [`migrations::v5::RekeyByAsset`] before
+		/// the first block, so entries land under `NativeAsset` and the storage version only
+		/// rises to 3 if it is still lower.
+		fn step_allowance_rekey() -> Weight {
+			let Some(mut moved) = <AllowanceRekey<T>>::get() else {
+				return T::DbWeight::get().reads(1)
+			};
+			let batch = T::AllowanceMigrationBatch::get() as u64;
+			let native = T::NativeAsset::get();
+			let mut step = 0u64;
+			for ((owner, spender), value) in <Allowance<T>>::drain().take(batch as usize) {
+				<Allowances<T>>::insert((native, &owner, &spender), value);
+				step += 1;
+			}
+			moved += step;
+			if step < batch {
+				<AllowanceRekey<T>>::kill();
+				if Self::on_chain_storage_version() < 3 {
+					StorageVersion::new(3).put::<Self>();
+				}
+				Self::deposit_event(Event::AllowancesRekeyed { moved });
+			} else {
+				<AllowanceRekey<T>>::put(moved);
+			}
+			T::DbWeight::get().reads_writes(step + 2, step * 2 + 2)
+		}
+		/// Fails while `migrate_to_assets` runs, and once `initiate_sunset` was called.
+		fn ensure_not_migrating() -> Result<(), Error<T>> {
+			ensure!(
+				!<AssetMigration<T>>::exists() && !<Sunset<T>>::exists(),
+				Error::<T>::MigrationInProgress
+			);
+			Ok(())
+		}
+		/// Fails with `TokenPaused` while the token is paused.
+		///
+		/// The helpers that move tokens or set allowances check it, so that every call built on
+		/// them stops. Calls that move balances by hand check it themselves.
+		fn ensure_not_paused() -> Result<(), Error<T>> {
+			ensure!(!<Paused<T>>::get(), Error::<T>::TokenPaused);
+			Ok(())
+		}
+		/// Moves up to `AssetMigrationBatch` balances into the asset, then allowances once no
+		/// balance is left to walk, finishing the migration once a block finds fewer
+		/// allowances than it had room for.
+		///
+		/// A balance the asset refuses is left in place and skipped from then on. An allowance
+		/// it refuses is dropped: its deposit here is returned either way.
+		fn step_asset_migration() -> Weight {
+			let Some(mut status) = <AssetMigration<T>>::get() else {
+				return T::DbWeight::get().reads(1)
+			};
+			let batch = T::AssetMigrationBatch::get() as usize;
+			let asset = status.asset;
+			let native = T::NativeAsset::get();
+			let mut step = 0usize;
+			if !status.balances_done {
+				let holders: Vec<_> = match &status.resume_after {
+					Some(who) => <BalanceOf<T>>::iter_prefix_from(
+						native,
+						<BalanceOf<T>>::hashed_key_for(native, who),
+					),
+					None => <BalanceOf<T>>::iter_prefix(native),
+				}
+				.take(batch)
+				.collect();
+				step = holders.len();
+				for (who, balance) in holders {
+					match with_storage_layer(|| Self::migrate_balance(asset, &who, balance)) {
+						Ok(()) => {
+							status.holders += 1;
+							status.tokens = status.tokens.saturating_add(balance);
+						},
+						Err(e) => {
+							log::warn!(target: LOG_TARGET, "Balance of {:?} stranded: {:?}", who, e);
+							status.stranded += 1;
+							status.resume_after = Some(who);
+						},
+					}
+				}
+				if step < batch {
+					status.balances_done = true;
+					status.resume_after = None;
+				}
+			}
+			let mut finished = false;
+			if status.balances_done && step < batch {
+				let room = batch - step;
+				let allowances: Vec<_> = <Allowances<T>>::iter_prefix((native,))
+					.map(|((owner, spender), value)| (owner, spender, value))
+					.chain(
+						<Allowance<T>>::iter()
+							.map(|((owner, spender), value)| (owner, spender, value)),
+					)
+					.take(room)
+					.collect();
+				finished = allowances.len() < room;
+				step += allowances.len();
+				for (owner, spender, value) in allowances {
+					let expired = Self::allowance_expired(&owner, &spender);
+					// Removing an allowance cannot fail, and returns its deposit.
+					let _ = Self::write_allowance(&owner, &spender, Zero::zero());
+					if expired {
+						status.dropped_allowances += 1;
+						continue
+					}
+					let approved = with_storage_layer(|| {
+						let value = value.saturated_into::<u128>().saturated_into();
+						T::Assets::approve(asset, &owner, &spender, value)
+					});
+					if approved.is_ok() {
+						status.allowances += 1;
+					} else {
+						status.dropped_allowances += 1;
+					}
+				}
+			}
+			if finished {
+				<AssetMigration<T>>::kill();
+				Self::deposit_event(Event::AssetMigrationCompleted {
+					asset,
+					holders: status.holders,
+					tokens: status.tokens,
+					allowances: status.allowances,
+					stranded: status.stranded,
+					dropped_allowances: status.dropped_allowances,
+				});
+			} else {
+				Self::deposit_event(Event::AssetMigrationProgress {
+					holders: status.holders,
+					tokens: status.tokens,
+					allowances: status.allowances,
+				});
+				<AssetMigration<T>>::put(status);
+			}
+			// Each entry touches its own storage, the asset's and the account's, and the
+			// balances share the two top holders items.
+			let step = step as u64;
+			T::DbWeight::get().reads_writes(step * 5 + 4, step * 5 + 3)
+		}
+		/// Winds down up to `SunsetBatch` holders, finishing the sunset once a block finds fewer
+		/// than that left. The successor, and any holder that could not be wound down, are
+		/// skipped from then on.
+		fn step_sunset() -> Weight {
+			let Some(mut status) = <Sunset<T>>::get() else { return T::DbWeight::get().reads(1) };
+			if status.finished {
+				return T::DbWeight::get().reads(1)
+			}
+			let batch = T::SunsetBatch::get() as usize;
+			let native = T::NativeAsset::get();
+			let holders: Vec<_> = match &status.resume_after {
+				Some(who) => <BalanceOf<T>>::iter_prefix_from(
+					native,
+					<BalanceOf<T>>::hashed_key_for(native, who),
+				),
+				None => <BalanceOf<T>>::iter_prefix(native),
+			}
+			.take(batch)
+			.collect();
+			let step = holders.len();
+			let mut allowances = 0u64;
+			for (who, balance) in holders {
+				if status.destination == SunsetDestination::Successor(who.clone()) {
+					status.resume_after = Some(who);
+					continue
+				}
+				match with_storage_layer(|| Self::sunset_holder(&status, &who, balance)) {
+					Ok((refund, cleared)) => {
+						status.holders += 1;
+						status.tokens = status.tokens.saturating_add(balance);
+						status.refunded = status.refunded.saturating_add(refund);
+						allowances += cleared;
+					},
+					Err(e) => {
+						log::warn!(target: LOG_TARGET, "Balance of {:?} stranded: {:?}", who, e);
+						status.stranded += 1;
+						status.resume_after = Some(who);
+					},
+				}
+			}
+			if step < batch {
+				status.finished = true;
+				status.resume_after = None;
+				Self::deposit_event(Event::SunsetComplete {
+					holders: status.holders,
+					tokens: status.tokens,
+					refunded: status.refunded,
+					stranded: status.stranded,
+				});
+			}
+			<Sunset<T>>::put(status);
+			// Each holder touches about a dozen items of its own, the native accounts and the
+			// two top holders items, and each allowance its deposit and index.
+			let (step, allowances) = (step as u64, allowances * 4);
+			T::DbWeight::get().reads_writes(step * 14 + allowances + 1, step * 14 + allowances + 1)
+		}
+		/// Clears what `who` granted and set up here, then moves or refunds its `balance`.
+		/// Returns the refund paid and the number of allowances removed.
+		fn sunset_holder(
+			status: &SunsetStatus<T>,
+			who: &T::AccountId,
+			balance: T::Balance,
+		) -> Result<(DepositBalanceOf<T>, u64), DispatchError> {
+			let spenders = <Approvals<T>>::get(who);
+			for spender in spenders.iter() {
+				Self::write_allowance(who, spender, Zero::zero())?;
+			}
+			let scoped: Vec<_> = <ScopedAllowances<T>>::iter_key_prefix((who,)).collect();
+			for (spender, purpose) in scoped.iter() {
+				Self::write_scoped_allowance(who, spender, *purpose, Zero::zero())?;
+			}
+			<FrozenBalance<T>>::remove(who);
+			<SavingsLock<T>>::remove(who);
+			<SavingTranches<T>>::remove(who);
+			<VestingSchedules<T>>::remove(who);
+			if <TransferLimits<T>>::take(who).is_some() {
+				<LimitedAccounts<T>>::mutate(|count| *count = count.saturating_sub(1));
+			}
+			<FreeTransfers<T>>::remove(who);
+			<KycOutflow<T>>::remove(who);
+			Self::_balance_set(who, Zero::zero())?;
+			let refund = match &status.destination {
+				SunsetDestination::Successor(to) => {
+					Self::_balance_set(to, Self::_balance_of(to).saturating_add(balance))?;
+					Self::deposit_event(Event::SunsetMoved {
+						who: who.clone(),
+						to: to.clone(),
+						value: balance,
+					});
+					Zero::zero()
+				},
+				SunsetDestination::Refund(pot) => {
+					Self::burn_supply(who, balance);
+					let refund = multiply_by_rational_with_rounding(
+						status.pool.saturated_into(),
+						balance.saturated_into(),
+						status.supply.saturated_into(),
+						Rounding::Down,
+					)
+					.unwrap_or(0)
+					.saturated_into();
+					T::Currency::transfer(pot, who, refund, ExistenceRequirement::AllowDeath)?;
+					Self::deposit_event(Event::SunsetRefunded {
+						who: who.clone(),
+						value: balance,
+						refund,
+					});
+					refund
+				},
+			};
+			Ok((refund, (spenders.len() + scoped.len()) as u64))
+		}
+		/// Mints `balance` of `asset` to `who` and removes it from this pallet.
+		fn migrate_balance(
+			asset: AssetIdOf<T>,
+			who: &T::AccountId,
+			balance: T::Balance,
+		) -> DispatchResult {
+			let amount = balance.saturated_into::<u128>().saturated_into();
+			T::Assets::mint_into(asset, who, amount)?;
+			Self::_balance_set(who, Zero::zero())?;
+			Self::_total_supply_set(Self::_total_supply().saturating_sub(balance));
+			Ok(())
+		}
+		/// Root of the claims tree reached from `leaf` through `proof`. Each pair of nodes is
+		/// hashed smaller first, so proofs need no left or right markers.
+		pub(crate) fn claims_root_of(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+			proof.iter().fold(leaf, |node, sibling| {
+				let (first, second) =
+					if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+				keccak_256(&[first, second].concat())
+			})
+		}
+		/// The Ethereum address whose key made `signature` over [`Pallet::claim_message`] for
+		/// `who`, if the signature is well formed.
+		fn eth_recover(signature: &EcdsaSignature, who: &T::AccountId) -> Option<EthereumAddress> {
+			let hash = keccak_256(&Self::claim_message(who));
+			let public = secp256k1_ecdsa_recover(signature, &hash).ok()?;
+			let mut address = EthereumAddress::default();
+			address.copy_from_slice(&keccak_256(&public)[12..]);
+			Some(address)
+		}
+		fn compute_domain_separator() -> [u8; 32] {
+			let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+			(
+				b"template::domain",
+				T::DomainName::get(),
+				T::DomainVersion::get(),
+				genesis_hash,
+				T::Version::get().spec_version,
+				<Self as PalletInfoAccess>::index() as u32,
+				<Self as PalletInfoAccess>::name().as_bytes(),
+			)
+				.using_encoded(blake2_256)
+		}
+		/// Recomputes `DomainSeparator`, writing it only when an input has changed.
+		fn refresh_domain_separator() -> Weight {
+			let separator = Self::compute_domain_separator();
+			if <DomainSeparator<T>>::get() == separator {
+				return T::DbWeight::get().reads(2)
+			}
+			<DomainSeparator<T>>::put(separator);
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+		fn release_inbound(nonce: u64, to: T::AccountId, amount: T::Balance) -> DispatchResult {
+			Self::ensure_not_paused()?;
+			ensure!(!<ProcessedInbound<T>>::contains_key(nonce), Error::<T>::InboundNonceProcessed);
+			if T::BridgeBurns::get() {
+				Self::ensure_mint_rate(amount)?;
+				Self::_mint(&to, amount)?;
+			} else {
+				let pot = T::BridgePot::get();
+				Self::ensure_accepts(Some(&pot), &to)?;
+				Self::settle_demurrage(&to)?;
+				let locked = Self::_balance_of(&pot);
+				ensure!(locked >= amount, Error::<T>::FundsUnavailable);
+				Self::_balance_set(&pot, locked - amount)?;
+				Self::_balance_set(&to, Self::_balance_of(&to).saturating_add(amount))?;
+			}
+			<ProcessedInbound<T>>::insert(nonce, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::BridgedIn { nonce, to, amount });
+			Ok(())
+		}
+		/// Shares whatever reached the staking pot since the last update among the current
+		/// stake.
+		fn update_reward_per_share() {
+			let total = Self::total_staked();
+			if total.is_zero() {
+				return
+			}
+			let accounted = <RewardsAccounted<T>>::get();
+			let fresh = Self::_balance_of(&T::StakingPot::get()).saturating_sub(accounted);
+			if fresh.is_zero() {
+				return
+			}
+			<RewardPerShare<T>>::mutate(|per_share| {
+				*per_share = per_share.saturating_add(Self::reward_per_share_of(fresh, total))
+			});
+			<RewardsAccounted<T>>::put(accounted.saturating_add(fresh));
+		}
+		/// What `fresh` rewards add to `RewardPerShare` when shared among `total` stake.
+		fn reward_per_share_of(fresh: T::Balance, total: T::Balance) -> u128 {
+			fresh.saturated_into::<u128>().saturating_mul(REWARD_PRECISION) /
+				total.saturated_into::<u128>()
+		}
+		fn reward_debt(active: T::Balance) -> u128 {
+			active.saturated_into::<u128>().saturating_mul(<RewardPerShare<T>>::get())
+		}
+		/// Pays `who` what its active stake earned since `ledger.reward_debt` was last set. The
+		/// caller resets the debt once it has changed the stake.
+		fn pay_rewards(who: &T::AccountId, ledger: &mut StakingLedger<T>) -> Result<(), Error<T>> {
+			Self::update_reward_per_share();
+			let earned = Self::reward_debt(ledger.active).saturating_sub(ledger.reward_debt) /
+				REWARD_PRECISION;
+			let pot = T::StakingPot::get();
+			let amount = (earned.saturated_into::<T::Balance>()).min(Self::_balance_of(&pot));
+			ledger.reward_debt = Self::reward_debt(ledger.active);
+			if amount.is_zero() {
+				return Ok(())
+			}
+			let balance =
+				Self::_balance_of(who).checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			Self::_balance_set(&pot, Self::_balance_of(&pot) - amount)?;
+			Self::_balance_set(who, balance)?;
+			<RewardsAccounted<T>>::mutate(|accounted| {
+				*accounted = accounted.saturating_sub(amount)
+			});
+			Self::deposit_event(Event::RewardPaid { who: who.clone(), amount });
+			Ok(())
+		}
+		/// How many more tokens may be created before `TotalSupply` hits `MaxSupply`, or the
+		/// largest balance if there is no cap.
+		fn mintable() -> T::Balance {
+			<MaxSupply<T>>::get()
+				.unwrap_or_else(T::Balance::max_value)
+				.saturating_sub(Self::_total_supply())
+		}
+		/// Removes and returns `payer`'s open request `id`.
+		fn take_request(payer: &T::AccountId, id: u64) -> Result<PaymentRequest<T>, Error<T>> {
+			<PaymentRequests<T>>::try_mutate_exists(payer, |maybe_requests| {
+				let requests = maybe_requests.as_mut().ok_or(Error::<T>::UnknownRequest)?;
+				let index = requests
+					.iter()
+					.position(|request| request.id == id)
+					.ok_or(Error::<T>::UnknownRequest)?;
+				let request = requests.remove(index);
+				if requests.is_empty() {
+					*maybe_requests = None;
+				}
+				Ok(request)
+			})
+		}
+		fn prune_expired_requests(
+			requests: &mut BoundedVec<PaymentRequest<T>, T::MaxPendingRequests>,
+		) {
+			let now = frame_system::Pallet::<T>::block_number();
+			requests.retain(|request| now <= request.expires_at);
+		}
+		/// Uses up the oracle's ticket for a transfer at or above `AttestationThreshold`.
+		fn consume_attestation(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			if value < T::AttestationThreshold::get() {
+				return Ok(())
+			}
+			let expiry = <Attestations<T>>::take((from, to, value))
+				.ok_or(Error::<T>::AttestationRequired)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= expiry,
+				Error::<T>::AttestationRequired
+			);
 			Ok(())
 		}
-		#[pallet::weight(10_000)]
-		pub fn transfer_from(
-			origin: OriginFor<T>,
-			from: T::AccountId,
-			to: T::AccountId,
-			value: u64,
-		) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-			Self::_transfer_from(from, to, value)?;
+		/// Counts `value` against the limits of `from`'s KYC tier.
+		fn check_kyc(from: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			let tier = T::KycProvider::kyc_tier(from);
+			let limit = match tier {
+				KycTier::None => T::KycNoneLimit::get(),
+				KycTier::Basic => T::KycBasicLimit::get(),
+				KycTier::Full => T::KycFullLimit::get(),
+			};
+			ensure!(value <= limit.max_transfer, Error::<T>::KycLimitExceeded { tier });
+			if limit.max_per_window == T::Balance::max_value() {
+				return Ok(())
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let (mut window_start, mut sent) =
+				<KycOutflow<T>>::get(from).unwrap_or((now, Zero::zero()));
+			if Self::roll_window(&mut window_start, T::KycWindow::get()) {
+				sent = Zero::zero();
+			}
+			let sent = sent
+				.checked_add(&value)
+				.filter(|sent| *sent <= limit.max_per_window)
+				.ok_or(Error::<T>::KycLimitExceeded { tier })?;
+			<KycOutflow<T>>::insert(from, (window_start, sent));
 			Ok(())
 		}
-	}
-	impl<T: Config> Pallet<T> {
-		fn _total_supply() -> u64 {
-			<TotalSupply<T>>::get().unwrap_or(0)
+		fn frozen_of(who: &T::AccountId) -> T::Balance {
+			<FrozenBalance<T>>::get(who).unwrap_or_default()
+		}
+		/// What `who`'s balance comes to once `value` is taken out of it. Fails with
+		/// `FundsUnavailable` if the balance does not cover `value`, and with `Frozen` if the
+		/// rest would dip into `who`'s frozen amount.
+		fn balance_after_debit(
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			let remaining =
+				Self::_balance_of(who).checked_sub(&value).ok_or(Error::<T>::FundsUnavailable)?;
+			ensure!(remaining >= Self::locked_of(who), Error::<T>::Frozen);
+			Ok(remaining)
+		}
+		/// What `who` cannot move out of its balance: the frozen amount, the savings tranches
+		/// that have not matured yet and what its vesting schedules lock.
+		fn locked_of(who: &T::AccountId) -> T::Balance {
+			let now = frame_system::Pallet::<T>::block_number();
+			let vesting = <VestingSchedules<T>>::get(who)
+				.iter()
+				.fold(T::Balance::zero(), |total, schedule| total.saturating_add(schedule.locked));
+			<SavingTranches<T>>::get(who)
+				.iter()
+				.filter(|(until, _)| *until > now)
+				.fold(Self::frozen_of(who), |locked, (_, value)| locked.saturating_add(*value))
+				.saturating_add(vesting)
+		}
+		/// Locks `value` just credited to `who` for its `SavingsLock`, if it is in savings mode.
+		fn lock_savings(who: &T::AccountId, value: T::Balance) {
+			let Some(lock) = <SavingsLock<T>>::get(who) else { return };
+			if value.is_zero() {
+				return
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut until = now.saturating_add(lock);
+			let mut tranches = <SavingTranches<T>>::get(who);
+			if tranches.len() as u32 >= T::MaxSavingTranches::get() {
+				tranches.retain(|(until, _)| *until > now);
+			}
+			let index = tranches.partition_point(|(at, _)| *at <= until);
+			if index > 0 && tranches[index - 1].0 == until {
+				tranches[index - 1].1 = tranches[index - 1].1.saturating_add(value);
+			} else if tranches.try_insert(index, (until, value)).is_err() {
+				// Every tranche is pending: the latest takes the tokens too, and unlocks no
+				// earlier than they would have.
+				let Some(last) = tranches.last_mut() else { return };
+				last.0 = last.0.max(until);
+				last.1 = last.1.saturating_add(value);
+				until = last.0;
+			}
+			<SavingTranches<T>>::insert(who, tranches);
+			Self::deposit_event(Event::SavingsLocked { who: who.clone(), value, until });
+		}
+		fn reserved_of(who: &T::AccountId) -> T::Balance {
+			<ReservedBalance<T>>::get(who).unwrap_or_default()
+		}
+		/// Every write of `ReservedBalance` goes through here. Like a balance, a reserved
+		/// bucket holds a provider reference, so an account whose tokens are all reserved is
+		/// not reaped.
+		fn reserved_set(who: &T::AccountId, reserved: T::Balance) -> Result<(), Error<T>> {
+			let existed = <ReservedBalance<T>>::contains_key(who);
+			if reserved.is_zero() {
+				if existed {
+					frame_system::Pallet::<T>::dec_providers(who)
+						.map_err(|_| Error::<T>::StillReferenced)?;
+					<ReservedBalance<T>>::remove(who);
+				}
+				return Ok(())
+			}
+			if !existed {
+				frame_system::Pallet::<T>::inc_providers(who);
+			}
+			<ReservedBalance<T>>::insert(who, reserved);
+			Ok(())
+		}
+		/// Moves `value` of `who`'s balance into its reserved bucket. Frozen tokens cannot be
+		/// reserved.
+		fn do_reserve(who: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			Self::atomically(|| {
+				if value.is_zero() {
+					return Ok(())
+				}
+				Self::settle_demurrage(who)?;
+				let remaining = Self::balance_after_debit(who, value)?;
+				let reserved =
+					Self::reserved_of(who).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+				// Reserve first, so that the account keeps a provider if its balance empties.
+				Self::reserved_set(who, reserved)?;
+				Self::_balance_set(who, remaining)?;
+				Self::deposit_event(Event::Reserved { who: who.clone(), amount: value });
+				Ok(())
+			})
+		}
+		/// Moves up to `value` of `who`'s reserved tokens back to its balance and returns how
+		/// much that was.
+		fn do_unreserve(who: &T::AccountId, value: T::Balance) -> Result<T::Balance, Error<T>> {
+			Self::atomically(|| {
+				let reserved = Self::reserved_of(who);
+				let amount = value.min(reserved);
+				if amount.is_zero() {
+					return Ok(Zero::zero())
+				}
+				Self::settle_demurrage(who)?;
+				// Credit the balance first, so that the account keeps a provider.
+				Self::_balance_set(who, Self::_balance_of(who).saturating_add(amount))?;
+				Self::reserved_set(who, reserved - amount)?;
+				Self::deposit_event(Event::Unreserved { who: who.clone(), amount });
+				Ok(amount)
+			})
+		}
+		/// Takes up to `value` out of `who`'s reserved bucket, without touching the supply, and
+		/// returns how much that was.
+		fn take_reserved(who: &T::AccountId, value: T::Balance) -> T::Balance {
+			let reserved = Self::reserved_of(who);
+			let amount = value.min(reserved);
+			match Self::reserved_set(who, reserved - amount) {
+				Ok(()) => amount,
+				Err(_) => Zero::zero(),
+			}
+		}
+		/// Applies `f` to `who`'s named reserve `id`, keeping `NamedReserves` sorted and free
+		/// of empty entries. Leaves `ReservedBalance` to the caller.
+		fn mutate_named(
+			id: &T::ReserveIdentifier,
+			who: &T::AccountId,
+			f: impl FnOnce(T::Balance) -> Result<T::Balance, Error<T>>,
+		) -> Result<(), Error<T>> {
+			<NamedReserves<T>>::try_mutate_exists(who, |maybe_reserves| {
+				let mut reserves = maybe_reserves.take().unwrap_or_default();
+				match reserves.binary_search_by_key(id, |(reserve, _)| *reserve) {
+					Ok(index) => match f(reserves[index].1)? {
+						amount if amount.is_zero() => {
+							reserves.remove(index);
+						},
+						amount => reserves[index].1 = amount,
+					},
+					Err(index) => match f(Zero::zero())? {
+						amount if amount.is_zero() => {},
+						amount => reserves
+							.try_insert(index, (*id, amount))
+							.map_err(|_| Error::<T>::TooManyReserves)?,
+					},
+				}
+				*maybe_reserves = (!reserves.is_empty()).then_some(reserves);
+				Ok(())
+			})
+		}
+		/// Hands `amount` slashed from `who` to `OnSlash`. The tokens leave the supply when the
+		/// imbalance carrying them is dropped.
+		fn report_slash(who: &T::AccountId, amount: T::Balance) {
+			if !amount.is_zero() {
+				T::OnSlash::on_slash(who, amount);
+				Self::deposit_event(Event::Slashed { who: who.clone(), amount });
+			}
+		}
+		/// Credits `value` newly created tokens to `who`, leaving `TotalSupply` to the
+		/// imbalance that accounts for them.
+		fn deposit(who: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			Self::atomically(|| {
+				Self::ensure_accepts(None, who)?;
+				Self::settle_demurrage(who)?;
+				ensure!(value <= Self::mintable(), Error::<T>::Overflow);
+				let balance =
+					Self::_balance_of(who).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+				Self::ensure_can_endow(who, value)?;
+				Self::_balance_set(who, balance)?;
+				Self::lock_savings(who, value);
+				Self::index_movement(None, Some(who), value);
+				Ok(())
+			})
 		}
-		fn _balance_of(who: &T::AccountId) -> u64 {
-			<BalanceOf<T>>::get(who).unwrap_or(0)
+		/// Fails with `TooManyHolders` if crediting `value` to `who` would add a holder while
+		/// `MaxHolders` accounts already hold tokens. Debit the sender first, so that moving
+		/// a whole balance to a new account is not refused at the cap.
+		fn ensure_can_endow(who: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			let Some(max) = T::MaxHolders::get() else { return Ok(()) };
+			ensure!(
+				value.is_zero() ||
+					<BalanceOf<T>>::contains_key(T::NativeAsset::get(), who) ||
+					Self::holder_count() < max,
+				Error::<T>::TooManyHolders
+			);
+			Ok(())
+		}
+		/// Fails if `from` or `to` is frozen, or if `to` is in strict mode and `from` is not on
+		/// its allow-list. Tokens without a sender, i.e. minted ones, are never accepted in
+		/// strict mode.
+		fn ensure_accepts(from: Option<&T::AccountId>, to: &T::AccountId) -> Result<(), Error<T>> {
+			if let Some(from) = from {
+				Self::ensure_not_frozen(from)?;
+			}
+			Self::ensure_not_frozen(to)?;
+			if !<StrictReceivers<T>>::contains_key(to) {
+				return Ok(())
+			}
+			match from {
+				Some(from) if <AllowedSenders<T>>::contains_key((to, from)) => Ok(()),
+				_ => Err(Error::<T>::RecipientNotAccepting),
+			}
+		}
+		/// Asks `OnTransfer` whether the movement may go ahead, failing with `TransferVetoed`
+		/// if not. The reason is logged.
+		fn before_transfer(
+			from: Option<&T::AccountId>,
+			to: Option<&T::AccountId>,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			T::OnTransfer::before_transfer(from, to, value).map_err(|e| {
+				log::debug!(target: LOG_TARGET, "Movement vetoed: {:?}", e);
+				Error::<T>::TransferVetoed
+			})
+		}
+		fn ensure_not_frozen(who: &T::AccountId) -> Result<(), Error<T>> {
+			ensure!(!<Frozen<T>>::contains_key(who), Error::<T>::AccountFrozen);
+			Ok(())
+		}
+		fn take_announced_mint(id: u64) -> Result<AnnouncedMint<T>, Error<T>> {
+			<AnnouncedMints<T>>::try_mutate(|announced| {
+				let index = announced
+					.iter()
+					.position(|mint| mint.id == id)
+					.ok_or(Error::<T>::UnknownMint)?;
+				Ok(announced.remove(index))
+			})
+		}
+		/// Counts `value` against `MaxMintPerBlock`. Every path that mints on request, rather
+		/// than by configuration like the block reward, goes through here first.
+		fn ensure_mint_rate(value: T::Balance) -> Result<(), Error<T>> {
+			<MintedThisBlock<T>>::try_mutate(|minted| {
+				*minted = minted
+					.checked_add(&value)
+					.filter(|minted| *minted <= T::MaxMintPerBlock::get())
+					.ok_or(Error::<T>::MintRateExceeded)?;
+				Ok(())
+			})
+		}
+		/// Takes `value` tokens, already removed from `from`'s balance, off `TotalSupply` and
+		/// adds them to `TotalBurned`.
+		fn burn_supply(from: &T::AccountId, value: T::Balance) {
+			Self::_total_supply_set(Self::_total_supply().saturating_sub(value));
+			<TotalBurned<T>>::mutate(|burned| *burned = burned.saturating_add(value));
+			Self::index_movement(Some(from), None, value);
 		}
-		fn _balance_set(who: &T::AccountId, balance: u64) {
-			<BalanceOf<T>>::insert(who, balance);
+		fn is_burn_address(who: &T::AccountId) -> bool {
+			T::BurnAddress::get().as_ref() == Some(who)
+		}
+		fn ensure_not_burn_address(from: &T::AccountId) -> Result<(), Error<T>> {
+			ensure!(!Self::is_burn_address(from), Error::<T>::BurnAddressCannotSend);
+			Ok(())
+		}
+		/// Takes what is left of `who`'s balance once it is below `MinBalance` and sends it to
+		/// `DustReceiver`, or burns it. Balances with a frozen or locked part, and that of
+		/// `DustReceiver` itself, are left alone. Returns whether `who` was reaped.
+		fn remove_dust(who: &T::AccountId) -> Result<bool, Error<T>> {
+			let dust = Self::_balance_of(who);
+			let receiver = T::DustReceiver::get();
+			if dust.is_zero() ||
+				dust >= T::MinBalance::get() ||
+				!Self::locked_of(who).is_zero() ||
+				receiver.as_ref() == Some(who)
+			{
+				return Ok(false)
+			}
+			Self::_balance_set(who, Zero::zero())?;
+			match receiver {
+				Some(receiver) => {
+					let balance = Self::_balance_of(&receiver)
+						.checked_add(&dust)
+						.ok_or(Error::<T>::Overflow)?;
+					Self::_balance_set(&receiver, balance)?;
+				},
+				None => Self::burn_supply(who, dust),
+			}
+			Self::deposit_event(Event::DustLost { account: who.clone(), amount: dust });
+			Ok(true)
 		}
-		fn _check_if_user_has_balance_or_set_zero(who: &T::AccountId) -> u64 {
-			if !<BalanceOf<T>>::contains_key(who) {
-				<BalanceOf<T>>::insert(who, 0);
+		/// Credits `value`, already debited from `from`, to `to`, or burns it when `to` is
+		/// `BurnAddress`.
+		fn credit(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			if Self::is_burn_address(to) {
+				Self::burn_supply(from, value);
+				return Ok(())
 			}
-			<BalanceOf<T>>::get(who).unwrap_or(0)
+			Self::ensure_can_endow(to, value)?;
+			// Read after the debit so that `to == from` nets out.
+			let balance = Self::_balance_of(to).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+			Self::_balance_set(to, balance)?;
+			if from != to {
+				Self::lock_savings(to, value);
+			}
+			Self::_record_transfer(from, to, value);
+			Ok(())
 		}
-		fn _transfer(from: T::AccountId, to: T::AccountId, value: u64) -> Result<(), Error<T>> {
-			ensure!(<BalanceOf<T>>::contains_key(&from), Error::<T>::InsufficientFunds);
-			let from_balance = Self::_balance_of(&from);
-			let to_balance = Self::_check_if_user_has_balance_or_set_zero(&to);
-			ensure!(from_balance >= value, Error::<T>::InsufficientFunds);
-			Self::_balance_set(&from, from_balance - value);
-			Self::_balance_set(&to, to_balance + value);
-			Self::deposit_event(Event::Transfer { from, to, value });
+		/// Creates `value` tokens in `to`'s balance.
+		fn _mint(to: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			Self::atomically(|| {
+				Self::ensure_not_paused()?;
+				Self::ensure_accepts(None, to)?;
+				Self::before_transfer(None, Some(to), value)?;
+				Self::settle_demurrage(to)?;
+				let supply =
+					Self::_total_supply().checked_add(&value).ok_or(Error::<T>::Overflow)?;
+				ensure!(value <= Self::mintable(), Error::<T>::SupplyCapExceeded);
+				let balance =
+					Self::_balance_of(to).checked_add(&value).ok_or(Error::<T>::Overflow)?;
+				Self::ensure_can_endow(to, value)?;
+				Self::check_collateral(supply)?;
+				Self::_balance_set(to, balance)?;
+				Self::lock_savings(to, value);
+				Self::_total_supply_set(supply);
+				Self::index_movement(None, Some(to), value);
+				T::OnTransfer::after_transfer(None, Some(to), value);
+				Ok(())
+			})
+		}
+		/// Sets `owner`'s allowance for `spender` to `value` on `owner`'s signature, using up
+		/// their nonce.
+		fn do_permit(
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+			signature: &T::OffchainSignature,
+		) -> Result<(), Error<T>> {
+			Self::ensure_not_migrating()?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::PermitExpired
+			);
+			let nonce = Self::nonces(&owner);
+			let message = Self::permit_message(&owner, &spender, value, nonce, deadline);
+			ensure!(
+				Self::verify_payload(&owner, &message, signature),
+				Error::<T>::InvalidPermitSignature
+			);
+			<Nonces<T>>::insert(&owner, nonce.wrapping_add(1));
+			Self::_allowance_set(&owner, &spender, value)?;
+			Self::deposit_event(Event::Approval { from: owner, to: spender, value });
 			Ok(())
 		}
-		fn _approve(from: T::AccountId, to: T::AccountId, value: u64) -> Result<(), Error<T>> {
-			ensure!(<BalanceOf<T>>::contains_key(&from), Error::<T>::InsufficientFunds);
-			let from_balance = <BalanceOf<T>>::get(&from).unwrap();
-			let to_balance = Self::_check_if_user_has_balance_or_set_zero(&to);
-			ensure!(from_balance >= value, Error::<T>::InsufficientFunds);
-			Self::_balance_set(&from, from_balance - value);
-			Self::_balance_set(&to, to_balance + value);
-			<Allowance<T>>::insert((&from, &to), value);
-			Self::deposit_event(Event::Approval { from, to, value });
+		/// Emits `UnderCollateralized` if a supply of `supply` exceeds the latest attested
+		/// reserves, or fails with `InsufficientCollateral` if `MintRequiresCollateral` is set.
+		fn check_collateral(supply: T::Balance) -> Result<(), Error<T>> {
+			let Some(attestation) = <LatestReserveAttestation<T>>::get() else { return Ok(()) };
+			if supply > attestation.reserves {
+				ensure!(!T::MintRequiresCollateral::get(), Error::<T>::InsufficientCollateral);
+				Self::deposit_event(Event::UnderCollateralized {
+					supply,
+					reserves: attestation.reserves,
+				});
+			}
 			Ok(())
 		}
+		/// Mints the per-block reward, or whatever is left of it below the supply cap. Never
+		/// fails: a reward that cannot be minted is skipped.
+		fn mint_block_reward() -> Weight {
+			let reward = Self::block_reward().min(Self::mintable());
+			if reward.is_zero() {
+				return T::DbWeight::get().reads(2)
+			}
+			let beneficiary = T::RewardBeneficiary::get();
+			if let Err(e) = Self::_mint(&beneficiary, reward) {
+				log::warn!(target: LOG_TARGET, "Block reward not minted: {:?}", e);
+			} else {
+				Self::deposit_event(Event::RewardMinted { beneficiary, value: reward });
+			}
+			// Reward, supply, the beneficiary's balance and account, the holder count and the
+			// latest reserve attestation.
+			T::DbWeight::get().reads_writes(6, 4)
+		}
+		/// Puts the scheduled parameters into effect once `n` reaches their activation block.
+		fn activate_parameters(n: BlockNumberFor<T>) -> Weight {
+			match <PendingParameters<T>>::get() {
+				Some(change) if change.activates_at <= n => {
+					<PendingParameters<T>>::kill();
+					Self::set_parameters(&change.parameters);
+					Self::deposit_event(Event::ParametersActivated {
+						parameters: change.parameters,
+					});
+					T::DbWeight::get().reads_writes(1, 2)
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+		/// Writes each of `parameters` where the pallet reads it from.
+		fn set_parameters(parameters: &Parameters<T::Balance>) {
+			<RewardPerBlock<T>>::put(parameters.block_reward);
+		}
+		/// Records `TotalSupply` into `SupplyHistory` when `n` starts a new tracking period,
+		/// dropping the period that falls out of the kept history.
+		fn checkpoint_supply(n: BlockNumberFor<T>) -> Weight {
+			let period = T::SupplyTrackingPeriod::get();
+			let n = n.saturated_into::<u32>();
+			if period == 0 || n % period != 0 {
+				return Weight::zero()
+			}
+			let index = n / period;
+			<SupplyHistory<T>>::insert(index, Self::_total_supply());
+			if let Some(expired) = index.checked_sub(T::MaxSupplyHistory::get()) {
+				<SupplyHistory<T>>::remove(expired);
+			}
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+		fn _record_transfer(from: &T::AccountId, to: &T::AccountId, value: T::Balance) {
+			<TransferCount<T>>::mutate(|count| *count = count.saturating_add(1));
+			<TransferVolume<T>>::mutate(|volume| {
+				*volume = volume.saturating_add(value.saturated_into())
+			});
+			<BlockVolume<T>>::mutate(|volume| {
+				*volume = volume.saturating_add(value.saturated_into())
+			});
+			Self::index_movement(Some(from), Some(to), value);
+		}
+		/// Key of the [`TransferRecord`] of the movement indexed when the block's event count
+		/// was `ordinal`, during extrinsic `extrinsic_index` of `block`:
+		/// `TRANSFER_RECORD_PREFIX ++ block ++ extrinsic_index ++ ordinal`, each SCALE-encoded.
+		///
+		/// Movements in hooks count as part of the extrinsic index current at the time.
+		pub fn transfer_record_key(
+			block: BlockNumberFor<T>,
+			extrinsic_index: u32,
+			ordinal: u32,
+		) -> Vec<u8> {
+			let mut key = TRANSFER_RECORD_PREFIX.to_vec();
+			block.encode_to(&mut key);
+			extrinsic_index.encode_to(&mut key);
+			ordinal.encode_to(&mut key);
+			key
+		}
+		/// Writes a [`TransferRecord`] into offchain storage if `IndexTransfers` is set.
+		fn index_movement(
+			from: Option<&T::AccountId>,
+			to: Option<&T::AccountId>,
+			value: T::Balance,
+		) {
+			if !T::IndexTransfers::get() {
+				return
+			}
+			let key = Self::transfer_record_key(
+				frame_system::Pallet::<T>::block_number(),
+				frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+				frame_system::Pallet::<T>::event_count(),
+			);
+			let record = TransferRecord { from: from.cloned(), to: to.cloned(), value };
+			sp_io::offchain_index::set(&key, &record.encode());
+		}
+		/// Runs `f` in a storage layer of its own, so that an error rolls back every write it
+		/// made.
+		///
+		/// Dispatchables already run in one. The helpers that write several items wrap
+		/// themselves as well, because hooks such as the block reward, `PayFromPot` and the
+		/// `Currency` impls enter them from outside any dispatchable. They nest only a few layers
+		/// deep, so the nesting limit is left unchecked.
+		fn atomically<R>(f: impl FnOnce() -> Result<R, Error<T>>) -> Result<R, Error<T>> {
+			transactional::with_transaction_unchecked(|| {
+				let result = f();
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			})
+		}
+		fn _transfer(
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+		) -> Result<(), Error<T>> {
+			Self::_transfer_with_id(from, to, value, None)
+		}
+		fn _transfer_with_id(
+			from: T::AccountId,
+			to: T::AccountId,
+			value: T::Balance,
+			id: Option<TransferId>,
+		) -> Result<(), Error<T>> {
+			Self::atomically(|| {
+				Self::ensure_not_paused()?;
+				Self::ensure_not_burn_address(&from)?;
+				Self::ensure_accepts(Some(&from), &to)?;
+				Self::before_transfer(Some(&from), Some(&to), value)?;
+				Self::consume_attestation(&from, &to, value)?;
+				Self::check_kyc(&from, value)?;
+				let demurrage =
+					Self::settle_demurrage(&from)?.saturating_add(Self::settle_demurrage(&to)?);
+				ensure!(
+					<BalanceOf<T>>::contains_key(T::NativeAsset::get(), &from),
+					Error::<T>::AccountNotFound
+				);
+				let remaining = Self::balance_after_debit(&from, value)?;
+				Self::record_outflow(&from, value)?;
+				Self::_balance_set(&from, remaining)?;
+				Self::credit(&from, &to, value)?;
+				Self::remove_dust(&from)?;
+				T::OnTransfer::after_transfer(Some(&from), Some(&to), value);
+				Self::deposit_transfer_event(from, to, value, id, demurrage);
+				Ok(())
+			})
+		}
+		fn _approve(
+			owner: T::AccountId,
+			spender: T::AccountId,
+			value: T::Balance,
+			deadline: Option<BlockNumberFor<T>>,
+		) -> Result<(), Error<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				deadline.map_or(true, |deadline| deadline >= now),
+				Error::<T>::AllowanceExpired
+			);
+			Self::atomically(|| {
+				Self::_allowance_set(&owner, &spender, value)?;
+				let deadline = deadline.filter(|_| !value.is_zero());
+				Self::set_allowance_expiry(&owner, &spender, deadline);
+				Self::deposit_event(Event::Approval { from: owner, to: spender, value });
+				Ok(())
+			})
+		}
 
+		/// Moves `window_start` to the start of the `period`-long window holding the current
+		/// block. Returns whether it moved, i.e. whether a new window began.
+		fn roll_window(window_start: &mut BlockNumberFor<T>, period: BlockNumberFor<T>) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			let elapsed = now.saturating_sub(*window_start);
+			if elapsed < period {
+				return false
+			}
+			*window_start = now - elapsed % period;
+			true
+		}
+		/// Takes one fee-less transfer from `who`'s quota for the current window, if any is
+		/// left.
+		fn use_free_transfer(who: &T::AccountId) -> bool {
+			let quota = T::FreeTransfersPerPeriod::get();
+			if quota == 0 {
+				return false
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let (mut window_start, mut used) = <FreeTransfers<T>>::get(who).unwrap_or((now, 0));
+			if Self::roll_window(&mut window_start, T::FreeTransferPeriod::get()) {
+				used = 0;
+			}
+			if used >= quota {
+				return false
+			}
+			<FreeTransfers<T>>::insert(who, (window_start, used + 1));
+			true
+		}
+		/// Counts `value` against `who`'s transfer limit, if it has one.
+		fn record_outflow(who: &T::AccountId, value: T::Balance) -> Result<(), Error<T>> {
+			if <LimitedAccounts<T>>::get() == 0 {
+				return Ok(())
+			}
+			<TransferLimits<T>>::try_mutate_exists(who, |maybe_limit| {
+				let Some(limit) = maybe_limit else { return Ok(()) };
+				if Self::roll_window(&mut limit.window_start, limit.period) {
+					limit.sent_in_window = Zero::zero();
+				}
+				limit.sent_in_window = limit
+					.sent_in_window
+					.checked_add(&value)
+					.filter(|sent| *sent <= limit.max_per_period)
+					.ok_or(Error::<T>::TransferLimitExceeded)?;
+				Ok(())
+			})
+		}
+		/// Draws `value` from `spender`'s subscription to `owner`'s tokens, rolling its window
+		/// forward to the one holding the current block first. Returns false, leaving the
+		/// subscription untouched, if there is none or the window has less than `value` left.
+		fn spend_subscription(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> bool {
+			let Some(mut subscription) = <Subscriptions<T>>::get((owner, spender)) else {
+				return false
+			};
+			if Self::roll_window(&mut subscription.window_start, subscription.period) {
+				subscription.spent_in_window = Zero::zero();
+			}
+			match subscription.spent_in_window.checked_add(&value) {
+				Some(spent) if spent <= subscription.limit => {
+					subscription.spent_in_window = spent;
+					<Subscriptions<T>>::insert((owner, spender), subscription);
+					true
+				},
+				_ => false,
+			}
+		}
+		/// Moves `value` of `from`'s tokens to `to` on behalf of `spender`, who must hold a
+		/// subscription or an allowance from `from` covering it.
 		fn _transfer_from(
+			spender: T::AccountId,
 			from: T::AccountId,
 			to: T::AccountId,
-			value: u64,
+			value: T::Balance,
 		) -> Result<(), Error<T>> {
-			ensure!(<BalanceOf<T>>::contains_key(&from), Error::<T>::InsufficientFunds);
-			let from_balance = Self::_check_if_user_has_balance_or_set_zero(&from);
-			let to_balance = Self::_check_if_user_has_balance_or_set_zero(&to);
-			ensure!(from_balance >= value, Error::<T>::InsufficientFunds);
-			let from_approve = match <Allowance<T>>::get((&from, &to)) {
-				Some(approve) => approve,
-				None => Err(Error::<T>::ApprovalNotGranted)?,
+			Self::atomically(|| {
+				Self::ensure_not_paused()?;
+				Self::ensure_not_burn_address(&from)?;
+				Self::ensure_not_frozen(&spender)?;
+				Self::ensure_accepts(Some(&from), &to)?;
+				Self::before_transfer(Some(&from), Some(&to), value)?;
+				Self::consume_attestation(&from, &to, value)?;
+				Self::check_kyc(&from, value)?;
+				let demurrage =
+					Self::settle_demurrage(&from)?.saturating_add(Self::settle_demurrage(&to)?);
+				ensure!(
+					<BalanceOf<T>>::contains_key(T::NativeAsset::get(), &from),
+					Error::<T>::AccountNotFound
+				);
+				let remaining = Self::balance_after_debit(&from, value)?;
+				if !Self::spend_subscription(&from, &spender, value) {
+					Self::charge_allowance(&from, &spender, value)?;
+				}
+				Self::record_spend(&from, &spender, value);
+				Self::record_outflow(&from, value)?;
+				Self::_balance_set(&from, remaining)?;
+				Self::credit(&from, &to, value)?;
+				Self::remove_dust(&from)?;
+				T::OnTransfer::after_transfer(Some(&from), Some(&to), value);
+				Self::deposit_transfer_from_event(from, to, value, demurrage);
+				Ok(())
+			})
+		}
+	}
+
+	/// Pays out in this token from the account `Pot`, so pallets such as treasury or salary can
+	/// make payments denominated in it.
+	///
+	/// Payments are plain transfers that either happen or fail on the spot, so every payment
+	/// id reports `PaymentStatus::Success`.
+	pub struct PayFromPot<T, Pot>(PhantomData<(T, Pot)>);
+
+	impl<T: Config, Pot: Get<T::AccountId>> Pay for PayFromPot<T, Pot> {
+		type Balance = T::Balance;
+		type Beneficiary = T::AccountId;
+		type AssetKind = ();
+		type Id = ();
+		type Error = DispatchError;
+
+		fn pay(
+			who: &Self::Beneficiary,
+			_: Self::AssetKind,
+			amount: Self::Balance,
+		) -> Result<Self::Id, Self::Error> {
+			Pallet::<T>::_transfer(Pot::get(), who.clone(), amount)?;
+			Ok(())
+		}
+
+		fn check_payment(_: Self::Id) -> PaymentStatus {
+			PaymentStatus::Success
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, amount: Self::Balance) {
+			let pot = Pot::get();
+			let balance = Pallet::<T>::_balance_of(&pot).saturating_add(amount);
+			let _ = Pallet::<T>::_balance_set(&pot, balance);
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn ensure_concluded(_: Self::Id) {}
+	}
+
+	/// Accounts whose balance is at least `Threshold`, for pallets that gate membership on
+	/// holding the token.
+	///
+	/// The balance counts frozen tokens but not reserved or staked ones. Every check is a
+	/// single read of `BalanceOf`, so it can be used in call filters.
+	pub struct HoldersAbove<T, Threshold>(PhantomData<(T, Threshold)>);
+
+	impl<T, Threshold> Contains<T::AccountId> for HoldersAbove<T, Threshold>
+	where
+		T: Config,
+		Threshold: Get<T::Balance>,
+	{
+		fn contains(who: &T::AccountId) -> bool {
+			Pallet::<T>::_balance_of(who) >= Threshold::get()
+		}
+	}
+
+	impl<T: Config> SlashHandler<T::AccountId, T::Balance> for Pallet<T> {
+		fn slash_stake(who: &T::AccountId, amount: T::Balance) -> T::Balance {
+			let mut remaining = amount;
+			if let Some(mut ledger) = <Ledgers<T>>::get(who) {
+				// Settle rewards on the stake as it was before the slash.
+				if let Err(e) = Self::pay_rewards(who, &mut ledger) {
+					log::warn!(target: LOG_TARGET, "Rewards not paid before slash: {:?}", e);
+				}
+				let from_active = remaining.min(ledger.active);
+				ledger.active -= from_active;
+				remaining -= from_active;
+				<TotalStaked<T>>::mutate(|total| *total = total.saturating_sub(from_active));
+				for (_, chunk) in ledger.unlocking.iter_mut() {
+					let from_chunk = remaining.min(*chunk);
+					*chunk -= from_chunk;
+					remaining -= from_chunk;
+				}
+				ledger.unlocking.retain(|(_, chunk)| !chunk.is_zero());
+				ledger.reward_debt = Self::reward_debt(ledger.active);
+				if ledger.active.is_zero() && ledger.unlocking.is_empty() {
+					<Ledgers<T>>::remove(who);
+					let _ = frame_system::Pallet::<T>::dec_providers(who);
+				} else {
+					<Ledgers<T>>::insert(who, ledger);
+				}
+			}
+			if !remaining.is_zero() && T::SlashFreeBalance::get() {
+				let balance = Self::_balance_of(who);
+				let from_free = remaining.min(balance);
+				if Self::_balance_set(who, balance - from_free).is_ok() {
+					remaining -= from_free;
+				}
+			}
+
+			let slashed = amount - remaining;
+			if !slashed.is_zero() {
+				Self::burn_supply(who, slashed);
+				T::OnSlash::on_slash(who, slashed);
+				Self::deposit_event(Event::Slashed { who: who.clone(), amount: slashed });
+			}
+			slashed
+		}
+	}
+
+	/// Tokens created through `Currency` that are not in `TotalSupply` yet. Dropping it adds
+	/// them.
+	#[must_use]
+	#[derive(RuntimeDebugNoBound, PartialEqNoBound, EqNoBound)]
+	pub struct PositiveImbalance<T: Config>(T::Balance, PhantomData<T>);
+
+	impl<T: Config> PositiveImbalance<T> {
+		fn new(amount: T::Balance) -> Self {
+			Self(amount, PhantomData)
+		}
+	}
+
+	/// Tokens taken out of accounts through `Currency` that are still in `TotalSupply`.
+	/// Dropping it burns them.
+	#[must_use]
+	#[derive(RuntimeDebugNoBound, PartialEqNoBound, EqNoBound)]
+	pub struct NegativeImbalance<T: Config>(T::Balance, PhantomData<T>);
+
+	impl<T: Config> NegativeImbalance<T> {
+		fn new(amount: T::Balance) -> Self {
+			Self(amount, PhantomData)
+		}
+	}
+
+	impl<T: Config> Drop for PositiveImbalance<T> {
+		fn drop(&mut self) {
+			if !self.0.is_zero() {
+				Pallet::<T>::_total_supply_set(Pallet::<T>::_total_supply().saturating_add(self.0));
+			}
+		}
+	}
+
+	impl<T: Config> Drop for NegativeImbalance<T> {
+		fn drop(&mut self) {
+			if !self.0.is_zero() {
+				Pallet::<T>::_total_supply_set(Pallet::<T>::_total_supply().saturating_sub(self.0));
+				<TotalBurned<T>>::mutate(|burned| *burned = burned.saturating_add(self.0));
+			}
+		}
+	}
+
+	impl<T: Config> Default for PositiveImbalance<T> {
+		fn default() -> Self {
+			Self::zero()
+		}
+	}
+
+	impl<T: Config> Default for NegativeImbalance<T> {
+		fn default() -> Self {
+			Self::zero()
+		}
+	}
+
+	impl<T: Config> TryDrop for PositiveImbalance<T> {
+		fn try_drop(self) -> Result<(), Self> {
+			self.drop_zero()
+		}
+	}
+
+	impl<T: Config> TryDrop for NegativeImbalance<T> {
+		fn try_drop(self) -> Result<(), Self> {
+			self.drop_zero()
+		}
+	}
+
+	impl<T: Config> TryMerge for PositiveImbalance<T> {
+		fn try_merge(self, other: Self) -> Result<Self, (Self, Self)> {
+			Ok(self.merge(other))
+		}
+	}
+
+	impl<T: Config> TryMerge for NegativeImbalance<T> {
+		fn try_merge(self, other: Self) -> Result<Self, (Self, Self)> {
+			Ok(self.merge(other))
+		}
+	}
+
+	impl<T: Config> Imbalance<T::Balance> for PositiveImbalance<T> {
+		type Opposite = NegativeImbalance<T>;
+
+		fn zero() -> Self {
+			Self::new(Zero::zero())
+		}
+		fn drop_zero(self) -> Result<(), Self> {
+			if self.0.is_zero() {
+				Ok(())
+			} else {
+				Err(self)
+			}
+		}
+		fn split(self, amount: T::Balance) -> (Self, Self) {
+			let first = self.0.min(amount);
+			let second = self.0 - first;
+			mem::forget(self);
+			(Self::new(first), Self::new(second))
+		}
+		fn extract(&mut self, amount: T::Balance) -> Self {
+			let extracted = self.0.min(amount);
+			self.0 -= extracted;
+			Self::new(extracted)
+		}
+		fn merge(mut self, other: Self) -> Self {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+			self
+		}
+		fn subsume(&mut self, other: Self) {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+		}
+		fn offset(self, other: Self::Opposite) -> SameOrOther<Self, Self::Opposite> {
+			let (a, b) = (self.0, other.0);
+			mem::forget((self, other));
+			if a > b {
+				SameOrOther::Same(Self::new(a - b))
+			} else if b > a {
+				SameOrOther::Other(NegativeImbalance::new(b - a))
+			} else {
+				SameOrOther::None
+			}
+		}
+		fn peek(&self) -> T::Balance {
+			self.0
+		}
+	}
+
+	impl<T: Config> Imbalance<T::Balance> for NegativeImbalance<T> {
+		type Opposite = PositiveImbalance<T>;
+
+		fn zero() -> Self {
+			Self::new(Zero::zero())
+		}
+		fn drop_zero(self) -> Result<(), Self> {
+			if self.0.is_zero() {
+				Ok(())
+			} else {
+				Err(self)
+			}
+		}
+		fn split(self, amount: T::Balance) -> (Self, Self) {
+			let first = self.0.min(amount);
+			let second = self.0 - first;
+			mem::forget(self);
+			(Self::new(first), Self::new(second))
+		}
+		fn extract(&mut self, amount: T::Balance) -> Self {
+			let extracted = self.0.min(amount);
+			self.0 -= extracted;
+			Self::new(extracted)
+		}
+		fn merge(mut self, other: Self) -> Self {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+			self
+		}
+		fn subsume(&mut self, other: Self) {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+		}
+		fn offset(self, other: Self::Opposite) -> SameOrOther<Self, Self::Opposite> {
+			let (a, b) = (self.0, other.0);
+			mem::forget((self, other));
+			if a > b {
+				SameOrOther::Same(Self::new(a - b))
+			} else if b > a {
+				SameOrOther::Other(PositiveImbalance::new(b - a))
+			} else {
+				SameOrOther::None
+			}
+		}
+		fn peek(&self) -> T::Balance {
+			self.0
+		}
+	}
+
+	/// The token as a `Currency`, so that pallets written against one can hold and move it.
+	/// The token has no existential deposit and knows no withdraw reasons; only frozen
+	/// amounts limit withdrawals.
+	impl<T: Config> Currency<T::AccountId> for Pallet<T> {
+		type Balance = T::Balance;
+		type PositiveImbalance = PositiveImbalance<T>;
+		type NegativeImbalance = NegativeImbalance<T>;
+
+		fn total_balance(who: &T::AccountId) -> T::Balance {
+			Self::_balance_of(who).saturating_add(Self::reserved_of(who))
+		}
+		fn can_slash(who: &T::AccountId, value: T::Balance) -> bool {
+			Self::_balance_of(who) >= value
+		}
+		fn total_issuance() -> T::Balance {
+			Self::_total_supply()
+		}
+		fn minimum_balance() -> T::Balance {
+			Zero::zero()
+		}
+		fn burn(amount: T::Balance) -> Self::PositiveImbalance {
+			let supply = Self::_total_supply();
+			let amount = amount.min(supply);
+			Self::_total_supply_set(supply - amount);
+			PositiveImbalance::new(amount)
+		}
+		fn issue(amount: T::Balance) -> Self::NegativeImbalance {
+			let amount = amount.min(Self::mintable());
+			Self::_total_supply_set(Self::_total_supply().saturating_add(amount));
+			NegativeImbalance::new(amount)
+		}
+		fn free_balance(who: &T::AccountId) -> T::Balance {
+			Self::_balance_of(who)
+		}
+		fn ensure_can_withdraw(
+			who: &T::AccountId,
+			_: T::Balance,
+			_: WithdrawReasons,
+			new_balance: T::Balance,
+		) -> DispatchResult {
+			ensure!(new_balance >= Self::locked_of(who), Error::<T>::Frozen);
+			Ok(())
+		}
+		fn transfer(
+			source: &T::AccountId,
+			dest: &T::AccountId,
+			value: T::Balance,
+			_: ExistenceRequirement,
+		) -> DispatchResult {
+			Self::_transfer(source.clone(), dest.clone(), value)?;
+			Ok(())
+		}
+		/// Slashes the balance first, then the reserve. Frozen tokens can be slashed.
+		fn slash(who: &T::AccountId, value: T::Balance) -> (Self::NegativeImbalance, T::Balance) {
+			let balance = Self::_balance_of(who);
+			let from_free = value.min(balance);
+			let from_free = match Self::_balance_set(who, balance - from_free) {
+				Ok(()) => from_free,
+				Err(_) => Zero::zero(),
 			};
-			ensure!(from_approve >= value, Error::<T>::ApprovalNotGranted);
-			Self::_balance_set(&from, from_balance - value);
-			Self::_balance_set(&to, to_balance + value);
-			<Allowance<T>>::insert((&from, &to), from_approve - value);
-			Self::deposit_event(Event::TransferFrom { from, to, value });
+			let slashed = from_free + Self::take_reserved(who, value - from_free);
+			Self::report_slash(who, slashed);
+			(NegativeImbalance::new(slashed), value - slashed)
+		}
+		fn deposit_into_existing(
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> Result<Self::PositiveImbalance, DispatchError> {
+			ensure!(
+				<BalanceOf<T>>::contains_key(T::NativeAsset::get(), who) ||
+					<ReservedBalance<T>>::contains_key(who),
+				Error::<T>::AccountNotFound
+			);
+			Self::deposit(who, value)?;
+			Ok(PositiveImbalance::new(value))
+		}
+		fn deposit_creating(who: &T::AccountId, value: T::Balance) -> Self::PositiveImbalance {
+			match Self::deposit(who, value) {
+				Ok(()) => PositiveImbalance::new(value),
+				Err(_) => PositiveImbalance::zero(),
+			}
+		}
+		fn withdraw(
+			who: &T::AccountId,
+			value: T::Balance,
+			_: WithdrawReasons,
+			_: ExistenceRequirement,
+		) -> Result<Self::NegativeImbalance, DispatchError> {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				if value.is_zero() {
+					return Ok(NegativeImbalance::zero())
+				}
+				Self::settle_demurrage(who)?;
+				let remaining = Self::balance_after_debit(who, value)?;
+				Self::_balance_set(who, remaining)?;
+				Self::index_movement(Some(who), None, value);
+				Ok(NegativeImbalance::new(value))
+			})
+		}
+		fn make_free_balance_be(
+			who: &T::AccountId,
+			balance: T::Balance,
+		) -> SignedImbalance<T::Balance, Self::PositiveImbalance> {
+			let old = Self::_balance_of(who);
+			if Self::_balance_set(who, balance).is_err() {
+				return SignedImbalance::Positive(PositiveImbalance::zero())
+			}
+			if balance >= old {
+				SignedImbalance::Positive(PositiveImbalance::new(balance - old))
+			} else {
+				SignedImbalance::Negative(NegativeImbalance::new(old - balance))
+			}
+		}
+	}
+
+	/// Reserved tokens sit in `ReservedBalance`, out of reach of every transfer, until they
+	/// are unreserved, repatriated or slashed.
+	impl<T: Config> ReservableCurrency<T::AccountId> for Pallet<T> {
+		fn can_reserve(who: &T::AccountId, value: T::Balance) -> bool {
+			Self::balance_after_debit(who, value).is_ok()
+		}
+		fn slash_reserved(
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> (Self::NegativeImbalance, T::Balance) {
+			let slashed = Self::take_reserved(who, value);
+			Self::report_slash(who, slashed);
+			(NegativeImbalance::new(slashed), value - slashed)
+		}
+		fn reserved_balance(who: &T::AccountId) -> T::Balance {
+			Self::reserved_of(who)
+		}
+		fn reserve(who: &T::AccountId, value: T::Balance) -> DispatchResult {
+			Self::do_reserve(who, value)?;
 			Ok(())
 		}
+		fn unreserve(who: &T::AccountId, value: T::Balance) -> T::Balance {
+			match Self::do_unreserve(who, value) {
+				Ok(amount) => value - amount,
+				Err(e) => {
+					log::warn!(target: LOG_TARGET, "Reserve not released: {:?}", e);
+					value
+				},
+			}
+		}
+		fn repatriate_reserved(
+			slashed: &T::AccountId,
+			beneficiary: &T::AccountId,
+			value: T::Balance,
+			status: BalanceStatus,
+		) -> Result<T::Balance, DispatchError> {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				if slashed == beneficiary {
+					return match status {
+						BalanceStatus::Free => Ok(Self::unreserve(slashed, value)),
+						BalanceStatus::Reserved =>
+							Ok(value.saturating_sub(Self::reserved_of(slashed))),
+					}
+				}
+				let reserved = Self::reserved_of(slashed);
+				let amount = value.min(reserved);
+				match status {
+					BalanceStatus::Free => {
+						Self::ensure_accepts(Some(slashed), beneficiary)?;
+						Self::settle_demurrage(beneficiary)?;
+						let balance = Self::_balance_of(beneficiary)
+							.checked_add(&amount)
+							.ok_or(Error::<T>::Overflow)?;
+						Self::ensure_can_endow(beneficiary, amount)?;
+						Self::_balance_set(beneficiary, balance)?;
+					},
+					BalanceStatus::Reserved => {
+						let reserved = Self::reserved_of(beneficiary)
+							.checked_add(&amount)
+							.ok_or(Error::<T>::Overflow)?;
+						Self::reserved_set(beneficiary, reserved)?;
+					},
+				}
+				Self::reserved_set(slashed, reserved - amount)?;
+				Self::index_movement(Some(slashed), Some(beneficiary), amount);
+				Self::deposit_event(Event::ReserveRepatriated {
+					from: slashed.clone(),
+					to: beneficiary.clone(),
+					amount,
+					destination_status: status,
+				});
+				Ok(value - amount)
+			})
+		}
+	}
+
+	/// Named reserves are tracked per identifier in `NamedReserves`, on top of the plain
+	/// reserve that holds their tokens.
+	impl<T: Config> NamedReservableCurrency<T::AccountId> for Pallet<T> {
+		type ReserveIdentifier = T::ReserveIdentifier;
+
+		fn reserved_balance_named(id: &Self::ReserveIdentifier, who: &T::AccountId) -> T::Balance {
+			let reserves = <NamedReserves<T>>::get(who).unwrap_or_default();
+			reserves
+				.binary_search_by_key(id, |(reserve, _)| *reserve)
+				.map(|index| reserves[index].1)
+				.unwrap_or_default()
+		}
+		fn reserve_named(
+			id: &Self::ReserveIdentifier,
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> DispatchResult {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				Self::mutate_named(id, who, |amount| {
+					amount.checked_add(&value).ok_or(Error::<T>::Overflow)
+				})?;
+				Self::do_reserve(who, value)?;
+				Ok(())
+			})
+		}
+		fn unreserve_named(
+			id: &Self::ReserveIdentifier,
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> T::Balance {
+			let value_named = value.min(Self::reserved_balance_named(id, who));
+			let amount = value_named - Self::unreserve(who, value_named);
+			// Cannot fail: the reserve only shrinks.
+			let _ = Self::mutate_named(id, who, |named| Ok(named.saturating_sub(amount)));
+			value - amount
+		}
+		fn slash_reserved_named(
+			id: &Self::ReserveIdentifier,
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> (Self::NegativeImbalance, T::Balance) {
+			let value_named = value.min(Self::reserved_balance_named(id, who));
+			let (imbalance, _) = Self::slash_reserved(who, value_named);
+			let amount = imbalance.peek();
+			let _ = Self::mutate_named(id, who, |named| Ok(named.saturating_sub(amount)));
+			(imbalance, value - amount)
+		}
+		/// With `BalanceStatus::Reserved`, the tokens go into `beneficiary`'s reserve of the
+		/// same name.
+		fn repatriate_reserved_named(
+			id: &Self::ReserveIdentifier,
+			slashed: &T::AccountId,
+			beneficiary: &T::AccountId,
+			value: T::Balance,
+			status: BalanceStatus,
+		) -> Result<T::Balance, DispatchError> {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				let value_named = value.min(Self::reserved_balance_named(id, slashed));
+				let amount = value_named -
+					Self::repatriate_reserved(slashed, beneficiary, value_named, status)?;
+				Self::mutate_named(id, slashed, |named| Ok(named.saturating_sub(amount)))?;
+				if status == BalanceStatus::Reserved {
+					Self::mutate_named(id, beneficiary, |named| {
+						named.checked_add(&amount).ok_or(Error::<T>::Overflow)
+					})?;
+				}
+				Ok(value - amount)
+			})
+		}
+	}
+
+	/// The token as a `fungible`, for pallets written against the newer token traits. As with
+	/// `Currency`, there is no existential deposit, so `Preservation` changes nothing, and
+	/// frozen amounts and locked savings are what limits withdrawals.
+	impl<T: Config> fungible::Inspect<T::AccountId> for Pallet<T> {
+		type Balance = T::Balance;
+
+		fn total_issuance() -> T::Balance {
+			Self::_total_supply()
+		}
+		fn minimum_balance() -> T::Balance {
+			Zero::zero()
+		}
+		fn total_balance(who: &T::AccountId) -> T::Balance {
+			Self::_balance_of(who).saturating_add(Self::reserved_of(who))
+		}
+		fn balance(who: &T::AccountId) -> T::Balance {
+			Self::_balance_of(who)
+		}
+		/// `Fortitude::Force` reaches frozen and locked tokens too.
+		fn reducible_balance(who: &T::AccountId, _: Preservation, force: Fortitude) -> T::Balance {
+			let balance = Self::_balance_of(who);
+			match force {
+				Fortitude::Polite => balance.saturating_sub(Self::locked_of(who)),
+				Fortitude::Force => balance,
+			}
+		}
+		fn can_deposit(
+			who: &T::AccountId,
+			amount: T::Balance,
+			provenance: Provenance,
+		) -> DepositConsequence {
+			if amount.is_zero() {
+				return DepositConsequence::Success
+			}
+			if let Provenance::Minted = provenance {
+				if amount > Self::mintable() {
+					return DepositConsequence::Overflow
+				}
+				if Self::ensure_accepts(None, who).is_err() {
+					return DepositConsequence::Blocked
+				}
+			}
+			if Self::_balance_of(who).checked_add(&amount).is_none() {
+				return DepositConsequence::Overflow
+			}
+			if Self::ensure_can_endow(who, amount).is_err() {
+				return DepositConsequence::CannotCreate
+			}
+			DepositConsequence::Success
+		}
+		fn can_withdraw(who: &T::AccountId, amount: T::Balance) -> WithdrawConsequence<T::Balance> {
+			let balance = Self::_balance_of(who);
+			if balance < amount {
+				return WithdrawConsequence::BalanceLow
+			}
+			if balance - amount < Self::locked_of(who) {
+				return WithdrawConsequence::Frozen
+			}
+			WithdrawConsequence::Success
+		}
+	}
+
+	/// Raw writes for the `fungible` defaults. They keep `HolderCount` and the provider
+	/// references exact but skip every check and event of the token's own paths.
+	impl<T: Config> fungible::Unbalanced<T::AccountId> for Pallet<T> {
+		/// Without an existential deposit there is never any dust.
+		fn handle_dust(_: fungible::Dust<T::AccountId, Self>) {}
+		fn write_balance(
+			who: &T::AccountId,
+			amount: T::Balance,
+		) -> Result<Option<T::Balance>, DispatchError> {
+			Self::_balance_set(who, amount)?;
+			Ok(None)
+		}
+		fn set_total_issuance(amount: T::Balance) {
+			Self::_total_supply_set(amount);
+		}
+	}
+
+	/// Mints, burns and transfers take the same paths as their `Currency` counterparts, so
+	/// strict receivers, holder caps, freezes and transfer limits all apply.
+	impl<T: Config> fungible::Mutate<T::AccountId> for Pallet<T> {
+		fn mint_into(who: &T::AccountId, amount: T::Balance) -> Result<T::Balance, DispatchError> {
+			Self::_mint(who, amount)?;
+			Ok(amount)
+		}
+		/// Burned tokens count towards `TotalBurned`.
+		fn burn_from(
+			who: &T::AccountId,
+			amount: T::Balance,
+			precision: Precision,
+			force: Fortitude,
+		) -> Result<T::Balance, DispatchError> {
+			let burned = Self::atomically(|| {
+				Self::settle_demurrage(who)?;
+				let reducible = <Self as fungible::Inspect<_>>::reducible_balance(
+					who,
+					Preservation::Expendable,
+					force,
+				);
+				let amount = match precision {
+					Precision::BestEffort => amount.min(reducible),
+					Precision::Exact => {
+						ensure!(amount <= reducible, Error::<T>::FundsUnavailable);
+						amount
+					},
+				};
+				Self::_balance_set(who, Self::_balance_of(who) - amount)?;
+				Self::burn_supply(who, amount);
+				Ok(amount)
+			})?;
+			Ok(burned)
+		}
+		fn transfer(
+			source: &T::AccountId,
+			dest: &T::AccountId,
+			amount: T::Balance,
+			_: Preservation,
+		) -> Result<T::Balance, DispatchError> {
+			Self::_transfer(source.clone(), dest.clone(), amount)?;
+			Ok(amount)
+		}
+	}
+
+	/// Holds are named reserves, with the reserve identifier as the hold reason.
+	impl<T: Config> fungible::hold::Inspect<T::AccountId> for Pallet<T> {
+		type Reason = T::ReserveIdentifier;
+
+		fn total_balance_on_hold(who: &T::AccountId) -> T::Balance {
+			Self::reserved_of(who)
+		}
+		fn balance_on_hold(reason: &T::ReserveIdentifier, who: &T::AccountId) -> T::Balance {
+			Self::reserved_balance_named(reason, who)
+		}
+	}
+
+	impl<T: Config> fungible::hold::Unbalanced<T::AccountId> for Pallet<T> {
+		/// Moves `ReservedBalance` along with the named reserve, leaving the balance alone.
+		fn set_balance_on_hold(
+			reason: &T::ReserveIdentifier,
+			who: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				let held = Self::reserved_balance_named(reason, who);
+				let reserved = if amount >= held {
+					Self::reserved_of(who)
+						.checked_add(&(amount - held))
+						.ok_or(Error::<T>::Overflow)?
+				} else {
+					Self::reserved_of(who).saturating_sub(held - amount)
+				};
+				Self::mutate_named(reason, who, |_| Ok(amount))?;
+				Self::reserved_set(who, reserved)?;
+				Ok(())
+			})
+		}
+	}
+
+	/// Holding and releasing go through `NamedReservableCurrency`, so frozen tokens cannot be
+	/// held and both emit the usual reserve events.
+	impl<T: Config> fungible::hold::Mutate<T::AccountId> for Pallet<T> {
+		fn hold(
+			reason: &T::ReserveIdentifier,
+			who: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			Self::reserve_named(reason, who, amount)
+		}
+		fn release(
+			reason: &T::ReserveIdentifier,
+			who: &T::AccountId,
+			amount: T::Balance,
+			precision: Precision,
+		) -> Result<T::Balance, DispatchError> {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				let released = amount - Self::unreserve_named(reason, who, amount);
+				ensure!(
+					released == amount || matches!(precision, Precision::BestEffort),
+					Error::<T>::FundsUnavailable
+				);
+				Ok(released)
+			})
+		}
+		/// Burned tokens count towards `TotalBurned`.
+		fn burn_held(
+			reason: &T::ReserveIdentifier,
+			who: &T::AccountId,
+			amount: T::Balance,
+			precision: Precision,
+			_: Fortitude,
+		) -> Result<T::Balance, DispatchError> {
+			with_storage_layer(|| -> Result<_, DispatchError> {
+				let amount = <Self as fungible::hold::Unbalanced<_>>::decrease_balance_on_hold(
+					reason, who, amount, precision,
+				)?;
+				Self::burn_supply(who, amount);
+				Ok(amount)
+			})
+		}
 	}
 }
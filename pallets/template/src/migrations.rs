@@ -0,0 +1,655 @@
+//! Storage migrations for pallet-template.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_runtime::{
+	traits::{CheckedMul, One, Saturating, Zero},
+	SaturatedConversion,
+};
+use sp_std::{cmp::Reverse, vec::Vec};
+
+/// The layouts before v5, when the pallet held a single token and nothing was keyed by asset
+/// id. Only the migrations up to [`v5::RekeyByAsset`] use them.
+pub(crate) mod pre_v5 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub(crate) type BalanceOf<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+	>;
+
+	#[frame_support::storage_alias]
+	pub(crate) type Allowances<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+	>;
+
+	#[frame_support::storage_alias]
+	pub(crate) type TotalSupply<T: Config> = StorageValue<Pallet<T>, <T as Config>::Balance>;
+
+	#[frame_support::storage_alias]
+	pub(crate) type Metadata<T: Config> = StorageValue<Pallet<T>, TokenMetadata<T>, ValueQuery>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Drops zero-balance `BalanceOf` entries, which older versions left behind for every
+	/// account that was ever touched, and initializes `HolderCount` from what remains.
+	pub struct InitHolderCount<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for InitHolderCount<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut entries = 0u64;
+			let mut holders = 0u32;
+			pre_v5::BalanceOf::<T>::translate::<T::Balance, _>(|_, balance| {
+				entries += 1;
+				if balance.is_zero() {
+					None
+				} else {
+					holders += 1;
+					Some(balance)
+				}
+			});
+			HolderCount::<T>::put(holders);
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(entries + 1, entries + 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let holders = pre_v5::BalanceOf::<T>::iter_values()
+				.filter(|balance| !balance.is_zero())
+				.count() as u32;
+			Ok(holders.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let holders = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade holder count")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "Storage version must be 1");
+			ensure!(HolderCount::<T>::get() == holders, "HolderCount does not match BalanceOf");
+			ensure!(
+				pre_v5::BalanceOf::<T>::iter_values().all(|balance| !balance.is_zero()),
+				"Zero balances must be removed"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Gives every existing holder the `frame_system` provider reference that newly endowed
+	/// accounts now get, so reaping them later releases a reference they actually hold.
+	pub struct AddProviders<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for AddProviders<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut holders = 0u64;
+			for who in pre_v5::BalanceOf::<T>::iter_keys() {
+				frame_system::Pallet::<T>::inc_providers(&who);
+				holders += 1;
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(2 * holders + 1, holders + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let providers: Vec<(T::AccountId, u32)> = pre_v5::BalanceOf::<T>::iter_keys()
+				.map(|who| {
+					let providers = frame_system::Pallet::<T>::providers(&who);
+					(who, providers)
+				})
+				.collect();
+			Ok(providers.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let providers = Vec::<(T::AccountId, u32)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade provider counts")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "Storage version must be 2");
+			for (who, before) in providers {
+				ensure!(
+					frame_system::Pallet::<T>::providers(&who) == before + 1,
+					"Every holder must gain exactly one provider reference"
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Starts moving `Allowance`, keyed by the (owner, spender) pair, into `Allowances`. A
+	/// single block cannot re-key every entry, so this only sets the `AllowanceRekey` flag;
+	/// `on_initialize` then moves `AllowanceMigrationBatch` entries per block and finishes once
+	/// the legacy map is empty. Reads check the new layout first and fall back to the legacy
+	/// one until then.
+	///
+	/// v4 and v5 run in the same upgrade, so entries move straight into the asset-keyed layout
+	/// and the chain is at version 5 while the re-keying runs.
+	///
+	/// `try_state` checks both layouts against the `Approvals` index on every block while the
+	/// re-keying runs, and that no legacy entry is left once it finishes.
+	pub struct RekeyAllowances<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for RekeyAllowances<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 2 || AllowanceRekey::<T>::exists() {
+				return T::DbWeight::get().reads(2)
+			}
+			AllowanceRekey::<T>::put(0);
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let entries = Allowance::<T>::iter_keys().count() as u64 +
+				pre_v5::Allowances::<T>::iter_keys().count() as u64;
+			Ok(entries.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let entries = u64::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade allowance count")?;
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3 || AllowanceRekey::<T>::exists(),
+				"The re-keying must be finished or running"
+			);
+			ensure!(
+				Allowance::<T>::iter_keys().count() as u64 +
+					pre_v5::Allowances::<T>::iter_keys().count() as u64 ==
+					entries,
+				"Allowances were lost or duplicated"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// Builds the `TopHolders` index from every balance, and sets `TopHoldersFloor` to the
+	/// largest balance left out of it. The index only depends on balances, so it is also built
+	/// on a chain at v2 whose v3 re-keying of allowances still runs.
+	pub struct InitTopHolders<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for InitTopHolders<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let version = Pallet::<T>::on_chain_storage_version();
+			if version != 3 && (version != 2 || !AllowanceRekey::<T>::exists()) {
+				return T::DbWeight::get().reads(2)
+			}
+
+			let mut holders: Vec<_> = pre_v5::BalanceOf::<T>::iter()
+				.map(|(who, balance)| (Pallet::<T>::holder_rank(balance, &who), balance))
+				.collect();
+			let entries = holders.len() as u64;
+			// Largest first: the reverse of the index's storage order, which compares encodings.
+			holders.sort_unstable_by_key(|(rank, _)| Reverse(rank.encode()));
+			let tracked = T::TopHoldersTracked::get() as usize;
+			let floor = holders.get(tracked).map_or(Zero::zero(), |(_, balance)| *balance);
+			holders.truncate(tracked);
+			let kept = holders.len() as u64;
+			for (rank, balance) in holders {
+				TopHolders::<T>::insert(rank, balance);
+			}
+			TopHoldersCount::<T>::put(kept as u32);
+			TopHoldersFloor::<T>::put(floor);
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(entries + 1, kept + 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 4, "Storage version must be 4");
+			let floor = TopHoldersFloor::<T>::get();
+			ensure!(
+				TopHolders::<T>::iter().all(|((_, who), balance)| {
+					balance >= floor && pre_v5::BalanceOf::<T>::get(&who) == Some(balance)
+				}),
+				"TopHolders out of date"
+			);
+			ensure!(
+				TopHolders::<T>::iter_keys().count() as u32 == TopHoldersCount::<T>::get(),
+				"TopHoldersCount out of date"
+			);
+			ensure!(
+				pre_v5::BalanceOf::<T>::iter().all(|(who, balance)| {
+					balance <= floor ||
+						TopHolders::<T>::contains_key(Pallet::<T>::holder_rank(balance, &who))
+				}),
+				"Holder missing from TopHolders above the floor"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// Moves the token's balances, allowances, supply and metadata under `NativeAsset` in the
+	/// maps now keyed by asset id, next to the tokens `create_asset` launches.
+	///
+	/// Runs at storage version 4, which the earlier migrations of the runtime's `Migrations`
+	/// reach in the same upgrade from any older version. A v3 re-keying still running then
+	/// goes on in later blocks, moving the remaining legacy allowances straight under
+	/// `NativeAsset`.
+	pub struct RekeyByAsset<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for RekeyByAsset<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 4 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let native = T::NativeAsset::get();
+			// Each old layout shares its prefix with the new one, so it is emptied first.
+			let balances: Vec<_> = pre_v5::BalanceOf::<T>::drain().collect();
+			let allowances: Vec<_> = pre_v5::Allowances::<T>::drain().collect();
+			let entries = (balances.len() + allowances.len()) as u64;
+			for (who, balance) in balances {
+				BalanceOf::<T>::insert(native, who, balance);
+			}
+			for (owner, spender, allowance) in allowances {
+				Allowances::<T>::insert((native, owner, spender), allowance);
+			}
+			if let Some(supply) = pre_v5::TotalSupply::<T>::take() {
+				TotalSupply::<T>::insert(native, supply);
+			}
+			Metadata::<T>::insert(native, pre_v5::Metadata::<T>::take());
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(entries + 4, 2 * entries + 5)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let balances = pre_v5::BalanceOf::<T>::iter_keys().count() as u64;
+			let allowances = pre_v5::Allowances::<T>::iter_keys().count() as u64;
+			let supply = pre_v5::TotalSupply::<T>::get();
+			let metadata = pre_v5::Metadata::<T>::get();
+			Ok((balances, allowances, supply, metadata).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (balances, allowances, supply, metadata) =
+				<(u64, u64, Option<T::Balance>, TokenMetadata<T>)>::decode(&mut &state[..])
+					.map_err(|_| "Failed to decode the pre-upgrade native token")?;
+			let native = T::NativeAsset::get();
+			ensure!(Pallet::<T>::on_chain_storage_version() == 5, "Storage version must be 5");
+			ensure!(
+				BalanceOf::<T>::iter_prefix(native).count() as u64 == balances,
+				"Balances were lost or duplicated"
+			);
+			ensure!(
+				Allowances::<T>::iter_prefix((native,)).count() as u64 == allowances,
+				"Allowances were lost or duplicated"
+			);
+			ensure!(TotalSupply::<T>::get(native) == supply, "TotalSupply must be kept");
+			ensure!(Metadata::<T>::get(native) == metadata, "Metadata must be kept");
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
+pub mod redenomination {
+	use super::*;
+
+	/// Multiplies every amount of the native token in the pallet's storage by `Factor`, e.g. by
+	/// `10^6` to move from 6 to 12 decimals, and raises its metadata's `decimals` to match.
+	/// Tokens launched with `create_asset` keep their amounts and decimals.
+	///
+	/// `Factor` must be a power of ten. Nothing is written unless it is and every scaled amount
+	/// fits: a single overflow leaves storage untouched and is logged instead. Amounts fixed in the
+	/// runtime configuration, such as `MintAnnouncementThreshold`, `MaxMintPerBlock`, the KYC
+	/// limits and the default `BlockReward`, are not in storage and must be scaled in the same
+	/// upgrade.
+	///
+	/// `ClaimsLeft` is scaled, but the leaves under `ClaimsRoot` still grant amounts in the old
+	/// units. Re-issue the snapshot in the new units with `set_claims_root` right after.
+	///
+	/// Not versioned: include it in exactly one runtime upgrade, then drop it.
+	pub struct Redenominate<T, Factor>(PhantomData<(T, Factor)>);
+
+	impl<T: Config, Factor: Get<T::Balance>> Redenominate<T, Factor> {
+		/// How many decimals `factor` adds, or `None` if it is not a power of ten.
+		fn added_decimals(mut factor: T::Balance) -> Option<u8> {
+			let ten = T::Balance::from(10u32);
+			let mut decimals = 0u8;
+			while !factor.is_zero() && (factor % ten).is_zero() {
+				factor /= ten;
+				decimals += 1;
+			}
+			(factor == One::one()).then_some(decimals)
+		}
+
+		/// Whether every amount still fits once scaled, and how many entries were read.
+		fn all_fit(factor: T::Balance) -> (bool, u64) {
+			let native = T::NativeAsset::get();
+			let fits = |value: T::Balance| value.checked_mul(&factor).is_some();
+			let fits_wide =
+				|value: u128| value.checked_mul(factor.saturated_into::<u128>()).is_some();
+			let values_fit = TotalSupply::<T>::get(native).map_or(true, fits) &&
+				MaxSupply::<T>::get().map_or(true, fits) &&
+				fits(TotalBurned::<T>::get()) &&
+				fits(ClaimsLeft::<T>::get()) &&
+				fits(TotalStaked::<T>::get()) &&
+				fits(RewardsAccounted::<T>::get()) &&
+				fits(RewardPerBlock::<T>::get()) &&
+				PendingParameters::<T>::get().map_or(true, |c| fits(c.parameters.block_reward)) &&
+				fits_wide(TransferVolume::<T>::get()) &&
+				AnnouncedMints::<T>::get().iter().all(|mint| fits(mint.amount)) &&
+				LatestReserveAttestation::<T>::get().map_or(true, |a| fits(a.reserves)) &&
+				ReserveAttestationHistory::<T>::get().iter().all(|a| fits(a.reserves));
+
+			let mut reads = 12u64;
+			let mut entries_fit = |entries: &mut dyn Iterator<Item = bool>| {
+				entries.all(|fit| {
+					reads += 1;
+					fit
+				})
+			};
+			let fit = values_fit &&
+				entries_fit(&mut BalanceOf::<T>::iter_prefix_values(native).map(fits)) &&
+				entries_fit(&mut ReservedBalance::<T>::iter_values().map(fits)) &&
+				entries_fit(
+					&mut NamedReserves::<T>::iter_values()
+						.map(|reserves| reserves.iter().all(|(_, amount)| fits(*amount))),
+				) &&
+				entries_fit(&mut Allowances::<T>::iter_prefix_values((native,)).map(fits)) &&
+				entries_fit(&mut Allowance::<T>::iter_values().map(fits)) &&
+				entries_fit(&mut ScopedAllowances::<T>::iter_values().map(|(v, _)| fits(v))) &&
+				entries_fit(&mut SpentBy::<T>::iter_values().map(fits)) &&
+				entries_fit(
+					&mut Attestations::<T>::iter_keys().map(|(_, _, value)| fits(value)),
+				) &&
+				entries_fit(&mut KycOutflow::<T>::iter_values().map(|(_, sent)| fits(sent))) &&
+				entries_fit(
+					&mut VestingSchedules::<T>::iter_values()
+						.map(|schedules| schedules.iter().all(|schedule| fits(schedule.locked))),
+				) &&
+				entries_fit(
+					&mut SavingTranches::<T>::iter_values()
+						.map(|tranches| tranches.iter().all(|(_, value)| fits(*value))),
+				) &&
+				entries_fit(&mut Subscriptions::<T>::iter_values().map(|s| fits(s.limit))) &&
+				entries_fit(
+					&mut TransferLimits::<T>::iter_values().map(|l| fits(l.max_per_period)),
+				) &&
+				entries_fit(&mut Ledgers::<T>::iter_values().map(|ledger| {
+					fits(ledger.active) &&
+						fits_wide(ledger.reward_debt) &&
+						ledger.unlocking.iter().all(|(_, amount)| fits(*amount))
+				})) &&
+				entries_fit(&mut RecentVolume::<T>::iter_values().map(|(_, v)| fits_wide(v))) &&
+				entries_fit(&mut SupplyHistory::<T>::iter_values().map(fits)) &&
+				entries_fit(
+					&mut PaymentRequests::<T>::iter_values()
+						.map(|requests| requests.iter().all(|request| fits(request.amount))),
+				) &&
+				entries_fit(&mut InboundVotes::<T>::iter_values().map(|p| fits(p.amount)));
+			(fit, reads)
+		}
+	}
+
+	impl<T: Config, Factor: Get<T::Balance>> OnRuntimeUpgrade for Redenominate<T, Factor> {
+		fn on_runtime_upgrade() -> Weight {
+			let factor = Factor::get();
+			let native = T::NativeAsset::get();
+			let Some(decimals) = Self::added_decimals(factor)
+				.and_then(|added| Metadata::<T>::get(native).decimals.checked_add(added))
+			else {
+				log::error!(
+					target: LOG_TARGET,
+					"Redenomination factor {:?} is not a power of ten; storage left untouched",
+					factor
+				);
+				return T::DbWeight::get().reads(1)
+			};
+			let (fits, reads) = Self::all_fit(factor);
+			if !fits {
+				log::error!(
+					target: LOG_TARGET,
+					"Redenomination by {:?} would overflow; storage left untouched",
+					factor
+				);
+				return T::DbWeight::get().reads(reads + 1)
+			}
+			// Every amount was checked above, so none of the multiplications below overflow.
+			let scale = |value: &mut T::Balance| *value *= factor;
+			let scale_wide = |value: &mut u128| *value *= factor.saturated_into::<u128>();
+
+			TotalSupply::<T>::mutate_exists(native, |supply| {
+				if let Some(supply) = supply {
+					scale(supply)
+				}
+			});
+			MaxSupply::<T>::mutate_exists(|cap| {
+				if let Some(cap) = cap {
+					scale(cap)
+				}
+			});
+			TotalBurned::<T>::mutate(scale);
+			ClaimsLeft::<T>::mutate(scale);
+			TotalStaked::<T>::mutate(scale);
+			RewardsAccounted::<T>::mutate(scale);
+			RewardPerBlock::<T>::mutate(scale);
+			PendingParameters::<T>::mutate(|change| {
+				if let Some(change) = change {
+					scale(&mut change.parameters.block_reward)
+				}
+			});
+			TransferVolume::<T>::mutate(scale_wide);
+			let balances: Vec<_> = BalanceOf::<T>::iter_prefix(native).collect();
+			for (who, balance) in balances {
+				BalanceOf::<T>::insert(native, who, balance * factor);
+			}
+			// Index entries are keyed by balance, so they move to the scaled key.
+			let top: Vec<_> = TopHolders::<T>::drain().collect();
+			for ((_, who), balance) in top {
+				let balance = balance * factor;
+				TopHolders::<T>::insert(Pallet::<T>::holder_rank(balance, &who), balance);
+			}
+			// The floor may be above every balance left, so it is not covered by the check.
+			TopHoldersFloor::<T>::mutate(|floor| *floor = floor.saturating_mul(factor));
+			ReservedBalance::<T>::translate_values::<T::Balance, _>(|reserved| {
+				Some(reserved * factor)
+			});
+			NamedReserves::<T>::translate_values::<
+				BoundedVec<(T::ReserveIdentifier, T::Balance), T::MaxReserves>,
+				_,
+			>(|mut reserves| {
+				reserves.iter_mut().for_each(|(_, amount)| scale(amount));
+				Some(reserves)
+			});
+			// So may a freeze, which only ever caps what can be moved out.
+			FrozenBalance::<T>::translate_values::<T::Balance, _>(|frozen| {
+				Some(frozen.saturating_mul(factor))
+			});
+			let allowances: Vec<_> = Allowances::<T>::iter_prefix((native,)).collect();
+			for ((owner, spender), allowance) in allowances {
+				Allowances::<T>::insert((native, owner, spender), allowance * factor);
+			}
+			Allowance::<T>::translate_values::<T::Balance, _>(|allowance| Some(allowance * factor));
+			// Delegations are carved out of an allowance, so they fit whenever it does.
+			Delegations::<T>::translate_values::<
+				BoundedVec<(T::AccountId, T::Balance), T::MaxDelegations>,
+				_,
+			>(|mut delegations| {
+				delegations.iter_mut().for_each(|(_, amount)| scale(amount));
+				Some(delegations)
+			});
+			SpentBy::<T>::translate_values::<T::Balance, _>(|spent| Some(spent * factor));
+			KycOutflow::<T>::translate_values::<(BlockNumberFor<T>, T::Balance), _>(
+				|(window_start, sent)| Some((window_start, sent * factor)),
+			);
+			// A schedule may vest everything in one block, so its rate saturates.
+			VestingSchedules::<T>::translate_values::<
+				BoundedVec<VestingSchedule<T>, T::MaxVestingSchedules>,
+				_,
+			>(|mut schedules| {
+				schedules.iter_mut().for_each(|schedule| {
+					scale(&mut schedule.locked);
+					schedule.per_block = schedule.per_block.saturating_mul(factor);
+				});
+				Some(schedules)
+			});
+			SavingTranches::<T>::translate_values::<
+				BoundedVec<(BlockNumberFor<T>, T::Balance), T::MaxSavingTranches>,
+				_,
+			>(|mut tranches| {
+				tranches.iter_mut().for_each(|(_, value)| scale(value));
+				Some(tranches)
+			});
+			ScopedAllowances::<T>::translate_values::<(T::Balance, DepositBalanceOf<T>), _>(
+				|(allowance, deposit)| Some((allowance * factor, deposit)),
+			);
+			Subscriptions::<T>::translate_values::<Subscription<T>, _>(|mut subscription| {
+				scale(&mut subscription.limit);
+				scale(&mut subscription.spent_in_window);
+				Some(subscription)
+			});
+			TransferLimits::<T>::translate_values::<TransferLimit<T>, _>(|mut limit| {
+				scale(&mut limit.max_per_period);
+				scale(&mut limit.sent_in_window);
+				Some(limit)
+			});
+			// Rewards per share are a ratio of tokens to tokens and keep their value.
+			Ledgers::<T>::translate_values::<StakingLedger<T>, _>(|mut ledger| {
+				scale(&mut ledger.active);
+				scale_wide(&mut ledger.reward_debt);
+				ledger.unlocking.iter_mut().for_each(|(_, amount)| scale(amount));
+				Some(ledger)
+			});
+			RecentVolume::<T>::translate_values::<(BlockNumberFor<T>, u128), _>(
+				|(block, mut volume)| {
+					scale_wide(&mut volume);
+					Some((block, volume))
+				},
+			);
+			SupplyHistory::<T>::translate_values::<T::Balance, _>(|supply| Some(supply * factor));
+			PaymentRequests::<T>::translate_values::<
+				BoundedVec<PaymentRequest<T>, T::MaxPendingRequests>,
+				_,
+			>(|mut requests| {
+				requests.iter_mut().for_each(|request| scale(&mut request.amount));
+				Some(requests)
+			});
+			AnnouncedMints::<T>::mutate(|announced| {
+				announced.iter_mut().for_each(|mint| scale(&mut mint.amount))
+			});
+			InboundVotes::<T>::translate_values::<InboundProposal<T>, _>(|mut proposal| {
+				scale(&mut proposal.amount);
+				Some(proposal)
+			});
+			LatestReserveAttestation::<T>::mutate(|attestation| {
+				if let Some(attestation) = attestation {
+					scale(&mut attestation.reserves)
+				}
+			});
+			ReserveAttestationHistory::<T>::mutate(|history| {
+				history.iter_mut().for_each(|attestation| scale(&mut attestation.reserves))
+			});
+			// Tickets are keyed by the exact value they allow, so they move to the scaled key.
+			let tickets: Vec<_> = Attestations::<T>::drain().collect();
+			for ((from, to, value), expiry) in tickets {
+				Attestations::<T>::insert((from, to, value * factor), expiry);
+			}
+
+			Metadata::<T>::mutate(native, |metadata| metadata.decimals = decimals);
+
+			Pallet::<T>::deposit_event(Event::RedenominationComplete { factor });
+			T::DbWeight::get().reads_writes(2 * reads + 3, 2 * reads + 9)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			ensure!(
+				Self::added_decimals(Factor::get()).is_some(),
+				"Redenomination factor must be a power of ten"
+			);
+			ensure!(Self::all_fit(Factor::get()).0, "Redenomination would overflow");
+			let native = T::NativeAsset::get();
+			let sample: Vec<(T::AccountId, T::Balance)> =
+				BalanceOf::<T>::iter_prefix(native).take(16).collect();
+			let frozen: Vec<(T::AccountId, T::Balance)> =
+				FrozenBalance::<T>::iter().take(16).collect();
+			let decimals = Metadata::<T>::get(native).decimals;
+			let supply = TotalSupply::<T>::get(native);
+			Ok((supply, MaxSupply::<T>::get(), sample, frozen, decimals).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (supply, cap, sample, frozen, decimals) = <(
+				Option<T::Balance>,
+				Option<T::Balance>,
+				Vec<(T::AccountId, T::Balance)>,
+				Vec<(T::AccountId, T::Balance)>,
+				u8,
+			)>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre-upgrade balances")?;
+			let factor = Factor::get();
+			let native = T::NativeAsset::get();
+			ensure!(
+				TotalSupply::<T>::get(native) == supply.map(|supply| supply * factor),
+				"TotalSupply must be scaled by the factor"
+			);
+			ensure!(
+				MaxSupply::<T>::get() == cap.map(|cap| cap * factor),
+				"MaxSupply must be scaled by the factor"
+			);
+			ensure!(
+				Some(Metadata::<T>::get(native).decimals) ==
+					Self::added_decimals(factor).and_then(|added| decimals.checked_add(added)),
+				"Decimals must grow by the factor's exponent"
+			);
+			for (who, before) in sample {
+				ensure!(
+					BalanceOf::<T>::get(native, &who) == Some(before * factor),
+					"Sampled balances must be scaled by the factor"
+				);
+			}
+			for (who, before) in frozen {
+				ensure!(
+					FrozenBalance::<T>::get(&who) == Some(before.saturating_mul(factor)),
+					"Sampled freezes must be scaled by the factor"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,25 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate::traits::{KycLimit, KycTier, KycTierOf, OnSlash, OnTokenTransfer, OnTokensReceived};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	parameter_types,
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, ConstU8, GetStorageVersion,
+		OnFinalize, OnInitialize,
+	},
+	weights::IdentityFee,
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use scale_info::TypeInfo;
 use sp_core::H256;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_core::{sr25519, Pair};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, IdentifyAccount, Lazy, LookupError, StaticLookup, Verify},
+	BuildStorage, DispatchError, MultiSignature, MultiSigner, Perbill, RuntimeDebug,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -13,10 +29,45 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
 		TemplateModule: pallet_template,
+		Offences: pallet_offences,
+		TransactionPayment: pallet_transaction_payment,
 	}
 );
 
+// A stand-in for an offences pallet that penalizes stakers through `SlashHandler`.
+#[frame_support::pallet]
+pub mod pallet_offences {
+	use crate::traits::SlashHandler;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Slash: SlashHandler<Self::AccountId, u64>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn report(origin: OriginFor<T>, offender: T::AccountId, amount: u64) -> DispatchResult {
+			ensure_root(origin)?;
+			T::Slash::slash_stake(&offender, amount);
+			Ok(())
+		}
+	}
+}
+
+impl pallet_offences::Config for Test {
+	type Slash = TemplateModule;
+}
+
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -28,13 +79,13 @@ impl frame_system::Config for Test {
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
 	type AccountId = u64;
-	type Lookup = IdentityLookup<Self::AccountId>;
+	type Lookup = TestLookup;
 	type Block = Block;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -43,12 +94,317 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
-impl pallet_template::Config for Test {
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<10>;
+	type AccountStore = System;
 	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+// Fees are reckoned in a native balance wider than the token's, so conversions can overflow.
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_template::fees::TokenFees<Test, u128>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<u128>;
+	type LengthToFee = IdentityFee<u128>;
+	type FeeMultiplierUpdate = ();
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type RemoveItemsLimit = ConstU32<10>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<0>;
+	type AssetAccountDeposit = ConstU64<0>;
+	type MetadataDepositBase = ConstU64<0>;
+	type MetadataDepositPerByte = ConstU64<0>;
+	type ApprovalDeposit = AssetApprovalDeposit;
+	type StringLimit = ConstU32<16>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const TemplatePalletId: PalletId = PalletId(*b"py/erc20");
+	pub const TemplateDomainName: &'static [u8] = b"Template Token";
+	pub const TreasuryPot: u64 = 99;
+	pub const RewardPot: u64 = 98;
+	pub static BlockReward: u64 = 0;
+	pub const CommunityPool: u64 = 97;
+	pub static DemurrageRate: Perbill = Perbill::zero();
+	pub const StakingPot: u64 = 96;
+	pub static SlashFreeBalance: bool = false;
+	pub static SlashedTokens: Vec<(u64, u64)> = vec![];
+	pub const BridgePot: u64 = 95;
+	pub static BridgeBurns: bool = false;
+	pub static BurnAddress: Option<u64> = None;
+	pub static MaxMintPerBlock: u64 = u64::MAX;
+	pub static FreeTransfersPerPeriod: u32 = 0;
+	pub static AllowanceDeposit: u64 = 0;
+	pub static AttestationThreshold: u64 = u64::MAX;
+	pub const KycNoneLimit: KycLimit<u64> = KycLimit { max_transfer: 0, max_per_window: 0 };
+	pub const KycBasicLimit: KycLimit<u64> = KycLimit { max_transfer: 50, max_per_window: 80 };
+	pub const KycFullLimit: KycLimit<u64> =
+		KycLimit { max_transfer: u64::MAX, max_per_window: u64::MAX };
+	pub static KycTiers: Vec<(u64, KycTier)> = vec![];
+	pub static IndexTransfers: bool = false;
+	pub static DetailedEvents: bool = false;
+	pub static RejectTokens: bool = false;
+	pub static MembershipThreshold: u64 = 50;
+	pub static MaxHolders: Option<u32> = None;
+	pub static MinBalance: u64 = 0;
+	pub static DustReceiver: Option<u64> = None;
+	pub static VetoTransfers: bool = false;
+	pub static ObservedTransfers: Vec<(Option<u64>, Option<u64>, u64)> = vec![];
+	pub static SigningKeys: Vec<(u64, MultiSigner)> = vec![];
+	pub static MintRequiresCollateral: bool = false;
+	pub static AssetApprovalDeposit: u64 = 0;
+	pub static ReceivedTokens: Vec<(u64, u64, u64, Vec<u8>, Vec<u8>)> = vec![];
+}
+
+// What a treasury-style pallet would use to make grants in the token.
+pub type TreasuryPay = pallet_template::PayFromPot<Test, TreasuryPot>;
+
+// What a token-gated registry would filter its members with.
+pub type RegistryMembers = pallet_template::HoldersAbove<Test, MembershipThreshold>;
+
+// Records every send it sees, or rejects them all while `RejectTokens` is set.
+pub struct RecordTokensReceived;
+impl OnTokensReceived<u64, u64> for RecordTokensReceived {
+	fn on_tokens_received(
+		from: &u64,
+		to: &u64,
+		value: u64,
+		data: &[u8],
+		operator_data: &[u8],
+	) -> DispatchResult {
+		if RejectTokens::get() {
+			return Err(DispatchError::Other("tokens rejected"))
+		}
+		ReceivedTokens::mutate(|received| {
+			received.push((*from, *to, value, data.to_vec(), operator_data.to_vec()))
+		});
+		Ok(())
+	}
+}
+
+// Records every movement it is told about after the fact, and vetoes them all while
+// `VetoTransfers` is set.
+pub struct RecordTransfers;
+impl OnTokenTransfer<u64, u64> for RecordTransfers {
+	fn before_transfer(_: Option<&u64>, _: Option<&u64>, _: u64) -> DispatchResult {
+		if VetoTransfers::get() {
+			return Err(DispatchError::Other("transfer vetoed"))
+		}
+		Ok(())
+	}
+	fn after_transfer(from: Option<&u64>, to: Option<&u64>, value: u64) {
+		ObservedTransfers::mutate(|observed| observed.push((from.copied(), to.copied(), value)));
+	}
 }
 
-// Build genesis storage according to the mock runtime.
+impl pallet_template::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = crate::weights::SubstrateWeight<Test>;
+	type Balance = u64;
+	type AssetId = u32;
+	type NativeAsset = ConstU32<0>;
+	type MaxApprovalsPerOwner = ConstU32<3>;
+	type MaxDelegations = ConstU32<2>;
+	type AllowanceMigrationBatch = ConstU32<2>;
+	type StatsWindow = ConstU32<4>;
+	type MetricsInterval = ConstU32<10>;
+	type SupplyTrackingPeriod = ConstU32<5>;
+	type MaxSupplyHistory = ConstU32<3>;
+	type PalletId = TemplatePalletId;
+	type MaxBatchSize = ConstU32<10>;
+	type DomainName = TemplateDomainName;
+	type DomainVersion = ConstU32<1>;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockSigner;
+	type PermitPriority = ConstU64<100>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+	type TokensReceived = RecordTokensReceived;
+	type MaxDataLen = ConstU32<32>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type BlockReward = BlockReward;
+	type RewardBeneficiary = RewardPot;
+	type DemurrageRate = DemurrageRate;
+	type DemurragePool = CommunityPool;
+	type StakingPot = StakingPot;
+	type UnbondingBlocks = ConstU64<10>;
+	type MaxUnbondingChunks = ConstU32<2>;
+	type SlashFreeBalance = SlashFreeBalance;
+	type OnSlash = RecordSlash;
+	type BridgeOrigin = EnsureRoot<u64>;
+	type BridgePot = BridgePot;
+	type BridgeBurns = BridgeBurns;
+	type BurnAddress = BurnAddress;
+	type MaxAddressLen = ConstU32<32>;
+	type MaxRelayers = ConstU32<3>;
+	type RequestTtl = ConstU64<5>;
+	type MaxPendingRequests = ConstU32<2>;
+	type MaxMintPerBlock = MaxMintPerBlock;
+	type MintAnnouncementThreshold = ConstU64<1_000>;
+	type MintDelay = ConstU64<5>;
+	type MaxAnnouncedMints = ConstU32<2>;
+	type ParameterDelay = ConstU64<3>;
+	type MaxExcludedAccounts = ConstU32<2>;
+	type FreeTransfersPerPeriod = FreeTransfersPerPeriod;
+	type FreeTransferMaxValue = ConstU64<10>;
+	type FreeTransferPeriod = ConstU64<10>;
+	type Currency = Balances;
+	type AllowanceDeposit = AllowanceDeposit;
+	type ComplianceOracle = EnsureRoot<u64>;
+	type ReserveOracle = EnsureRoot<u64>;
+	type MaxReserveHistory = ConstU32<2>;
+	type MintRequiresCollateral = MintRequiresCollateral;
+	type AttestationThreshold = AttestationThreshold;
+	type IndexTransfers = IndexTransfers;
+	type DetailedEvents = DetailedEvents;
+	type FreezeOrigin = EnsureRoot<u64>;
+	type KycProvider = MockKyc;
+	type KycNoneLimit = KycNoneLimit;
+	type KycBasicLimit = KycBasicLimit;
+	type KycFullLimit = KycFullLimit;
+	type KycWindow = ConstU64<10>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<2>;
+	type MaxSavingTranches = ConstU32<3>;
+	type MaxHolders = MaxHolders;
+	type MaxClaimProofLen = ConstU32<8>;
+	type TransferIdRetention = ConstU64<10>;
+	type Assets = Assets;
+	type AssetMigrationBatch = ConstU32<2>;
+	type SunsetBatch = ConstU32<2>;
+	type TopHoldersTracked = ConstU32<3>;
+	type StringLimit = ConstU32<8>;
+	type PauseOrigin = EnsureRoot<u64>;
+	type MinBalance = MinBalance;
+	type DustReceiver = DustReceiver;
+	type MaxVestingSchedules = ConstU32<2>;
+	type OnTransfer = RecordTransfers;
+	#[cfg(feature = "testnet-faucet")]
+	type FaucetAmount = ConstU64<50>;
+	#[cfg(feature = "testnet-faucet")]
+	type FaucetCooldown = ConstU64<10>;
+}
+
+// Records every slashed amount.
+pub struct RecordSlash;
+impl OnSlash<u64, u64> for RecordSlash {
+	fn on_slash(who: &u64, amount: u64) {
+		SlashedTokens::mutate(|slashed| slashed.push((*who, amount)));
+	}
+}
+
+// Resolves every address to the account of the same number, except `UNKNOWN_ADDRESS`.
+pub const UNKNOWN_ADDRESS: u64 = u64::MAX;
+pub struct TestLookup;
+impl StaticLookup for TestLookup {
+	type Source = u64;
+	type Target = u64;
+	fn lookup(address: u64) -> Result<u64, LookupError> {
+		if address == UNKNOWN_ADDRESS {
+			return Err(LookupError)
+		}
+		Ok(address)
+	}
+	fn unlookup(who: u64) -> u64 {
+		who
+	}
+}
+
+// A `MultiSignature` checked against the key `SigningKeys` lists for a `u64` account, which
+// cannot hold a public key itself.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub MultiSignature);
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+	fn verify<L: Lazy<[u8]>>(&self, msg: L, signer: &u64) -> bool {
+		SigningKeys::get()
+			.into_iter()
+			.find_map(|(account, key)| (account == *signer).then_some(key))
+			.map_or(false, |key| self.0.verify(msg, &key.into_account()))
+	}
+}
+pub struct MockSigner(pub u64);
+impl IdentifyAccount for MockSigner {
+	type AccountId = u64;
+	fn into_account(self) -> u64 {
+		self.0
+	}
+}
+
+// Signs benchmark payloads as account 90 with a fixed sr25519 key.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::traits::BenchmarkHelper<u64, MockSignature> for MockBenchmarkHelper {
+	fn signer() -> u64 {
+		SigningKeys::set(vec![(90, Self::pair().public().into())]);
+		90
+	}
+	fn sign(_: &u64, payload: &[u8]) -> MockSignature {
+		MockSignature(Self::pair().sign(payload).into())
+	}
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl MockBenchmarkHelper {
+	fn pair() -> sr25519::Pair {
+		sr25519::Pair::from_seed(&[90; 32])
+	}
+}
+
+// Gives the accounts listed in `KycTiers` their tier and everyone else `Full`.
+pub struct MockKyc;
+impl KycTierOf<u64> for MockKyc {
+	fn kyc_tier(who: &u64) -> KycTier {
+		KycTiers::get()
+			.into_iter()
+			.find_map(|(account, tier)| (account == *who).then_some(tier))
+			.unwrap_or(KycTier::Full)
+	}
+}
+
+// Build genesis storage according to the mock runtime, with a keystore for the benchmarks
+// that sign.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut ext: sp_io::TestExternalities =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext.execute_with(|| TemplateModule::current_storage_version().put::<TemplateModule>());
+	ext
+}
+
+// Advance the chain to block `n`, running the pallet hooks on the way.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let block = System::block_number();
+		TemplateModule::on_finalize(block);
+		System::on_finalize(block);
+		System::set_block_number(block + 1);
+		System::on_initialize(block + 1);
+		TemplateModule::on_initialize(block + 1);
+	}
 }
@@ -1,27 +1,5472 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	extensions::ACCOUNT_FROZEN,
+	fees::TokenFees,
+	migrations::{self, pre_v5},
+	mock::*,
+	traits::KycTier,
+	weights::SubstrateWeight,
+	Allowance, AllowanceExpiry, AllowanceRekey, AllowanceSweepCursor, Allowances, AnnouncedMint,
+	Approvals, Attestations, BalanceOf, BatchMode, CheckNotFrozen, Config, DelegatedAllowances,
+	Delegations, Erc20Error, Error, Event, ExpiringAllowances, GenesisConfig, HolderCount,
+	InboundProposal, KycOutflow, LastActivity, Ledgers, Metadata, NamedReserves, Parameters,
+	PaymentRequest, ReserveAttestation, ReservedBalance, SavingTranches, ScopedApprovalCount,
+	StakingLedger, SunsetDestination, TokenAction, TopHoldersFloor, TotalSupply, TransferRecord,
+	UsedTransferIds, VestingSchedules, WeightInfo, DEFAULT_PURPOSE, MAX_BALANCES_QUERY,
+	METRICS_ENDPOINT_KEY, TRANSFER_RECORD_PREFIX,
+};
+use codec::{Encode, MaxEncodedLen};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchInfo, Pays, PostDispatchInfo, WithPostDispatchInfo},
+	pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned},
+	traits::{
+		fungible, fungibles,
+		tokens::{Fortitude, Pay, PaymentStatus, Precision, Preservation},
+		BalanceStatus, BuildGenesisConfig, ConstU32, ConstU64, Contains, Currency,
+		ExistenceRequirement, Get, GetStorageVersion, Imbalance, NamedReservableCurrency,
+		OffchainWorker, OnIdle, OnRuntimeUpgrade, PalletError, ReservableCurrency,
+		StorageInfoTrait, StorageVersion, UnfilteredDispatchable, WithdrawReasons,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_core::{
+	ecdsa, ed25519,
+	offchain::{
+		testing::{PendingRequest, TestOffchainExt},
+		OffchainDbExt, OffchainWorkerExt, StorageKind,
+	},
+	sr25519, Pair, H256,
+};
+use sp_runtime::{
+	traits::SignedExtension, transaction_validity::TransactionValidityError, DispatchError,
+	FixedPointNumber, FixedU128, MultiSignature, MultiSigner, Perbill,
+};
+use sp_std::cmp::Reverse;
 
 #[test]
-fn it_works_for_default_value() {
+fn transfer_works() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
 		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 40));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(60));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(40));
 		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		System::assert_last_event(Event::Transfer { from: 1, to: 2, value: 40 }.into());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn transfer_fails_with_insufficient_funds() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::AccountNotFound
 		);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 10));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 11),
+			Error::<Test>::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn transfer_from_removes_exhausted_allowance() {
+	new_test_ext().execute_with(|| {
+		Allowances::<Test>::insert((0, 1, 2), 30);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(20));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 20));
+		assert!(!Allowances::<Test>::contains_key((0, 1, 2)));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+	});
+}
+
+#[test]
+fn approve_only_records_an_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 150, None));
+		System::assert_last_event(Event::Approval { from: 1, to: 2, value: 150 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(TemplateModule::get_balance_of(2), None);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(150));
+
+		// Only the approved spender can draw on the allowance, for any recipient.
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 2, 10),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 3, 10));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(90));
+		assert_eq!(TemplateModule::get_balance_of(3), Some(10));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(140));
+	});
+}
+
+#[test]
+fn transfers_stop_at_the_exact_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 101),
+			Error::<Test>::FundsUnavailable
+		);
+		Allowances::<Test>::insert((0, 1, 3), u64::MAX);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 2, 101),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(0));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(100));
+	});
+}
+
+#[test]
+fn credits_and_mints_fail_instead_of_wrapping() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		BalanceOf::<Test>::insert(0, 4, u64::MAX);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 4, 1),
+			Error::<Test>::Overflow
+		);
+		Allowances::<Test>::insert((0, 1, 3), 10);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 4, 1),
+			Error::<Test>::Overflow
+		);
+
+		TotalSupply::<Test>::insert(0, u64::MAX - 1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 5, 1));
+		assert_noop!(TemplateModule::mint(RuntimeOrigin::root(), 5, 1), Error::<Test>::Overflow);
+		assert_eq!(TemplateModule::get_total_supply(), Some(u64::MAX));
+	});
+}
+
+#[test]
+fn spend_all_allowance_moves_what_is_left() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 30));
+		assert_noop!(
+			TemplateModule::spend_all_allowance(RuntimeOrigin::signed(3), 1, 4),
+			Error::<Test>::ApprovalNotGranted
+		);
+
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 4));
+		System::assert_last_event(Event::TransferFrom { from: 1, to: 4, value: 30 }.into());
+		assert_eq!(TemplateModule::get_balance_of(4), Some(30));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+		assert!(TemplateModule::approvals_of(1).is_empty());
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn spend_all_allowance_is_capped_by_the_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 150));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, u64::MAX));
+
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 4));
+		System::assert_last_event(Event::TransferFrom { from: 1, to: 4, value: 100 }.into());
+		// The shortfall is not kept as a smaller allowance.
+		assert!(!Allowances::<Test>::contains_key((0, 1, 2)));
+
+		// `u64::MAX` is an ordinary allowance: it is removed once spent like any other, and
+		// the owner now has nothing left to move.
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(3), 1, 4));
+		System::assert_last_event(Event::TransferFrom { from: 1, to: 4, value: 0 }.into());
+		assert!(!Allowances::<Test>::contains_key((0, 1, 3)));
+		assert_eq!(TemplateModule::get_balance_of(4), Some(100));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn delegations_are_carved_out_of_the_spenders_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 50));
+
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 3, 30));
+		System::assert_last_event(
+			Event::AllowanceDelegated { owner: 1, spender: 2, delegate: 3, amount: 30 }.into(),
+		);
+		// Only 20 of the allowance is left to delegate.
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 4, 21),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 1, 1),
+			Error::<Test>::InvalidDelegate
+		);
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 4, 0),
+			Error::<Test>::EmptyDelegation
+		);
+		// A delegate cannot pass its share on.
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(3), 1, 4, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 31),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 20));
+		assert_eq!(Delegations::<Test>::get(1, 2).into_inner(), vec![(3, 10)]);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(30));
+
+		// The spender keeps only what it did not delegate.
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 21),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 5));
+		System::assert_last_event(Event::TransferFrom { from: 1, to: 5, value: 20 }.into());
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(10));
+
+		// Spending the rest of the delegation uses up the allowance along with it.
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 10));
+		assert_eq!(TemplateModule::get_balance_of(3), Some(30));
+		assert!(!Delegations::<Test>::contains_key(1, 2));
+		assert!(!DelegatedAllowances::<Test>::contains_key(1, 3));
+		assert!(!Allowances::<Test>::contains_key((0, 1, 2)));
+		assert!(!Approvals::<Test>::get(1).contains(&2));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn delegations_above_the_allowance_leave_nothing_to_spend() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 50));
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 3, 30));
+		// Shrunk underneath its delegations, as no call can.
+		Allowances::<Test>::insert((0, 1, 2), 20);
+
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 1),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 4, 1),
+			Error::<Test>::AllowanceExceeded
+		);
+		// Nothing moves, and the allowance is not raised to what was delegated.
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 5));
+		System::assert_last_event(Event::TransferFrom { from: 1, to: 5, value: 0 }.into());
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(20));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert!(!Delegations::<Test>::contains_key(1, 2));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn lowering_an_allowance_revokes_the_newest_delegations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 50));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 6, 0, 50));
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 3, 10));
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 4, 20));
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 5, 1),
+			Error::<Test>::TooManyDelegations
+		);
+		assert_noop!(
+			TemplateModule::delegate_allowance(RuntimeOrigin::signed(6), 1, 3, 1),
+			Error::<Test>::DelegateTaken
+		);
+		// Delegating to the same delegate again adds to its share.
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 3, 5));
+		assert_eq!(Delegations::<Test>::get(1, 2).into_inner(), vec![(3, 15), (4, 20)]);
+
+		System::reset_events();
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 50, 25));
+		System::assert_has_event(
+			Event::DelegationRevoked { owner: 1, spender: 2, delegate: 4, amount: 20 }.into(),
+		);
+		assert_eq!(Delegations::<Test>::get(1, 2).into_inner(), vec![(3, 15)]);
+		assert!(!DelegatedAllowances::<Test>::contains_key(1, 4));
+
+		// Revoking the allowance revokes every delegation of it.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 25, 0));
+		System::assert_has_event(
+			Event::DelegationRevoked { owner: 1, spender: 2, delegate: 3, amount: 15 }.into(),
+		);
+		assert!(!Delegations::<Test>::contains_key(1, 2));
+		assert!(!DelegatedAllowances::<Test>::contains_key(1, 3));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn weights_account_for_proof_size() {
+	assert!(SubstrateWeight::<Test>::transfer().proof_size() > 0);
+	assert!(SubstrateWeight::<Test>::approve().proof_size() > 0);
+	assert!(SubstrateWeight::<Test>::transfer_from().proof_size() > 0);
+}
+
+#[test]
+fn storage_items_are_bounded() {
+	for info in TemplateModule::storage_info() {
+		assert!(info.max_size.is_some(), "{:?} has no max encoded length", info.storage_name);
+	}
+}
+
+// Only compiles for types with a `MaxEncodedLen`, and checks that `value` stays within it.
+fn assert_fits<V: Encode + MaxEncodedLen>(value: V) {
+	assert!(value.encoded_size() <= V::max_encoded_len(), "{:?} over its bound", value.encode());
+}
+
+#[test]
+fn largest_stored_values_fit_their_bound() {
+	let chunks = <Test as Config>::MaxUnbondingChunks::get() as usize;
+	assert_fits(StakingLedger::<Test> {
+		active: u64::MAX,
+		unlocking: vec![(u64::MAX, u64::MAX); chunks].try_into().unwrap(),
+		reward_debt: u128::MAX,
+	});
+
+	let request = PaymentRequest::<Test> {
+		id: u64::MAX,
+		requester: u64::MAX,
+		amount: u64::MAX,
+		memo: vec![u8::MAX; <Test as Config>::MaxDataLen::get() as usize].try_into().unwrap(),
+		expires_at: u64::MAX,
+	};
+	let pending = <Test as Config>::MaxPendingRequests::get() as usize;
+	assert_fits(
+		BoundedVec::<_, <Test as Config>::MaxPendingRequests>::try_from(vec![request; pending])
+			.unwrap(),
+	);
+
+	let relayers = <Test as Config>::MaxRelayers::get() as usize;
+	assert_fits(InboundProposal::<Test> {
+		to: u64::MAX,
+		amount: u64::MAX,
+		voters: vec![u64::MAX; relayers].try_into().unwrap(),
+		frozen: true,
+	});
+
+	let mint =
+		AnnouncedMint::<Test> { id: u64::MAX, to: u64::MAX, amount: u64::MAX, executable_at: 0 };
+	let mints = <Test as Config>::MaxAnnouncedMints::get() as usize;
+	assert_fits(
+		BoundedVec::<_, <Test as Config>::MaxAnnouncedMints>::try_from(vec![mint; mints]).unwrap(),
+	);
+
+	let reserves = <Test as Config>::MaxReserves::get() as usize;
+	assert_fits(
+		BoundedVec::<_, <Test as Config>::MaxReserves>::try_from(vec![([0u8; 8], 0u64); reserves])
+			.unwrap(),
+	);
+}
+
+#[test]
+fn balances_of_answers_in_input_order() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 50));
+		// Account 2 moves everything away and is reaped.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 50));
+		// Account 4 has never been seen.
+		assert_eq!(TemplateModule::balances_of(vec![4, 3, 2, 1, 1]), vec![0, 50, 0, 100, 100]);
+		assert_eq!(TemplateModule::balances_of(vec![]), Vec::<u64>::new());
+	});
+}
+
+#[test]
+fn balances_of_is_truncated() {
+	new_test_ext().execute_with(|| {
+		let accounts = vec![1; MAX_BALANCES_QUERY + 1];
+		assert_eq!(TemplateModule::balances_of(accounts).len(), MAX_BALANCES_QUERY);
+	});
+}
+
+#[test]
+fn holders_is_empty_without_balances() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::holders(None, 10), (vec![], None));
+	});
+}
+
+#[test]
+fn holders_paginates_over_all_accounts() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=5 {
+			assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), who, who * 10));
+		}
+
+		let mut seen = vec![];
+		let mut cursor = None;
+		let mut pages = 0;
+		loop {
+			let (page, next) = TemplateModule::holders(cursor, 2);
+			assert!(page.len() <= 2);
+			seen.extend(page);
+			pages += 1;
+			match next {
+				Some(key) => cursor = Some(key),
+				None => break,
+			}
+		}
+		assert_eq!(pages, 3);
+		seen.sort();
+		assert_eq!(seen, vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50)]);
+
+		// A page that ends exactly at the last holder reports no further cursor.
+		let (page, next) = TemplateModule::holders(None, 5);
+		assert_eq!(page.len(), 5);
+		assert_eq!(next, None);
+	});
+}
+
+// The `n` largest holders found by scanning every balance.
+fn largest_holders(n: usize) -> Vec<(u64, u64)> {
+	let mut holders: Vec<_> = BalanceOf::<Test>::iter_prefix(0).collect();
+	// Ties go the way the index stores them, by encoding.
+	holders.sort_by_key(|(who, balance)| Reverse((*balance, who.encode())));
+	holders.truncate(n);
+	holders
+}
+
+// The index matches a full scan, for as many entries as it holds.
+fn assert_top_holders(expected: Vec<(u64, u64)>) {
+	let top = TemplateModule::top_holders(10);
+	assert_eq!(top, expected);
+	assert_eq!(top, largest_holders(top.len()));
+	assert_ok!(TemplateModule::do_try_state());
+}
+
+#[test]
+fn top_holders_follow_balance_changes() {
+	new_test_ext().execute_with(|| {
+		// Three are tracked: the fourth and fifth holders push the smallest ones out.
+		for who in 1..=5 {
+			assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), who, who * 10));
+		}
+		assert_top_holders(vec![(5, 50), (4, 40), (3, 30)]);
+		assert_eq!(TopHoldersFloor::<Test>::get(), 20);
+		assert_eq!(TemplateModule::top_holders(2), vec![(5, 50), (4, 40)]);
+
+		// A transfer moves one holder below the floor and another above it.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(3), 1, 15));
+		assert_top_holders(vec![(5, 50), (4, 40), (1, 25)]);
+
+		// Leaving the index does not pull in 2, whose balance sits at the floor.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 1));
+		assert_top_holders(vec![(4, 40), (1, 25)]);
+
+		// Until it rises above the floor.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 30));
+		assert_top_holders(vec![(4, 40), (2, 30), (1, 25)]);
+
+		// Once the index holds every holder, nobody is left out and the floor resets.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 3, 0));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 0));
+		assert_eq!(TopHoldersFloor::<Test>::get(), 0);
+		assert_top_holders(vec![(4, 40), (2, 30), (1, 25)]);
+
+		// A new holder too small for a full index raises the floor to its balance.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 6, 5));
+		assert_eq!(TopHoldersFloor::<Test>::get(), 5);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 6, 60));
+		assert_top_holders(vec![(6, 60), (4, 40), (2, 30)]);
+		assert_eq!(TopHoldersFloor::<Test>::get(), 25);
+	});
+}
+
+#[test]
+fn top_holders_stay_exact_through_churn_around_the_cutoff() {
+	new_test_ext().execute_with(|| {
+		// Few distinct balances, so many holders tie at the cutoff.
+		let mut seed = 7u64;
+		for _ in 0..200 {
+			seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+			let (who, balance) = ((seed >> 33) % 8 + 1, (seed >> 40) % 5 * 10);
+			assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), who, balance));
+			let top = TemplateModule::top_holders(10);
+			assert_eq!(top, largest_holders(top.len()));
+			assert!(top.len() as u32 <= <Test as Config>::TopHoldersTracked::get());
+			assert_ok!(TemplateModule::do_try_state());
+		}
+	});
+}
+
+#[test]
+fn holder_count_follows_endowment_and_reaping() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TemplateModule::holder_count(), 0);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 80));
+		assert_eq!(TemplateModule::holder_count(), 1);
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		assert_eq!(TemplateModule::holder_count(), 2);
+
+		// Emptying an account reaps it.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50));
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::holder_count(), 1);
+
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 0));
+		assert_eq!(TemplateModule::holder_count(), 0);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_wrong_holder_count() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		HolderCount::<Test>::put(2);
+		assert!(TemplateModule::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn v1_migration_initializes_holder_count() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		pre_v5::BalanceOf::<Test>::insert(1, 100);
+		pre_v5::BalanceOf::<Test>::insert(2, 0);
+		pre_v5::BalanceOf::<Test>::insert(3, 5);
+
+		migrations::v1::InitHolderCount::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+		assert_eq!(TemplateModule::holder_count(), 2);
+		assert!(!pre_v5::BalanceOf::<Test>::contains_key(2));
+		assert!(pre_v5::BalanceOf::<Test>::iter_values().all(|balance| balance > 0));
+
+		// Running it again is a no-op.
+		HolderCount::<Test>::put(7);
+		migrations::v1::InitHolderCount::<Test>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::holder_count(), 7);
+	});
+}
+
+#[test]
+fn v4_migration_builds_the_top_holders_index() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<TemplateModule>();
+		for who in 1..=5 {
+			pre_v5::BalanceOf::<Test>::insert(who, who * 10);
+		}
+		HolderCount::<Test>::put(5);
+
+		migrations::v4::InitTopHolders::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 4);
+		assert_eq!(TemplateModule::top_holders(10), vec![(5, 50), (4, 40), (3, 30)]);
+		assert_eq!(TopHoldersFloor::<Test>::get(), 20);
+
+		// Running it again is a no-op.
+		TopHoldersFloor::<Test>::put(7);
+		migrations::v4::InitTopHolders::<Test>::on_runtime_upgrade();
+		assert_eq!(TopHoldersFloor::<Test>::get(), 7);
+	});
+}
+
+#[test]
+fn approvals_of_lists_spenders() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 10, None));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 20, None));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 10), (3, 20)]);
+
+		// Re-approving an existing spender does not add a second index entry.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 5, None));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 5), (3, 20)]);
+
+		// Approving zero revokes.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 0, None));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 5)]);
+		assert_eq!(TemplateModule::approvals_of(4), vec![]);
+		assert_eq!(TemplateModule::allowance(1, 2), 5);
+		assert_eq!(TemplateModule::allowance(1, 3), 0);
+	});
+}
+
+#[test]
+fn approvals_are_bounded_per_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		for spender in 2..=4 {
+			assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), spender, 1, None));
+		}
+		assert_noop!(
+			TemplateModule::approve(RuntimeOrigin::signed(1), 5, 1, None),
+			Error::<Test>::TooManyApprovals
+		);
+		// Updating an existing spender still works at the bound.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 4, 2, None));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 4, 2, 3));
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 5, 0, 1),
+			Error::<Test>::TooManyApprovals
+		);
+		let batch = vec![(2, 5), (5, 1)].try_into().unwrap();
+		assert_noop!(
+			TemplateModule::batch_approve(RuntimeOrigin::signed(1), batch),
+			Error::<Test>::TooManyApprovals
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn every_removal_frees_an_approval_slot() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		for spender in 2..=4 {
+			assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), spender, 0, 1));
+		}
+
+		// Spent down to zero.
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 1));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 5, 0, 1));
+		// Revoked one by one.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 1, 0));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 6, 0, 1));
+		// Revoked in a batch, making room in the same call.
+		let batch = vec![(4, 0), (7, 1)].try_into().unwrap();
+		assert_ok!(TemplateModule::batch_approve(RuntimeOrigin::signed(1), batch));
+
+		assert_eq!(TemplateModule::approvals_of(1), vec![(5, 1), (6, 1), (7, 1)]);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn exhausting_an_allowance_updates_the_index() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 10, None));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 10, None));
+
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 4));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 6), (3, 10)]);
+
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 6));
+		assert!(!Allowances::<Test>::contains_key((0, 1, 2)));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(3, 10)]);
+
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 10));
+		assert!(!Approvals::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn transfers_update_statistics() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		Allowances::<Test>::insert((0, 1, 3), 20);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 20));
+		// Failed transfers are not counted.
+		assert!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 31).is_err());
+
+		assert_eq!(TemplateModule::transfer_count(), 2);
+		assert_eq!(TemplateModule::transfer_volume(), 50);
+		assert_eq!(TemplateModule::block_volume(), 50);
+	});
+}
+
+#[test]
+fn recent_volume_ring_buffer_wraps() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		for block in 1..=6 {
+			run_to_block(block);
+			assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, block * 10));
+		}
+		// Close block 6 so its volume lands in the buffer.
+		run_to_block(7);
+		assert_eq!(TemplateModule::block_volume(), 0);
+
+		// The window holds four blocks, so blocks 1 and 2 have been overwritten.
+		assert_eq!(TemplateModule::recent_volume(), vec![(3, 30), (4, 40), (5, 50), (6, 60)]);
+		assert_eq!(TemplateModule::transfer_volume(), 210);
+	});
+}
+
+#[test]
+fn offchain_worker_posts_metrics() {
+	let (offchain, state) = TestOffchainExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			METRICS_ENDPOINT_KEY,
+			b"http://localhost:9100/metrics",
+		);
+
+		state.write().expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: "http://localhost:9100/metrics".into(),
+			headers: vec![("Content-Type".into(), "application/json".into())],
+			body: concat!(
+				r#"{"block":10,"total_supply":100,"holders":2,"block_volume":30,"#,
+				r#""transfers":1,"transfer_volume":30}"#,
+			)
+			.as_bytes()
+			.to_vec(),
+			response: Some(b"ok".to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		TemplateModule::offchain_worker(10);
+	});
+}
+
+#[test]
+fn offchain_worker_requires_opt_in_and_interval() {
+	let (offchain, _state) = TestOffchainExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.execute_with(|| {
+		// No endpoint configured: nothing is sent.
+		TemplateModule::offchain_worker(10);
+
+		// Off-interval blocks never publish, even when opted in. Any request here would fail
+		// the test because none is expected.
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			METRICS_ENDPOINT_KEY,
+			b"http://localhost:9100/metrics",
+		);
+		TemplateModule::offchain_worker(11);
+	});
+}
+
+#[test]
+fn supply_is_checkpointed_every_period() {
+	new_test_ext().execute_with(|| {
+		TotalSupply::<Test>::insert(0, 1_000);
+		run_to_block(5);
+		assert_eq!(TemplateModule::supply_at_period(1), Some(1_000));
+
+		// A mint during period 1 shows up at the start of period 2.
+		run_to_block(7);
+		TotalSupply::<Test>::insert(0, 1_500);
+		assert_eq!(TemplateModule::supply_at_period(1), Some(1_000));
+		run_to_block(10);
+		assert_eq!(TemplateModule::supply_at_period(2), Some(1_500));
+
+		// A burn during period 2.
+		run_to_block(12);
+		TotalSupply::<Test>::insert(0, 1_200);
+		run_to_block(15);
+		assert_eq!(TemplateModule::supply_at_period(3), Some(1_200));
+
+		// Only the last three periods are kept.
+		run_to_block(20);
+		assert_eq!(TemplateModule::supply_at_period(1), None);
+		assert_eq!(
+			(2..=4).map(TemplateModule::supply_at_period).collect::<Vec<_>>(),
+			vec![Some(1_500), Some(1_200), Some(1_200)]
+		);
+	});
+}
+
+#[test]
+fn approve_if_replaces_the_expected_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// A missing allowance is expected to be zero.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 50));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(50));
+		System::assert_last_event(Event::Approval { from: 1, to: 2, value: 50 }.into());
+
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 50, 20));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(20));
+	});
+}
+
+#[test]
+fn approve_if_fails_on_mismatch() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 50));
+		// A spend landed first, so the allowance is no longer what the caller saw.
+		Allowances::<Test>::insert((0, 1, 2), 30);
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 50, 20),
+			Error::<Test>::AllowanceMismatch
+		);
+		// Nothing stored for this spender, so only an expectation of zero matches.
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 10, 20),
+			Error::<Test>::AllowanceMismatch
+		);
+	});
+}
+
+#[test]
+fn allowances_can_be_raised_and_lowered_in_place() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::decrease_allowance(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_ok!(TemplateModule::increase_allowance(RuntimeOrigin::signed(1), 2, 30));
+		System::assert_last_event(
+			Event::AllowanceChanged { owner: 1, spender: 2, allowance: 30 }.into(),
+		);
+
+		// A spend in between is not undone by the change.
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10));
+		assert_ok!(TemplateModule::increase_allowance(RuntimeOrigin::signed(1), 2, 5));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(25));
+		assert_noop!(
+			TemplateModule::increase_allowance(RuntimeOrigin::signed(1), 2, u64::MAX),
+			Error::<Test>::Overflow
+		);
+
+		assert_noop!(
+			TemplateModule::decrease_allowance(RuntimeOrigin::signed(1), 2, 26),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(TemplateModule::decrease_allowance(RuntimeOrigin::signed(1), 2, 20));
+		System::assert_last_event(
+			Event::AllowanceChanged { owner: 1, spender: 2, allowance: 5 }.into(),
+		);
+		assert_ok!(TemplateModule::decrease_allowance(RuntimeOrigin::signed(1), 2, 5));
+		assert!(!Allowances::<Test>::contains_key((0, 1, 2)));
+		assert!(TemplateModule::approvals_of(1).is_empty());
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn sweep_collects_deposit_sub_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let first = TemplateModule::derive_deposit_account(1, 0);
+		let second = TemplateModule::derive_deposit_account(1, 1);
+		assert_ne!(first, second);
+		assert_ne!(first, TemplateModule::derive_deposit_account(2, 0));
+
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(5), first, 60));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(5), second, 40));
+
+		// Sub-account 2 was never funded and is skipped.
+		let indices = vec![0, 1, 2].try_into().unwrap();
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(1), indices));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert!(!BalanceOf::<Test>::contains_key(0, first));
+		assert!(!BalanceOf::<Test>::contains_key(0, second));
+		assert_eq!(HolderCount::<Test>::get(), 1);
+		System::assert_has_event(Event::Swept { master: 1, index: 0, value: 60 }.into());
+		System::assert_last_event(Event::Swept { master: 1, index: 1, value: 40 }.into());
+	});
+}
+
+#[test]
+fn sweep_only_reaches_own_sub_accounts() {
+	new_test_ext().execute_with(|| {
+		let deposit = TemplateModule::derive_deposit_account(1, 0);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(5), deposit, 100));
+
+		// Account 2 sweeping index 0 derives its own, empty, sub-account.
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(2), vec![0].try_into().unwrap()));
+		assert_eq!(TemplateModule::get_balance_of(deposit), Some(100));
+		assert_eq!(TemplateModule::get_balance_of(2), None);
+	});
+}
+
+#[test]
+fn sweep_moves_each_balance_as_a_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let deposit = TemplateModule::derive_deposit_account(1, 0);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(5), deposit, 100));
+		let sweep = || TemplateModule::sweep(RuntimeOrigin::signed(1), vec![0].try_into().unwrap());
+
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), deposit));
+		assert_noop!(sweep(), Error::<Test>::AccountFrozen);
+		assert_ok!(TemplateModule::thaw_account(RuntimeOrigin::root(), deposit));
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 1));
+		assert_noop!(sweep(), Error::<Test>::AccountFrozen);
+		assert_ok!(TemplateModule::thaw_account(RuntimeOrigin::root(), 1));
+		VetoTransfers::set(true);
+		assert_noop!(sweep(), Error::<Test>::TransferVetoed);
+		VetoTransfers::set(false);
+
+		ObservedTransfers::set(vec![]);
+		assert_ok!(sweep());
+		assert_eq!(ObservedTransfers::get(), vec![(Some(deposit), Some(1), 100)]);
+		System::assert_has_event(Event::Transfer { from: deposit, to: 1, value: 100 }.into());
+		System::assert_last_event(Event::Swept { master: 1, index: 0, value: 100 }.into());
+		ObservedTransfers::set(vec![]);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn domain_separator_commits_to_genesis_hash() {
+	let separator_on = |genesis_hash| {
+		new_test_ext().execute_with(|| {
+			frame_system::BlockHash::<Test>::insert(0, genesis_hash);
+			run_to_block(1);
+			TemplateModule::domain_separator()
+		})
+	};
+	let here = separator_on(H256::repeat_byte(1));
+	assert_ne!(here, [0; 32]);
+	assert_eq!(here, separator_on(H256::repeat_byte(1)));
+	// A payload signed against another chain's separator can never match this one.
+	assert_ne!(here, separator_on(H256::repeat_byte(2)));
+}
+
+#[test]
+fn batch_approve_sets_every_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let approvals = vec![(2, 10), (3, 20)].try_into().unwrap();
+		assert_ok!(TemplateModule::batch_approve(RuntimeOrigin::signed(1), approvals));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 10), (3, 20)]);
+		System::assert_has_event(Event::Approval { from: 1, to: 2, value: 10 }.into());
+		System::assert_last_event(Event::Approval { from: 1, to: 3, value: 20 }.into());
+	});
+}
+
+#[test]
+fn batch_approve_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let duplicated = vec![(2, 10), (3, 20), (2, 30)].try_into().unwrap();
+		assert_noop!(
+			TemplateModule::batch_approve(RuntimeOrigin::signed(1), duplicated),
+			Error::<Test>::DuplicateSpender
+		);
+
+		// The fourth spender overflows the approval index, undoing the first three.
+		let too_many = vec![(2, 10), (3, 20), (4, 30), (5, 40)].try_into().unwrap();
+		assert_noop!(
+			TemplateModule::batch_approve(RuntimeOrigin::signed(1), too_many),
+			Error::<Test>::TooManyApprovals
+		);
+		assert!(TemplateModule::approvals_of(1).is_empty());
+	});
+}
+
+#[test]
+fn send_notifies_the_recipient_handler() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let data: BoundedVec<u8, _> = b"invoice 7".to_vec().try_into().unwrap();
+		let operator_data: BoundedVec<u8, _> = b"via app".to_vec().try_into().unwrap();
+		assert_ok!(TemplateModule::send(
+			RuntimeOrigin::signed(1),
+			2,
+			40,
+			data.clone(),
+			operator_data.clone()
+		));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(40));
+		assert_eq!(ReceivedTokens::get(), vec![(1, 2, 40, data.to_vec(), operator_data.to_vec())]);
+		System::assert_has_event(Event::Transfer { from: 1, to: 2, value: 40 }.into());
+		System::assert_last_event(
+			Event::Sent { from: 1, to: 2, value: 40, data, operator_data }.into(),
+		);
+	});
+}
+
+#[test]
+fn send_is_rolled_back_when_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		RejectTokens::set(true);
+		assert_noop!(
+			TemplateModule::send(
+				RuntimeOrigin::signed(1),
+				2,
+				40,
+				Default::default(),
+				Default::default()
+			),
+			DispatchError::Other("tokens rejected")
+		);
+	});
+}
+
+#[test]
+fn transfer_skips_the_recipient_handler() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		RejectTokens::set(true);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 40));
+		assert!(ReceivedTokens::get().is_empty());
+	});
+}
+
+#[test]
+fn token_only_account_survives_native_reaping() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 50));
+		System::inc_account_nonce(2);
+		assert_eq!(System::providers(&2), 2);
+
+		// Leaving native dust below the existential deposit reaps the native balance.
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 3, 95));
+		assert_eq!(Balances::free_balance(2), 0);
+		assert!(System::account_exists(&2));
+		assert_eq!(System::account_nonce(2), 1);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(50));
+
+		// Without tokens the same reaping wipes the account, nonce included.
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 4, 100));
+		System::inc_account_nonce(4);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), 3, 95));
+		assert!(!System::account_exists(&4));
+		assert_eq!(System::account_nonce(4), 0);
+	});
+}
+
+#[test]
+fn reaping_tokens_releases_the_provider() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_eq!(System::providers(&1), 1);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 60));
+		assert_eq!(System::providers(&2), 1);
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 40));
+		assert!(!System::account_exists(&1));
+		assert_eq!(System::providers(&2), 1);
+	});
+}
+
+#[test]
+fn reaping_tokens_fails_while_the_account_is_in_use() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(System::inc_consumers(&1));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100),
+			Error::<Test>::StillReferenced
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 99));
+	});
+}
+
+#[test]
+fn v2_migration_adds_providers() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<TemplateModule>();
+		pre_v5::BalanceOf::<Test>::insert(1, 100);
+		pre_v5::BalanceOf::<Test>::insert(2, 5);
+
+		migrations::v2::AddProviders::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+		assert_eq!(System::providers(&1), 1);
+		assert_eq!(System::providers(&2), 1);
+
+		// Running it again is a no-op.
+		migrations::v2::AddProviders::<Test>::on_runtime_upgrade();
+		assert_eq!(System::providers(&1), 1);
+	});
+}
+
+#[test]
+fn v3_migration_rekeys_allowances_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StorageVersion::new(2).put::<TemplateModule>();
+		for (owner, spender) in [(1, 2), (1, 3), (1, 4), (5, 2), (5, 3)] {
+			Allowance::<Test>::insert((owner, spender), 10);
+			Approvals::<Test>::mutate(owner, |spenders| spenders.try_push(spender).unwrap());
+		}
+
+		migrations::v3::RekeyAllowances::<Test>::on_runtime_upgrade();
+		assert_eq!(AllowanceRekey::<Test>::get(), Some(0));
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+
+		// Mid-migration, legacy entries are still readable.
+		assert_eq!(TemplateModule::approvals_of(5), vec![(2, 10), (3, 10)]);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Two entries per block, straight under the native asset.
+		run_to_block(2);
+		assert_eq!(Allowance::<Test>::iter_keys().count(), 3);
+		assert_eq!(AllowanceRekey::<Test>::get(), Some(2));
+
+		// A moved allowance can be spent before the rest follow.
+		let (_, owner, spender) = Allowances::<Test>::iter_keys().next().unwrap();
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), owner, 100));
+		assert_ok!(TemplateModule::transfer_from(
+			RuntimeOrigin::signed(spender),
+			owner,
+			spender,
+			4
+		));
+		assert_eq!(Allowances::<Test>::get((0, owner, spender)), Some(6));
+		assert_eq!(TemplateModule::get_balance_of(spender), Some(4));
+
+		run_to_block(3);
+		assert_eq!(Allowance::<Test>::iter_keys().count(), 1);
+
+		// The next block finds fewer than two left and finishes.
+		run_to_block(4);
+		assert_eq!(AllowanceRekey::<Test>::get(), None);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 3);
+		System::assert_has_event(Event::AllowancesRekeyed { moved: 5 }.into());
+		assert_eq!(Allowance::<Test>::iter_keys().count(), 0);
+		assert_eq!(Allowances::<Test>::iter_prefix((0,)).count(), 5);
+		for ((_, o, s), value) in Allowances::<Test>::iter() {
+			assert_eq!(value, if (o, s) == (owner, spender) { 6 } else { 10 });
+		}
+
+		// Running the upgrade again is a no-op.
+		migrations::v3::RekeyAllowances::<Test>::on_runtime_upgrade();
+		assert_eq!(AllowanceRekey::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn pay_from_pot_pays_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(
+			RuntimeOrigin::root(),
+			TreasuryPot::get(),
+			100
+		));
+
+		assert_ok!(<TreasuryPay as Pay>::pay(&7, (), 30));
+		assert_eq!(<TreasuryPay as Pay>::check_payment(()), PaymentStatus::Success);
+		assert_eq!(TemplateModule::get_balance_of(7), Some(30));
+		assert_eq!(TemplateModule::get_balance_of(TreasuryPot::get()), Some(70));
+
+		// A grant larger than the pot fails without moving anything.
+		assert_noop!(<TreasuryPay as Pay>::pay(&7, (), 71), Error::<Test>::FundsUnavailable);
+	});
+}
+
+#[test]
+fn block_reward_is_minted_every_block() {
+	new_test_ext().execute_with(|| {
+		BlockReward::set(10);
+		run_to_block(3);
+		assert_eq!(TemplateModule::get_total_supply(), Some(30));
+		assert_eq!(TemplateModule::get_balance_of(RewardPot::get()), Some(30));
+		System::assert_last_event(
+			Event::RewardMinted { beneficiary: RewardPot::get(), value: 10 }.into(),
+		);
+
+		let parameters = Parameters { block_reward: 100 };
+		assert_noop!(
+			TemplateModule::schedule_parameter_change(RuntimeOrigin::signed(1), parameters.clone()),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::schedule_parameter_change(RuntimeOrigin::root(), parameters));
+		run_to_block(6);
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+	});
+}
+
+#[test]
+fn parameter_changes_wait_out_the_delay() {
+	new_test_ext().execute_with(|| {
+		BlockReward::set(10);
+		System::set_block_number(1);
+		let parameters = Parameters { block_reward: 100 };
+		assert_ok!(TemplateModule::schedule_parameter_change(
+			RuntimeOrigin::root(),
+			parameters.clone()
+		));
+		System::assert_last_event(
+			Event::ParameterChangeScheduled { parameters: parameters.clone(), activates_at: 4 }
+				.into(),
+		);
+		assert_noop!(
+			TemplateModule::schedule_parameter_change(
+				RuntimeOrigin::root(),
+				Parameters { block_reward: 5 }
+			),
+			Error::<Test>::ParameterChangePending
+		);
+
+		// The old reward is minted right up to the activation block.
+		run_to_block(3);
+		assert_eq!(TemplateModule::parameters(), Parameters { block_reward: 10 });
+		assert_eq!(TemplateModule::get_balance_of(RewardPot::get()), Some(20));
+		run_to_block(4);
+		assert_eq!(TemplateModule::parameters(), parameters);
+		assert_eq!(TemplateModule::get_balance_of(RewardPot::get()), Some(120));
+		System::assert_has_event(Event::ParametersActivated { parameters }.into());
+		assert_eq!(TemplateModule::pending_parameters(), None);
+
+		// A change called off before its block never takes effect.
+		assert_ok!(TemplateModule::schedule_parameter_change(
+			RuntimeOrigin::root(),
+			Parameters { block_reward: 0 }
+		));
+		assert_noop!(
+			TemplateModule::cancel_parameter_change(RuntimeOrigin::signed(1)),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::cancel_parameter_change(RuntimeOrigin::root()));
+		System::assert_last_event(
+			Event::ParameterChangeCancelled { parameters: Parameters { block_reward: 0 } }.into(),
+		);
+		assert_noop!(
+			TemplateModule::cancel_parameter_change(RuntimeOrigin::root()),
+			Error::<Test>::NoParameterChange
+		);
+		run_to_block(10);
+		assert_eq!(TemplateModule::block_reward(), 100);
+	});
+}
+
+#[test]
+fn block_reward_stops_at_the_supply_cap() {
+	new_test_ext().execute_with(|| {
+		BlockReward::set(10);
+		TotalSupply::<Test>::insert(0, u64::MAX - 15);
+		run_to_block(1);
+		assert_eq!(TemplateModule::get_total_supply(), Some(u64::MAX - 5));
+
+		// Only what is left below the cap is minted, then nothing.
+		run_to_block(2);
+		assert_eq!(TemplateModule::get_total_supply(), Some(u64::MAX));
+		assert_eq!(TemplateModule::get_balance_of(RewardPot::get()), Some(15));
+		run_to_block(3);
+		assert_eq!(TemplateModule::get_total_supply(), Some(u64::MAX));
+	});
+}
+
+#[test]
+fn idle_balances_decay_into_the_pool() {
+	new_test_ext().execute_with(|| {
+		DemurrageRate::set(Perbill::from_percent(1));
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+
+		// Ten idle blocks at 1% per block.
+		run_to_block(11);
+		assert_eq!(TemplateModule::effective_balance(&1), 900);
+		assert_eq!(TemplateModule::balances_of(vec![1]), vec![900]);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(1_000));
+
+		// Moving tokens charges the decay first.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100));
+		System::assert_has_event(Event::DemurrageCharged { who: 1, value: 100 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(800));
+		assert_eq!(TemplateModule::get_balance_of(CommunityPool::get()), Some(100));
+
+		// 3% of 100 over three blocks; the pool itself does not decay.
+		run_to_block(14);
+		assert_eq!(TemplateModule::effective_balance(&2), 97);
+		assert_eq!(TemplateModule::effective_balance(&CommunityPool::get()), 100);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 97));
+		assert_eq!(TemplateModule::get_balance_of(2), None);
+		assert_eq!(TemplateModule::get_balance_of(CommunityPool::get()), Some(103));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn detailed_events_report_demurrage_and_resulting_balances() {
+	new_test_ext().execute_with(|| {
+		DetailedEvents::set(true);
+		DemurrageRate::set(Perbill::from_percent(1));
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 200));
+
+		// Both parties settle ten idle blocks before the move.
+		run_to_block(11);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100));
+		System::assert_has_event(
+			Event::TransferDetailed {
+				from: 1,
+				to: 2,
+				value: 100,
+				id: None,
+				demurrage: 110,
+				from_balance: 800,
+				to_balance: 190,
+			}
+			.into(),
+		);
+
+		run_to_block(21);
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(3), 1, 4));
+		System::assert_last_event(
+			Event::TransferFromDetailed {
+				from: 1,
+				to: 4,
+				value: 200,
+				demurrage: 80,
+				from_balance: 520,
+				to_balance: 200,
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::get_balance_of(CommunityPool::get()), Some(190));
+
+		// The lean layout is kept otherwise.
+		DetailedEvents::set(false);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 10));
+		System::assert_has_event(Event::Transfer { from: 2, to: 1, value: 10 }.into());
+	});
+}
+
+#[test]
+fn demurrage_rounds_down_and_caps_at_the_balance() {
+	new_test_ext().execute_with(|| {
+		DemurrageRate::set(Perbill::from_percent(1));
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 333));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 50));
+
+		run_to_block(4);
+		// 3% of 333 is 9.99.
+		assert_eq!(TemplateModule::effective_balance(&1), 324);
+
+		run_to_block(301);
+		assert_eq!(TemplateModule::effective_balance(&2), 0);
+	});
+}
+
+#[test]
+fn zero_demurrage_rate_tracks_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		run_to_block(50);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(900));
+		assert_eq!(LastActivity::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn staking_rewards_are_shared_by_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 300));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(2), 300));
+		assert_eq!(TemplateModule::total_staked(), 400);
+		// Staked tokens leave the free balance.
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 1),
+			Error::<Test>::AccountNotFound
+		);
+
+		// 400 reward tokens reach the pot and are split 1:3.
+		assert_ok!(TemplateModule::force_set_balance(
+			RuntimeOrigin::root(),
+			StakingPot::get(),
+			400
+		));
+		assert_eq!(TemplateModule::pending_rewards(&1), 100);
+		assert_eq!(TemplateModule::pending_rewards(&2), 300);
+
+		// Unstaking pays out what was earned so far.
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 100));
+		System::assert_has_event(Event::RewardPaid { who: 1, amount: 100 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+
+		// Later rewards go to the remaining stake only.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), StakingPot::get(), 60));
+		assert_eq!(TemplateModule::pending_rewards(&1), 0);
+		assert_eq!(TemplateModule::pending_rewards(&2), 360);
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(2), 0));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(360));
+		assert_eq!(TemplateModule::get_balance_of(StakingPot::get()), None);
+	});
+}
+
+#[test]
+fn unbonded_stake_is_withdrawable_after_the_unbonding_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 30));
+		System::assert_last_event(Event::Unbonded { who: 1, amount: 30, unlock_at: 11 }.into());
+		assert_noop!(
+			TemplateModule::unstake(RuntimeOrigin::signed(1), 71),
+			Error::<Test>::InsufficientStake
+		);
+
+		run_to_block(10);
+		assert_noop!(
+			TemplateModule::withdraw_unbonded(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToWithdraw
+		);
+		run_to_block(11);
+		assert_ok!(TemplateModule::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(30));
+
+		// Unstaking the rest empties the ledger once withdrawn.
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 70));
+		run_to_block(21);
+		assert_ok!(TemplateModule::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert!(!Ledgers::<Test>::contains_key(1));
+		assert_eq!(System::providers(&1), 1);
+	});
+}
+
+#[test]
+fn unbonding_chunks_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 1));
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 1));
+		assert_noop!(
+			TemplateModule::unstake(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::TooManyUnbondingChunks
+		);
+	});
+}
+
+#[test]
+fn slashes_take_stake_before_free_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 150));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 40));
+
+		// Active stake first, then the unbonding chunk; the free balance is left alone.
+		assert_ok!(Offences::report(RuntimeOrigin::root(), 1, 80));
+		assert_eq!(Ledgers::<Test>::get(1).unwrap().active, 0);
+		assert_eq!(Ledgers::<Test>::get(1).unwrap().unlocking.to_vec(), vec![(11, 20)]);
+		assert_ok!(Offences::report(RuntimeOrigin::root(), 1, 80));
+		assert!(!Ledgers::<Test>::contains_key(1));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(50));
+		assert_eq!(TemplateModule::get_total_supply(), Some(50));
+		assert_eq!(SlashedTokens::get(), vec![(1, 80), (1, 20)]);
+		System::assert_last_event(Event::Slashed { who: 1, amount: 20 }.into());
+
+		SlashFreeBalance::set(true);
+		assert_ok!(Offences::report(RuntimeOrigin::root(), 1, 30));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(20));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn slashes_keep_reward_accounting_correct() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(2), 100));
+		assert_ok!(TemplateModule::force_set_balance(
+			RuntimeOrigin::root(),
+			StakingPot::get(),
+			100
+		));
+
+		// Rewards earned before the slash are paid on the old stake.
+		assert_ok!(Offences::report(RuntimeOrigin::root(), 1, 50));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(50));
+		assert_eq!(TemplateModule::total_staked(), 150);
+
+		// Afterwards account 1 earns on 50 and account 2 on 100.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), StakingPot::get(), 30));
+		assert_eq!(TemplateModule::pending_rewards(&1), 10);
+		assert_eq!(TemplateModule::pending_rewards(&2), 70);
+	});
+}
+
+#[test]
+fn bridge_locks_and_releases_through_the_pot() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let dest: BoundedVec<u8, _> = b"0xdest".to_vec().try_into().unwrap();
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, dest.clone(), 60));
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, dest.clone(), 10));
+		System::assert_last_event(
+			Event::BridgedOut { nonce: 1, from: 1, dest_chain: 7, dest_address: dest, amount: 10 }
+				.into(),
+		);
+		assert_eq!(TemplateModule::outbound_nonce(), 2);
+		assert_eq!(TemplateModule::get_balance_of(BridgePot::get()), Some(70));
+		assert_eq!(TemplateModule::get_total_supply(), Some(100));
+
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::signed(1), 0, 2, 50),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 2, 50));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(50));
+		assert_eq!(TemplateModule::get_balance_of(BridgePot::get()), Some(20));
+		// A replayed nonce is refused even though the pot could cover it.
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 2, 20),
+			Error::<Test>::InboundNonceProcessed
+		);
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::root(), 1, 2, 21),
+			Error::<Test>::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn bridge_burns_and_mints_when_configured() {
+	new_test_ext().execute_with(|| {
+		BridgeBurns::set(true);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, Default::default(), 60));
+		assert_eq!(TemplateModule::get_total_supply(), Some(40));
+		assert_eq!(TemplateModule::get_balance_of(BridgePot::get()), None);
+
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 5, 2, 25));
+		assert_eq!(TemplateModule::get_total_supply(), Some(65));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(25));
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::root(), 5, 2, 25),
+			Error::<Test>::InboundNonceProcessed
+		);
+	});
+}
+
+fn relayers(ids: &[u64]) -> BoundedVec<u64, ConstU32<3>> {
+	ids.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn set_relayers_checks_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::set_relayers(RuntimeOrigin::signed(1), relayers(&[1, 2]), 1),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2]), 0),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2]), 3),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 1]), 2),
+			Error::<Test>::DuplicateRelayer
+		);
+		assert_ok!(TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2, 3]), 2));
+		assert_eq!(TemplateModule::relayers().into_inner(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn relayer_votes_release_at_threshold() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BridgeBurns::set(true);
+		assert_ok!(TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2, 3]), 2));
+
+		assert_noop!(
+			TemplateModule::vote_bridge_in(RuntimeOrigin::signed(4), 0, 5, 50),
+			Error::<Test>::NotRelayer
+		);
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(1), 0, 5, 50));
+		assert_eq!(TemplateModule::get_balance_of(5), None);
+		assert_noop!(
+			TemplateModule::vote_bridge_in(RuntimeOrigin::signed(1), 0, 5, 50),
+			Error::<Test>::DuplicateVote
+		);
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(2), 0, 5, 50));
+		System::assert_last_event(Event::BridgedIn { nonce: 0, to: 5, amount: 50 }.into());
+		assert_eq!(TemplateModule::get_balance_of(5), Some(50));
+		assert_noop!(
+			TemplateModule::vote_bridge_in(RuntimeOrigin::signed(3), 0, 5, 50),
+			Error::<Test>::InboundNonceProcessed
+		);
+	});
+}
+
+#[test]
+fn conflicting_relayer_vote_freezes_nonce() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BridgeBurns::set(true);
+		assert_ok!(TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2, 3]), 2));
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(1), 0, 5, 50));
+		// A compromised relayer names a larger amount: nothing is released and the nonce
+		// stays frozen even for honest votes.
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(2), 0, 5, 5_000));
+		System::assert_last_event(Event::InboundConflict { nonce: 0, relayer: 2 }.into());
+		assert_noop!(
+			TemplateModule::vote_bridge_in(RuntimeOrigin::signed(3), 0, 5, 50),
+			Error::<Test>::InboundNonceFrozen
+		);
+		assert_eq!(TemplateModule::get_balance_of(5), None);
+
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 5, 50));
+		assert_eq!(TemplateModule::get_balance_of(5), Some(50));
+		assert_eq!(TemplateModule::get_total_supply(), Some(50));
+	});
+}
+
+#[test]
+fn removed_relayer_votes_stop_counting() {
+	new_test_ext().execute_with(|| {
+		BridgeBurns::set(true);
+		assert_ok!(TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[1, 2, 3]), 2));
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(1), 0, 5, 50));
+		assert_ok!(TemplateModule::set_relayers(RuntimeOrigin::root(), relayers(&[2, 3]), 2));
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(2), 0, 5, 50));
+		assert_eq!(TemplateModule::get_balance_of(5), None);
+		assert_ok!(TemplateModule::vote_bridge_in(RuntimeOrigin::signed(3), 0, 5, 50));
+		assert_eq!(TemplateModule::get_balance_of(5), Some(50));
+	});
+}
+
+#[test]
+fn strict_recipient_only_accepts_allowed_senders() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		// Allowing a sender before opting in has no effect until strict mode is on.
+		assert_ok!(TemplateModule::allow_sender(RuntimeOrigin::signed(3), 1));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 10));
+
+		assert_ok!(TemplateModule::accept_incoming(RuntimeOrigin::signed(3), false));
+		System::assert_last_event(Event::IncomingPolicySet { who: 3, accept_all: false }.into());
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 10));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 10),
+			Error::<Test>::RecipientNotAccepting
+		);
+
+		// Switching strict mode off and on again keeps the allow-list.
+		assert_ok!(TemplateModule::accept_incoming(RuntimeOrigin::signed(3), true));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 10));
+		assert_ok!(TemplateModule::accept_incoming(RuntimeOrigin::signed(3), false));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 10));
+
+		assert_ok!(TemplateModule::disallow_sender(RuntimeOrigin::signed(3), 1));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 10),
+			Error::<Test>::RecipientNotAccepting
+		);
+		assert_eq!(TemplateModule::get_balance_of(3), Some(40));
+	});
+}
+
+#[test]
+fn strict_recipient_refuses_transfer_from_and_mints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 50, None));
+		assert_ok!(TemplateModule::accept_incoming(RuntimeOrigin::signed(2), false));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10),
+			Error::<Test>::RecipientNotAccepting
+		);
+		assert_ok!(TemplateModule::allow_sender(RuntimeOrigin::signed(2), 1));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10));
+
+		// Minted tokens have no sender to allow.
+		BridgeBurns::set(true);
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 2, 10),
+			Error::<Test>::RecipientNotAccepting
+		);
+	});
+}
+
+#[test]
+fn payment_request_is_paid_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let memo: BoundedVec<u8, _> = b"invoice 7".to_vec().try_into().unwrap();
+		assert_ok!(TemplateModule::request_payment(RuntimeOrigin::signed(2), 1, 30, memo.clone()));
+		System::assert_last_event(
+			Event::PaymentRequested { id: 0, requester: 2, payer: 1, amount: 30, memo }.into(),
+		);
+
+		assert_noop!(
+			TemplateModule::accept_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::UnknownRequest
+		);
+		assert_ok!(TemplateModule::accept_request(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::PaymentRequestAccepted { id: 0, payer: 1 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+		assert_noop!(
+			TemplateModule::accept_request(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::UnknownRequest
+		);
+		assert!(TemplateModule::payment_requests(1).is_empty());
+	});
+}
+
+#[test]
+fn payment_requests_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(2),
+			1,
+			30,
+			Default::default()
+		));
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(3),
+			1,
+			30,
+			Default::default()
+		));
+		assert_noop!(
+			TemplateModule::request_payment(RuntimeOrigin::signed(4), 1, 30, Default::default()),
+			Error::<Test>::TooManyRequests
+		);
+
+		// Open up to and including block 1 + RequestTtl.
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::accept_request(RuntimeOrigin::signed(1), 0));
+		System::set_block_number(7);
+		assert_noop!(
+			TemplateModule::accept_request(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::RequestExpired
+		);
+		// The expired request no longer takes up a slot.
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(4),
+			1,
+			30,
+			Default::default()
+		));
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(5),
+			1,
+			30,
+			Default::default()
+		));
+		assert_eq!(TemplateModule::payment_requests(1).len(), 2);
+	});
+}
+
+#[test]
+fn payment_requests_can_be_rejected_or_cancelled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(2),
+			1,
+			30,
+			Default::default()
+		));
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(3),
+			1,
+			30,
+			Default::default()
+		));
+		assert_ok!(TemplateModule::reject_request(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::PaymentRequestRejected { id: 0, payer: 1 }.into());
+
+		assert_noop!(
+			TemplateModule::cancel_request(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::UnknownRequest
+		);
+		assert_ok!(TemplateModule::cancel_request(RuntimeOrigin::signed(3), 1, 1));
+		System::assert_last_event(Event::PaymentRequestCancelled { id: 1, payer: 1 }.into());
+		assert_noop!(
+			TemplateModule::accept_request(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::UnknownRequest
+		);
+	});
+}
+
+#[test]
+fn subscription_refills_every_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_noop!(
+			TemplateModule::approve_subscription(RuntimeOrigin::signed(1), 2, 50, 0),
+			Error::<Test>::ZeroPeriod
+		);
+		assert_ok!(TemplateModule::approve_subscription(RuntimeOrigin::signed(1), 2, 50, 10));
+
+		// Blocks 1 to 10 make up the first window.
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 30));
+		System::set_block_number(10);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 20));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 50));
+
+		// Skipping windows lands in the one holding the current block, here 31 to 40.
+		System::set_block_number(35);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 50));
+		System::set_block_number(40);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_eq!(TemplateModule::subscription((1, 2)).unwrap().window_start, 31);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(150));
+	});
+}
+
+#[test]
+fn cancelled_subscription_falls_back_to_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 20, None));
+		assert_ok!(TemplateModule::approve_subscription(RuntimeOrigin::signed(1), 2, 50, 10));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 40));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(20));
+
+		assert_ok!(TemplateModule::cancel_subscription(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::SubscriptionCancelled { owner: 1, spender: 2 }.into());
+		assert_noop!(
+			TemplateModule::cancel_subscription(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NoSubscription
+		);
+		// The rest of the window is gone; only the regular allowance is left.
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 21),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 20));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), None);
+	});
+}
+
+#[test]
+fn transfer_limit_caps_outflow_per_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_noop!(
+			TemplateModule::set_transfer_limit(RuntimeOrigin::signed(1), 1, 50, 10),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::set_transfer_limit(RuntimeOrigin::root(), 1, 50, 10));
+		System::assert_last_event(
+			Event::TransferLimitSet { who: 1, max_per_period: 50, period: 10 }.into(),
+		);
+
+		// Exactly the limit goes through, one more unit does not.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, Default::default(), 20));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::TransferLimitExceeded
+		);
+		// Incoming transfers are not limited.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 100));
+
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::TransferLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn transfer_limit_covers_transfer_from_and_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 100, None));
+		assert_ok!(TemplateModule::set_transfer_limit(RuntimeOrigin::root(), 1, 50, 10));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 51),
+			Error::<Test>::TransferLimitExceeded
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 50));
+
+		assert_ok!(TemplateModule::clear_transfer_limit(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::TransferLimitCleared { who: 1 }.into());
+		assert_noop!(
+			TemplateModule::clear_transfer_limit(RuntimeOrigin::root(), 1),
+			Error::<Test>::NoTransferLimit
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 50));
+		assert_eq!(TemplateModule::transfer_limit(1), None);
+	});
+}
+
+#[test]
+fn minting_is_rate_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		BridgeBurns::set(true);
+		MaxMintPerBlock::set(100);
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 1, 60));
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 1, 2, 40));
+		assert_noop!(
+			TemplateModule::bridge_in(RuntimeOrigin::root(), 2, 2, 1),
+			Error::<Test>::MintRateExceeded
+		);
+
+		run_to_block(2);
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 2, 2, 100));
+		assert_eq!(TemplateModule::get_total_supply(), Some(200));
+	});
+}
+
+#[test]
+fn block_reward_does_not_count_against_mint_rate() {
+	new_test_ext().execute_with(|| {
+		BridgeBurns::set(true);
+		MaxMintPerBlock::set(100);
+		BlockReward::set(500);
+		run_to_block(1);
+		assert_ok!(TemplateModule::bridge_in(RuntimeOrigin::root(), 0, 1, 100));
+		assert_eq!(TemplateModule::get_total_supply(), Some(600));
+	});
+}
+
+#[test]
+fn small_mints_are_immediate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::signed(1), 1, 10),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 1_000));
+		System::assert_last_event(Event::Minted { to: 1, amount: 1_000 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(1_000));
+		assert_eq!(TemplateModule::get_total_supply(), Some(1_000));
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 1, 1_001),
+			Error::<Test>::MintNeedsAnnouncement
+		);
+	});
+}
+
+#[test]
+fn admin_burns_take_tokens_out_of_the_supply() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::burn(RuntimeOrigin::signed(1), 1, 10),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::burn(RuntimeOrigin::root(), 1, 101),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 70));
+		assert_noop!(TemplateModule::burn(RuntimeOrigin::root(), 1, 31), Error::<Test>::Frozen);
+
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 1, 30));
+		System::assert_last_event(Event::Burned { from: 1, value: 30 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+		assert_eq!(TemplateModule::get_total_supply(), Some(70));
+		assert_eq!(TemplateModule::total_burned(), 30);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn announced_mint_waits_out_the_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::announce_mint(RuntimeOrigin::root(), 1, 5_000));
+		System::assert_last_event(
+			Event::MintAnnounced { id: 0, to: 1, amount: 5_000, executable_at: 6 }.into(),
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			TemplateModule::execute_mint(RuntimeOrigin::root(), 0),
+			Error::<Test>::MintNotDue
+		);
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::execute_mint(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::MintExecuted { id: 0, to: 1, amount: 5_000 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(5_000));
+		assert_noop!(
+			TemplateModule::execute_mint(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnknownMint
+		);
+	});
+}
+
+#[test]
+fn announced_mints_are_bounded_and_cancellable() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::announce_mint(RuntimeOrigin::root(), 1, 5_000));
+		assert_ok!(TemplateModule::announce_mint(RuntimeOrigin::root(), 2, 5_000));
+		assert_noop!(
+			TemplateModule::announce_mint(RuntimeOrigin::root(), 3, 5_000),
+			Error::<Test>::TooManyAnnouncedMints
+		);
+
+		assert_ok!(TemplateModule::cancel_mint(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::MintCancelled { id: 0 }.into());
+		System::set_block_number(6);
+		assert_noop!(
+			TemplateModule::execute_mint(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnknownMint
+		);
+		assert_ok!(TemplateModule::announce_mint(RuntimeOrigin::root(), 3, 5_000));
+		assert_eq!(
+			TemplateModule::announced_mints().iter().map(|mint| mint.id).collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+	});
+}
+
+#[cfg(feature = "testnet-faucet")]
+#[test]
+fn faucet_drips_once_per_cooldown() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::faucet(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::FaucetDrip { who: 1, amount: 50 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(50));
+
+		// Other accounts have their own cooldown.
+		assert_ok!(TemplateModule::faucet(RuntimeOrigin::signed(2)));
+		System::set_block_number(10);
+		assert_noop!(
+			TemplateModule::faucet(RuntimeOrigin::signed(1)),
+			Error::<Test>::FaucetCooldownActive
+		);
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::faucet(RuntimeOrigin::signed(1)));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+
+		// The supply cap still applies.
+		TotalSupply::<Test>::insert(0, u64::MAX - 49);
+		assert_noop!(TemplateModule::faucet(RuntimeOrigin::signed(3)), Error::<Test>::Overflow);
+	});
+}
+
+#[test]
+fn every_burn_adds_to_total_burned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 200));
+		assert_eq!(TemplateModule::total_burned(), 0);
+
+		BridgeBurns::set(true);
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, Default::default(), 60));
+		assert_eq!(TemplateModule::total_burned(), 60);
+
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 100));
+		assert_ok!(Offences::report(RuntimeOrigin::root(), 1, 30));
+		assert_eq!(TemplateModule::total_burned(), 90);
+		assert_eq!(TemplateModule::get_total_supply(), Some(110));
+
+		// Releasing locked bridge tokens destroys nothing.
+		BridgeBurns::set(false);
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, Default::default(), 10));
+		assert_eq!(TemplateModule::total_burned(), 90);
+	});
+}
+
+#[test]
+fn transfers_to_the_burn_address_destroy_supply() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BurnAddress::set(Some(94));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 200));
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 94, 50));
+		System::assert_last_event(Event::Burned { from: 1, value: 50 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(150));
+		assert_eq!(TemplateModule::get_balance_of(94), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+		assert_eq!(TemplateModule::total_burned(), 50);
+
+		// Allowances spent towards it burn as well.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 30));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 94, 30));
+		System::assert_last_event(Event::Burned { from: 1, value: 30 }.into());
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 20));
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 94));
+		System::assert_last_event(Event::Burned { from: 1, value: 20 }.into());
+		assert_eq!(TemplateModule::get_balance_of(94), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(100));
+		assert_eq!(TemplateModule::total_burned(), 100);
+
+		// Whatever reaches it otherwise stays put.
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 94, 10));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(94), 1, 10),
+			Error::<Test>::BurnAddressCannotSend
+		);
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(94), 2, 0, 10));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 94, 2, 10),
+			Error::<Test>::BurnAddressCannotSend
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn circulating_supply_leaves_out_excluded_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 500));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), TreasuryPot::get(), 300));
+		assert_eq!(TemplateModule::circulating_supply(), 800);
+
+		assert_ok!(TemplateModule::exclude_from_circulating(
+			RuntimeOrigin::root(),
+			TreasuryPot::get()
+		));
+		System::assert_last_event(
+			Event::ExcludedFromCirculating { who: TreasuryPot::get() }.into(),
+		);
+		assert_eq!(TemplateModule::circulating_supply(), 500);
+		assert_noop!(
+			TemplateModule::exclude_from_circulating(RuntimeOrigin::root(), TreasuryPot::get()),
+			Error::<Test>::AlreadyExcluded
+		);
+
+		// Funds moving in or out of an excluded account move the figure.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), TreasuryPot::get(), 100));
+		assert_eq!(TemplateModule::circulating_supply(), 400);
+		assert_ok!(TreasuryPay::pay(&2, (), 250));
+		assert_eq!(TemplateModule::circulating_supply(), 650);
+
+		assert_ok!(TemplateModule::include_in_circulating(
+			RuntimeOrigin::root(),
+			TreasuryPot::get()
+		));
+		System::assert_last_event(Event::IncludedInCirculating { who: TreasuryPot::get() }.into());
+		assert_eq!(TemplateModule::circulating_supply(), 800);
+		assert_noop!(
+			TemplateModule::include_in_circulating(RuntimeOrigin::root(), TreasuryPot::get()),
+			Error::<Test>::NotExcluded
+		);
+	});
+}
+
+#[test]
+fn excluded_accounts_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::exclude_from_circulating(RuntimeOrigin::signed(1), 1),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TemplateModule::exclude_from_circulating(RuntimeOrigin::root(), 1));
+		assert_ok!(TemplateModule::exclude_from_circulating(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			TemplateModule::exclude_from_circulating(RuntimeOrigin::root(), 3),
+			Error::<Test>::TooManyExcluded
+		);
+	});
+}
+
+#[test]
+fn redenomination_scales_every_amount() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_metadata(
+			RuntimeOrigin::root(),
+			BoundedVec::default(),
+			BoundedVec::default(),
+			6
+		));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 500));
+		assert_ok!(TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), Some(10)));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 100, None));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 200));
+		assert_ok!(TemplateModule::unstake(RuntimeOrigin::signed(1), 50));
+		assert_ok!(TemplateModule::approve_subscription(RuntimeOrigin::signed(1), 3, 40, 10));
+		assert_ok!(TemplateModule::set_max_supply(RuntimeOrigin::root(), 1_000));
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 2, 60));
+		assert_ok!(TemplateModule::reserve_named(b"deposit_", &1, 20));
+		assert_ok!(TemplateModule::set_claims_root(RuntimeOrigin::root(), [0; 32], 175));
+		assert_ok!(TemplateModule::delegate_allowance(RuntimeOrigin::signed(2), 1, 3, 30));
+		assert_ok!(TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 4, 10, 2, 5));
+		for reserves in [400, 600] {
+			assert_ok!(TemplateModule::post_reserve_attestation(
+				RuntimeOrigin::root(),
+				reserves,
+				1,
+				BoundedVec::default()
+			));
+		}
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 9, 5, 20));
+		assert_ok!(TemplateModule::create_asset(RuntimeOrigin::root(), 9, 5, 1));
+		assert_ok!(TemplateModule::mint_asset(RuntimeOrigin::signed(5), 9, 1, 10));
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 2, 10));
+
+		migrations::redenomination::Redenominate::<Test, ConstU64<1_000_000>>::on_runtime_upgrade();
+		System::assert_last_event(Event::RedenominationComplete { factor: 1_000_000 }.into());
+		// Launched tokens keep their amounts.
+		assert_eq!(TemplateModule::asset_balance(9, &1), 10);
+		assert_eq!(TemplateModule::asset_supply(9), 10);
+		assert_eq!(Allowances::<Test>::get((9, 1, 2)), Some(10));
+		assert_eq!(TemplateModule::max_supply(), Some(1_000_000_000));
+		assert_eq!(TemplateModule::metadata().decimals, 12);
+		assert_eq!(TemplateModule::claims_left(), 175_000_000);
+		assert_eq!(
+			TemplateModule::latest_reserve_attestation().map(|a| a.reserves),
+			Some(600_000_000)
+		);
+		assert_eq!(TemplateModule::reserve_attestation_history()[0].reserves, 400_000_000);
+		assert_eq!(Attestations::<Test>::get((1, 9, 5_000_000)), Some(20));
+		assert_eq!(Attestations::<Test>::get((1, 9, 5)), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(500_000_000));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(170_000_000));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(100_000_000));
+		assert_eq!(TemplateModule::frozen_balance(2), Some(60_000_000));
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(11, 100_000_000)]);
+		let schedule = VestingSchedules::<Test>::get(4)[0].clone();
+		assert_eq!((schedule.locked, schedule.per_block), (10_000_000, 2_000_000));
+		assert_eq!(ReservedBalance::<Test>::get(1), Some(20_000_000));
+		assert_eq!(TemplateModule::reserved_balance_named(b"deposit_", &1), 20_000_000);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(100_000_000));
+		assert_eq!(Delegations::<Test>::get(1, 2).to_vec(), vec![(3, 30_000_000)]);
+		let ledger = Ledgers::<Test>::get(1).unwrap();
+		assert_eq!(ledger.active, 150_000_000);
+		assert_eq!(ledger.unlocking.to_vec(), vec![(11, 50_000_000)]);
+		assert_eq!(TemplateModule::total_staked(), 150_000_000);
+		assert_eq!(TemplateModule::subscription((1, 3)).unwrap().limit, 40_000_000);
+		assert_ok!(TemplateModule::do_try_state());
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 1_000));
+	});
+}
+
+#[test]
+fn redenomination_that_overflows_changes_nothing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 500));
+		// Any one amount that would overflow stops the whole redenomination.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, u64::MAX / 10));
+		assert_ok!(TemplateModule::set_max_supply(RuntimeOrigin::root(), u64::MAX));
+		System::reset_events();
+
+		migrations::redenomination::Redenominate::<Test, ConstU64<1_000_000>>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::get_total_supply(), Some(500 + u64::MAX / 10));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(500));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(u64::MAX / 10));
+		assert_eq!(TemplateModule::max_supply(), Some(u64::MAX));
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn redenomination_needs_a_power_of_ten() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 500));
+		System::reset_events();
+
+		migrations::redenomination::Redenominate::<Test, ConstU64<1_500>>::on_runtime_upgrade();
+		migrations::redenomination::Redenominate::<Test, ConstU64<0>>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::get_total_supply(), Some(500));
+		assert_eq!(TemplateModule::metadata().decimals, 0);
+		assert!(System::events().is_empty());
+
+		// A factor of one keeps both the amounts and the decimals.
+		migrations::redenomination::Redenominate::<Test, ConstU64<1>>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::get_total_supply(), Some(500));
+		assert_eq!(TemplateModule::metadata().decimals, 0);
+	});
+}
+
+#[test]
+fn redenomination_scales_what_was_sent_in_the_kyc_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		KycTiers::set(vec![(1, KycTier::Basic)]);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+
+		migrations::redenomination::Redenominate::<Test, ConstU64<1_000_000>>::on_runtime_upgrade();
+		assert_eq!(KycOutflow::<Test>::get(1), Some((1, 30_000_000)));
+	});
+}
+
+#[test]
+fn small_transfers_are_fee_less_up_to_the_quota() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FreeTransfersPerPeriod::set(2);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let pays =
+			|value| TemplateModule::transfer(RuntimeOrigin::signed(1), 2, value).unwrap().pays_fee;
+
+		// Over the value cap is never free and does not use up the quota.
+		assert_eq!(pays(11), Pays::Yes);
+		assert_eq!(pays(10), Pays::No);
+		assert_eq!(pays(1), Pays::No);
+		assert_eq!(pays(1), Pays::Yes);
+
+		// Blocks 1 to 10 make up the first window.
+		System::set_block_number(10);
+		assert_eq!(pays(1), Pays::Yes);
+		System::set_block_number(11);
+		assert_eq!(pays(1), Pays::No);
+		// Quotas are per account.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 3, 100));
+		assert_eq!(
+			TemplateModule::transfer(RuntimeOrigin::signed(3), 2, 1).unwrap().pays_fee,
+			Pays::No
+		);
+	});
+}
+
+#[test]
+fn fee_less_transfers_can_be_turned_off() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let info = TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+	});
+}
+
+#[test]
+fn allowance_deposit_is_reserved_and_returned() {
+	new_test_ext().execute_with(|| {
+		AllowanceDeposit::set(5);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10),
+			Error::<Test>::CannotReserveDeposit
+		);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+
+		// Spent down to zero by the spender.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// Changing an existing allowance takes no further deposit.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 10, 20));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 20));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Revoked by the owner.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 10));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 10, 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Created and cleared in a batch.
+		let batch: BoundedVec<_, _> = vec![(4, 10), (5, 10)].try_into().unwrap();
+		assert_ok!(TemplateModule::batch_approve(RuntimeOrigin::signed(1), batch));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		let batch: BoundedVec<_, _> = vec![(4, 0), (5, 0)].try_into().unwrap();
+		assert_ok!(TemplateModule::batch_approve(RuntimeOrigin::signed(1), batch));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn allowances_from_before_deposits_release_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		AllowanceDeposit::set(5);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(Balances::reserve(&1, 7));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 10, 0));
+		assert_eq!(Balances::reserved_balance(1), 7);
+	});
+}
+
+#[test]
+fn migrate_account_moves_the_whole_position() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AllowanceDeposit::set(5);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 9, 100));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 4, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 20));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(4), 1, 0, 30));
+		assert_ok!(TemplateModule::stake(RuntimeOrigin::signed(1), 40));
+		assert_ok!(TemplateModule::set_transfer_limit(RuntimeOrigin::root(), 1, 50, 10));
+
+		// Someone who already holds tokens cannot be migrated into.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 8, 1));
+		assert_noop!(
+			TemplateModule::migrate_account(RuntimeOrigin::signed(1), 8, Default::default()),
+			Error::<Test>::DestinationNotEmpty
+		);
+
+		let approvers: BoundedVec<_, _> = vec![4].try_into().unwrap();
+		assert_ok!(TemplateModule::migrate_account(RuntimeOrigin::signed(1), 9, approvers));
+		System::assert_last_event(Event::AccountMigrated { old: 1, new: 9 }.into());
+
+		assert_eq!(TemplateModule::get_balance_of(9), Some(60));
+		assert_eq!(TemplateModule::approvals_of(9), vec![(2, 10), (3, 20)]);
+		assert_eq!(TemplateModule::approvals_of(4), vec![(9, 30)]);
+		assert_eq!(Ledgers::<Test>::get(9).map(|ledger| ledger.active), Some(40));
+		assert!(TemplateModule::transfer_limit(9).is_some());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(9), 10);
+		assert_eq!(Balances::reserved_balance(4), 5);
+
+		// Nothing is left under the old key.
+		assert!(!BalanceOf::<Test>::contains_key(0, 1));
+		assert!(!Approvals::<Test>::contains_key(1));
+		assert_eq!(Allowances::<Test>::iter_prefix((0, 1)).count(), 0);
+		assert!(!Allowances::<Test>::contains_key((0, 4, 1)));
+		assert!(!Ledgers::<Test>::contains_key(1));
+		assert!(TemplateModule::transfer_limit(1).is_none());
+		assert!(!LastActivity::<Test>::contains_key(1));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn scoped_allowances_are_isolated_by_purpose() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let subscriptions = *b"subscrib";
+		let purchases = *b"purchase";
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, subscriptions, 10));
+		System::assert_last_event(
+			Event::ScopedApproval { owner: 1, spender: 2, purpose: subscriptions, value: 10 }
+				.into(),
+		);
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, purchases, 30));
+
+		// Neither purpose can draw on the other, nor on the plain allowance.
+		assert_noop!(
+			TemplateModule::transfer_from_scoped(RuntimeOrigin::signed(2), 1, subscriptions, 3, 11),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 1),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_ok!(TemplateModule::transfer_from_scoped(
+			RuntimeOrigin::signed(2),
+			1,
+			purchases,
+			3,
+			30
+		));
+		System::assert_last_event(
+			Event::ScopedTransfer { owner: 1, spender: 2, purpose: purchases, to: 3, value: 30 }
+				.into(),
+		);
+		assert_eq!(TemplateModule::get_balance_of(3), Some(30));
+		assert_ok!(TemplateModule::transfer_from_scoped(
+			RuntimeOrigin::signed(2),
+			1,
+			subscriptions,
+			3,
+			10
+		));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(60));
+		assert!(!ScopedApprovalCount::<Test>::contains_key(1));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn default_purpose_is_the_plain_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_scoped(
+			RuntimeOrigin::signed(1),
+			2,
+			DEFAULT_PURPOSE,
+			10
+		));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 10)]);
+		assert_ok!(TemplateModule::transfer_from_scoped(
+			RuntimeOrigin::signed(2),
+			1,
+			DEFAULT_PURPOSE,
+			3,
+			4
+		));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(2, 6)]);
+		assert!(!ScopedApprovalCount::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn scoped_allowances_count_against_the_owner_bound() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 1));
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, [1; 8], 1));
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 3, [1; 8], 1));
+		assert_noop!(
+			TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 3, [2; 8], 1),
+			Error::<Test>::TooManyApprovals
+		);
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 4, 0, 1),
+			Error::<Test>::TooManyApprovals
+		);
+		// Updating an existing scoped entry still works at the bound.
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 3, [1; 8], 5));
+
+		// Revoking a scoped entry frees a slot for a plain one.
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, [1; 8], 0));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 4, 0, 1));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn spent_by_survives_allowance_renewals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10));
+		assert!(TemplateModule::approvals_of(1).is_empty());
+		assert_eq!(TemplateModule::spent_by(1, 2), 10);
+
+		// Renewed, then pulled through every spending path.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 5));
+		assert_ok!(TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 3));
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, [1; 8], 7));
+		assert_ok!(TemplateModule::transfer_from_scoped(RuntimeOrigin::signed(2), 1, [1; 8], 3, 7));
+		assert_eq!(TemplateModule::spent_by(1, 2), 22);
+		assert_eq!(TemplateModule::spent_by(1, 3), 0);
+	});
+}
+
+#[test]
+fn spent_by_is_dropped_with_the_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 5, 100));
+		for spender in 2..=3 {
+			assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), spender, 0, 10));
+			assert_ok!(TemplateModule::transfer_from(
+				RuntimeOrigin::signed(spender),
+				1,
+				spender,
+				10
+			));
+			assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(5), spender, 0, 10));
+			assert_ok!(TemplateModule::transfer_from(
+				RuntimeOrigin::signed(spender),
+				5,
+				spender,
+				10
+			));
+		}
+
+		assert_ok!(TemplateModule::migrate_account(
+			RuntimeOrigin::signed(1),
+			9,
+			Default::default()
+		));
+		assert_eq!(TemplateModule::spent_by(1, 2), 0);
+		assert_eq!(TemplateModule::spent_by(9, 2), 0);
+
+		// Reaping keeps the history until someone prunes it.
+		assert_noop!(
+			TemplateModule::prune_spent_by(RuntimeOrigin::signed(4), 5),
+			Error::<Test>::OwnerNotReaped
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(5), 4, 80));
+		assert_eq!(TemplateModule::spent_by(5, 3), 10);
+		assert_ok!(TemplateModule::prune_spent_by(RuntimeOrigin::signed(4), 5));
+		assert_eq!(TemplateModule::spent_by(5, 2), 0);
+		assert_eq!(TemplateModule::spent_by(5, 3), 0);
+	});
+}
+
+#[test]
+fn large_transfers_need_an_attestation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AttestationThreshold::set(50);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 200));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 49));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50),
+			Error::<Test>::AttestationRequired
+		);
+		assert_noop!(
+			TemplateModule::attest_transfer(RuntimeOrigin::signed(1), 1, 2, 50, 5),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 50, 5));
+		System::assert_last_event(
+			Event::TransferAttested { from: 1, to: 2, value: 50, expiry: 5 }.into(),
+		);
+		// The ticket covers only the exact transfer.
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 51),
+			Error::<Test>::AttestationRequired
+		);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 50),
+			Error::<Test>::AttestationRequired
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50),
+			Error::<Test>::AttestationRequired
+		);
+	});
+}
+
+#[test]
+fn attestations_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		AttestationThreshold::set(50);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 200));
+		assert_noop!(
+			TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 50, 4),
+			Error::<Test>::AttestationExpired
+		);
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 50, 5));
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 60, 5));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50));
+
+		System::set_block_number(6);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 60),
+			Error::<Test>::AttestationRequired
+		);
+	});
+}
+
+#[test]
+fn attestations_apply_to_spenders() {
+	new_test_ext().execute_with(|| {
+		AttestationThreshold::set(50);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 200));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 100));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 60),
+			Error::<Test>::AttestationRequired
+		);
+		assert_noop!(
+			TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 3),
+			Error::<Test>::AttestationRequired
+		);
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 60, 1));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 60));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(60));
+	});
+}
+
+#[test]
+fn kyc_tiers_limit_senders() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		KycTiers::set(vec![(2, KycTier::Basic), (3, KycTier::None)]);
+		for who in 1..=3 {
+			assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), who, 200));
+		}
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 4, 150));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(3), 4, 1),
+			Error::<Test>::KycLimitExceeded { tier: KycTier::None }
+		);
+		// Receiving is not limited.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 10));
+
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 4, 51),
+			Error::<Test>::KycLimitExceeded { tier: KycTier::Basic }
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 4, 50));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 4, 30));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 4, 1),
+			Error::<Test>::KycLimitExceeded { tier: KycTier::Basic }
+		);
+
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 4, 50));
+	});
+}
+
+#[test]
+fn kyc_tiers_limit_owners_in_transfer_from() {
+	new_test_ext().execute_with(|| {
+		KycTiers::set(vec![(2, KycTier::Basic)]);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 200));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 1, 0, 100));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(1), 2, 1, 60),
+			Error::<Test>::KycLimitExceeded { tier: KycTier::Basic }
+		);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(1), 2, 1, 50));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(50));
+	});
+}
+
+#[test]
+fn multicall_runs_dependent_actions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 1, 0, 50));
+		let calls = vec![
+			// Account 1 holds nothing until it pulls from 2.
+			TokenAction::TransferFrom { owner: 2, to: 1, value: 50 },
+			TokenAction::Transfer { to: 3, value: 20 },
+			TokenAction::Approve { spender: 4, value: 30 },
+		];
+		assert_ok!(TemplateModule::multicall(RuntimeOrigin::signed(1), calls.try_into().unwrap()));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(30));
+		assert_eq!(TemplateModule::get_balance_of(3), Some(20));
+		assert_eq!(Allowances::<Test>::get((0, 2, 1)), None);
+		assert_eq!(Allowances::<Test>::get((0, 1, 4)), Some(30));
+		assert_eq!(TemplateModule::spent_by(2, 1), 50);
+		System::assert_last_event(Event::Approval { from: 1, to: 4, value: 30 }.into());
+
+		// 4 spends the allowance 1 just granted it, then passes the tokens on.
+		let calls = vec![
+			TokenAction::TransferFrom { owner: 1, to: 4, value: 30 },
+			TokenAction::Transfer { to: 5, value: 30 },
+		];
+		assert_ok!(TemplateModule::multicall(RuntimeOrigin::signed(4), calls.try_into().unwrap()));
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::get_balance_of(5), Some(30));
+	});
+}
+
+#[test]
+fn multicall_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let calls = vec![
+			TokenAction::Transfer { to: 2, value: 60 },
+			TokenAction::Approve { spender: 3, value: 10 },
+			TokenAction::Transfer { to: 2, value: 60 },
+			TokenAction::Transfer { to: 2, value: 1 },
+		];
+		// Only the actions up to the failing one are paid for.
+		let used = SubstrateWeight::<Test>::transfer() * 2 + SubstrateWeight::<Test>::approve();
+		assert_noop!(
+			TemplateModule::multicall(RuntimeOrigin::signed(1), calls.try_into().unwrap()),
+			Error::<Test>::MulticallFailed { index: 2 }.with_weight(used)
+		);
+		assert_eq!(Allowances::<Test>::get((0, 1, 3)), None);
+	});
+}
+
+#[test]
+fn errors_fit_in_a_module_error() {
+	assert!(
+		<Error<Test> as PalletError>::MAX_ENCODED_SIZE <= sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE
+	);
+	assert!(<Test as Config>::MaxBatchSize::get() <= u16::MAX as u32);
+}
+
+#[test]
+fn movements_are_indexed_offchain() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		IndexTransfers::set(true);
+		BridgeBurns::set(true);
+		System::set_block_number(2);
+		System::reset_events();
+
+		System::set_extrinsic_index(1);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		System::set_extrinsic_index(2);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 5));
+		assert_ok!(TemplateModule::bridge_out(RuntimeOrigin::signed(2), 7, Default::default(), 10));
+	});
+	ext.persist_offchain_overlay();
+
+	let db = ext.offchain_db();
+	let key = [TRANSFER_RECORD_PREFIX, &2u64.encode(), &1u32.encode(), &0u32.encode()].concat();
+	assert_eq!(key, TemplateModule::transfer_record_key(2, 1, 0));
+	assert_eq!(db.get(&key), Some((Some(1u64), Some(2u64), 30u64).encode()));
+	// Several movements in one extrinsic are told apart by the block's event count.
+	assert_eq!(
+		db.get(&TemplateModule::transfer_record_key(2, 2, 1)),
+		Some(TransferRecord { from: None, to: Some(1), value: 5 }.encode())
+	);
+	assert_eq!(
+		db.get(&TemplateModule::transfer_record_key(2, 2, 2)),
+		Some(TransferRecord { from: Some(2), to: None, value: 10 }.encode())
+	);
+}
+
+#[test]
+fn movements_are_not_indexed_by_default() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		System::set_extrinsic_index(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		System::reset_events();
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+	});
+	ext.persist_offchain_overlay();
+
+	assert_eq!(ext.offchain_db().get(&TemplateModule::transfer_record_key(1, 1, 0)), None);
+}
+
+// `simulate_transfer`, leaving storage untouched.
+fn simulate(from: u64, to: u64, value: u64) -> Result<(), Erc20Error> {
+	let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+	let simulated = TemplateModule::simulate_transfer(from, to, value);
+	assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+	simulated
+}
+
+fn assert_simulates_ok(from: u64, to: u64, value: u64) {
+	assert_eq!(simulate(from, to, value), Ok(()));
+	assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(from), to, value));
+}
+
+fn assert_simulates_err(from: u64, to: u64, value: u64, error: impl Fn() -> Error<Test>) {
+	assert_eq!(simulate(from, to, value), Err(error().into()));
+	assert_noop!(TemplateModule::transfer(RuntimeOrigin::signed(from), to, value), error());
+}
+
+#[test]
+fn simulate_transfer_matches_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_simulates_err(1, 2, 101, || Error::<Test>::FundsUnavailable);
+		assert_simulates_err(5, 2, 1, || Error::<Test>::AccountNotFound);
+
+		assert_ok!(TemplateModule::accept_incoming(RuntimeOrigin::signed(3), false));
+		assert_simulates_err(1, 3, 10, || Error::<Test>::RecipientNotAccepting);
+
+		assert_ok!(TemplateModule::set_transfer_limit(RuntimeOrigin::root(), 1, 20, 10));
+		assert_simulates_err(1, 2, 21, || Error::<Test>::TransferLimitExceeded);
+		assert_simulates_ok(1, 2, 20);
+		assert_simulates_err(1, 2, 1, || Error::<Test>::TransferLimitExceeded);
+		assert_ok!(TemplateModule::clear_transfer_limit(RuntimeOrigin::root(), 1));
+
+		AttestationThreshold::set(50);
+		assert_simulates_err(1, 2, 50, || Error::<Test>::AttestationRequired);
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), 1, 2, 50, 1));
+		// Simulating leaves the ticket for the real transfer.
+		assert_simulates_ok(1, 2, 50);
+		AttestationThreshold::set(u64::MAX);
+
+		KycTiers::set(vec![(1, KycTier::Basic)]);
+		assert_simulates_err(1, 2, 51, || Error::<Test>::KycLimitExceeded { tier: KycTier::Basic });
+	})
+}
+
+#[test]
+fn erc20_error_encoding_is_stable() {
+	assert_eq!(Erc20Error::InsufficientFunds.encode(), vec![0]);
+	assert_eq!(Erc20Error::KycLimitExceeded { tier: KycTier::Basic }.encode(), vec![4, 1]);
+	assert_eq!(Erc20Error::Overflow.encode(), vec![6]);
+	assert_eq!(Erc20Error::TooManyHolders.encode(), vec![10]);
+	assert_eq!(Erc20Error::Other.encode(), vec![255]);
+	assert_eq!(Erc20Error::from(Error::<Test>::ZeroPeriod), Erc20Error::Other);
+}
+
+#[test]
+fn unresolvable_addresses_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), UNKNOWN_ADDRESS, 10),
+			Error::<Test>::UnknownAddress
+		);
+		assert_noop!(
+			TemplateModule::approve(RuntimeOrigin::signed(1), UNKNOWN_ADDRESS, 10, None),
+			Error::<Test>::UnknownAddress
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), UNKNOWN_ADDRESS, 2, 10),
+			Error::<Test>::UnknownAddress
+		);
+	});
+}
+
+#[test]
+fn frozen_amount_is_a_spending_floor() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::freeze_amount(RuntimeOrigin::signed(1), 1, 30),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 30));
+		System::assert_last_event(Event::AmountFrozen { who: 1, amount: 30, frozen: 30 }.into());
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 10));
+		System::assert_last_event(Event::AmountFrozen { who: 1, amount: 10, frozen: 40 }.into());
+
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 61),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 60));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::Frozen
+		);
+		// Spenders and staking are held to the same floor, and receiving is not affected.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 10));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 1),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(TemplateModule::stake(RuntimeOrigin::signed(1), 1), Error::<Test>::Frozen);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 5));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 5));
+
+		assert_noop!(
+			TemplateModule::unfreeze_amount(RuntimeOrigin::root(), 1, 41),
+			Error::<Test>::NotEnoughFrozen
+		);
+		assert_ok!(TemplateModule::unfreeze_amount(RuntimeOrigin::root(), 1, 10));
+		System::assert_last_event(Event::AmountUnfrozen { who: 1, amount: 10, frozen: 30 }.into());
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::unfreeze_amount(RuntimeOrigin::root(), 1, 30));
+		assert_eq!(TemplateModule::frozen_balance(1), None);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+	});
+}
+
+#[test]
+fn frozen_amount_stays_with_the_tokens() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		// More than the balance can be frozen; the whole balance is then immobile.
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 150));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::unfreeze_amount(RuntimeOrigin::root(), 1, 100));
+
+		assert_ok!(TemplateModule::migrate_account(
+			RuntimeOrigin::signed(1),
+			9,
+			Default::default()
+		));
+		assert_eq!(TemplateModule::frozen_balance(9), Some(50));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(9), 2, 51),
+			Error::<Test>::Frozen
+		);
+
+		// Sweeping leaves the frozen part in the deposit sub-account.
+		let deposit = TemplateModule::derive_deposit_account(9, 0);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(9), deposit, 50));
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), deposit, 20));
+		assert_ok!(TemplateModule::sweep(RuntimeOrigin::signed(9), vec![0].try_into().unwrap()));
+		assert_eq!(TemplateModule::get_balance_of(deposit), Some(20));
+		assert_eq!(TemplateModule::get_balance_of(9), Some(80));
+	});
+}
+
+#[test]
+fn savings_mode_locks_each_credit_until_it_matures() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 200));
+		assert_noop!(
+			TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), Some(0)),
+			Error::<Test>::ZeroPeriod
+		);
+		assert_ok!(TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), Some(10)));
+		System::assert_last_event(Event::SavingsModeSet { who: 2, lock_blocks: Some(10) }.into());
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		System::assert_has_event(Event::SavingsLocked { who: 2, value: 30, until: 11 }.into());
+		run_to_block(5);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 20));
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(11, 30), (15, 20)]);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			TemplateModule::release_matured(RuntimeOrigin::signed(2)),
+			Error::<Test>::NoMaturedSavings
+		);
+
+		// Only the first tranche has matured.
+		run_to_block(11);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 31),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 30));
+		assert_ok!(TemplateModule::release_matured(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::SavingsReleased { who: 2, value: 30 }.into());
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(15, 20)]);
+
+		// Credits in the same block share a tranche, and once `MaxSavingTranches` are pending
+		// the latest one takes the rest.
+		run_to_block(12);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 5));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 5));
+		run_to_block(13);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 5));
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(15, 20), (22, 10), (23, 5)]);
+		run_to_block(14);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 5));
+		System::assert_has_event(Event::SavingsLocked { who: 2, value: 5, until: 24 }.into());
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(15, 20), (22, 10), (24, 10)]);
+		// A matured tranche makes room for a new one.
+		run_to_block(15);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 5));
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(22, 10), (24, 10), (25, 5)]);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 20));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn leaving_savings_mode_keeps_pending_tranches_locked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), Some(10)));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 50));
+
+		assert_ok!(TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), None));
+		System::assert_last_event(Event::SavingsModeSet { who: 2, lock_blocks: None }.into());
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 20));
+		assert_eq!(SavingTranches::<Test>::get(2).into_inner(), vec![(11, 50)]);
+		// Spenders and staking are held to the locked tranches too.
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 3, 0, 30));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 2, 3, 21),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(TemplateModule::stake(RuntimeOrigin::signed(2), 21), Error::<Test>::Frozen);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 20));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+
+		run_to_block(11);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 50));
+		assert_ok!(TemplateModule::release_matured(RuntimeOrigin::signed(2)));
+		assert!(!SavingTranches::<Test>::contains_key(2));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn reserves_round_trip_through_the_reserved_bucket() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let supply = TemplateModule::total_issuance();
+		let providers = System::providers(&1);
+
+		assert_ok!(TemplateModule::reserve(&1, 30));
+		System::assert_last_event(Event::Reserved { who: 1, amount: 30 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+		assert_eq!(TemplateModule::reserved_balance(&1), 30);
+		assert_eq!(TemplateModule::total_balance(&1), 100);
+		assert_eq!(TemplateModule::total_issuance(), supply);
+
+		// No transfer path reaches the reserve.
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 71),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 100));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 3, 71),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_noop!(TemplateModule::reserve(&1, 71), Error::<Test>::FundsUnavailable);
+
+		// An account with everything reserved is kept alive by the reserve.
+		assert_ok!(TemplateModule::reserve(&1, 70));
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(System::providers(&1), providers);
+
+		assert_eq!(TemplateModule::unreserve(&1, 120), 20);
+		System::assert_last_event(Event::Unreserved { who: 1, amount: 100 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(ReservedBalance::<Test>::get(1), None);
+		assert_eq!(System::providers(&1), providers);
+
+		// Frozen tokens cannot be reserved.
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 80));
+		assert!(!TemplateModule::can_reserve(&1, 21));
+		assert_noop!(TemplateModule::reserve(&1, 21), Error::<Test>::Frozen);
+		assert!(TemplateModule::can_reserve(&1, 20));
+		assert_ok!(TemplateModule::reserve(&1, 20));
+	});
+}
+
+#[test]
+fn reserves_repatriate_to_free_or_reserved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 10));
+		let supply = TemplateModule::total_issuance();
+		assert_ok!(TemplateModule::reserve(&1, 50));
+
+		assert_eq!(TemplateModule::repatriate_reserved(&1, &2, 20, BalanceStatus::Free), Ok(0));
+		System::assert_last_event(
+			Event::ReserveRepatriated {
+				from: 1,
+				to: 2,
+				amount: 20,
+				destination_status: BalanceStatus::Free,
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+		assert_eq!(TemplateModule::reserved_balance(&1), 30);
+
+		assert_eq!(
+			TemplateModule::repatriate_reserved(&1, &2, 40, BalanceStatus::Reserved),
+			Ok(10)
+		);
+		assert_eq!(TemplateModule::reserved_balance(&1), 0);
+		assert_eq!(TemplateModule::reserved_balance(&2), 30);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+
+		// Repatriating to oneself unreserves.
+		assert_eq!(TemplateModule::repatriate_reserved(&2, &2, 10, BalanceStatus::Free), Ok(0));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(40));
+		assert_eq!(TemplateModule::total_issuance(), supply);
+	});
+}
+
+#[test]
+fn slashed_reserves_leave_the_supply() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let supply = TemplateModule::total_issuance();
+		assert_ok!(TemplateModule::reserve(&1, 50));
+
+		let (imbalance, remaining) = TemplateModule::slash_reserved(&1, 60);
+		assert_eq!((imbalance.peek(), remaining), (50, 10));
+		System::assert_last_event(Event::Slashed { who: 1, amount: 50 }.into());
+		assert_eq!(TemplateModule::total_issuance(), supply);
+		drop(imbalance);
+		assert_eq!(TemplateModule::total_issuance(), supply - 50);
+		assert_eq!(TemplateModule::reserved_balance(&1), 0);
+
+		// A plain slash takes the balance before the reserve.
+		assert_ok!(TemplateModule::reserve(&1, 20));
+		let (imbalance, remaining) = TemplateModule::slash(&1, 40);
+		assert_eq!((imbalance.peek(), remaining), (40, 0));
+		drop(imbalance);
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::reserved_balance(&1), 10);
+		assert_eq!(TemplateModule::total_issuance(), supply - 90);
+		assert_eq!(SlashedTokens::get(), vec![(1, 50), (1, 40)]);
+	});
+}
+
+#[test]
+fn currency_imbalances_settle_the_supply() {
+	new_test_ext().execute_with(|| {
+		let supply = TemplateModule::total_issuance();
+		drop(TemplateModule::deposit_creating(&1, 25));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(25));
+		assert_eq!(TemplateModule::total_issuance(), supply + 25);
+		assert!(TemplateModule::deposit_into_existing(&2, 5).is_err());
+
+		let imbalance = TemplateModule::withdraw(
+			&1,
+			5,
+			WithdrawReasons::all(),
+			ExistenceRequirement::AllowDeath,
+		)
+		.unwrap();
+		drop(imbalance);
+		assert_eq!(TemplateModule::total_issuance(), supply + 20);
+
+		// Moving tokens between accounts through imbalances leaves the supply as it was.
+		let taken = TemplateModule::withdraw(
+			&1,
+			20,
+			WithdrawReasons::all(),
+			ExistenceRequirement::AllowDeath,
+		)
+		.unwrap();
+		TemplateModule::resolve_creating(&2, taken);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(20));
+		assert_eq!(TemplateModule::total_issuance(), supply + 20);
+	});
+}
+
+#[test]
+fn named_reserves_round_trip() {
+	new_test_ext().execute_with(|| {
+		const BOND: [u8; 8] = *b"bond____";
+		const DEPOSIT: [u8; 8] = *b"deposit_";
+		const LOTTERY: [u8; 8] = *b"lottery_";
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+
+		assert_ok!(TemplateModule::reserve_named(&DEPOSIT, &1, 20));
+		assert_ok!(TemplateModule::reserve_named(&BOND, &1, 30));
+		assert_eq!(TemplateModule::reserved_balance(&1), 50);
+		assert_eq!(TemplateModule::reserved_balance_named(&DEPOSIT, &1), 20);
+		assert_noop!(
+			TemplateModule::reserve_named(&LOTTERY, &1, 1),
+			Error::<Test>::TooManyReserves
+		);
+		assert_noop!(
+			TemplateModule::reserve_named(&DEPOSIT, &1, 51),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::reserve_named(&DEPOSIT, &1, 10));
+
+		assert_eq!(TemplateModule::unreserve_named(&DEPOSIT, &1, 40), 10);
+		assert_eq!(TemplateModule::reserved_balance_named(&DEPOSIT, &1), 0);
+		assert_eq!(TemplateModule::reserved_balance(&1), 30);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+
+		assert_eq!(
+			TemplateModule::repatriate_reserved_named(&BOND, &1, &2, 10, BalanceStatus::Reserved),
+			Ok(0)
+		);
+		assert_eq!(TemplateModule::reserved_balance_named(&BOND, &1), 20);
+		assert_eq!(TemplateModule::reserved_balance_named(&BOND, &2), 10);
+
+		let (imbalance, remaining) = TemplateModule::slash_reserved_named(&BOND, &1, 50);
+		assert_eq!((imbalance.peek(), remaining), (20, 30));
+		drop(imbalance);
+		assert_eq!(NamedReserves::<Test>::get(1), None);
+		assert_eq!(ReservedBalance::<Test>::get(1), None);
+
+		// Reserves move with a migrated account.
+		assert_ok!(TemplateModule::migrate_account(
+			RuntimeOrigin::signed(2),
+			9,
+			Default::default()
+		));
+		assert_eq!(TemplateModule::reserved_balance(&9), 10);
+		assert_eq!(TemplateModule::reserved_balance_named(&BOND, &9), 10);
+		assert_eq!(NamedReserves::<Test>::get(2), None);
+	});
+}
+
+#[test]
+fn fungible_traits_take_the_token_paths() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let supply = <TemplateModule as fungible::Inspect<u64>>::total_issuance();
+
+		assert_eq!(
+			<TemplateModule as fungible::Mutate<u64>>::transfer(
+				&1,
+				&2,
+				30,
+				Preservation::Expendable
+			),
+			Ok(30)
+		);
+		System::assert_last_event(Event::Transfer { from: 1, to: 2, value: 30 }.into());
+		assert_eq!(<TemplateModule as fungible::Inspect<u64>>::balance(&2), 30);
+
+		assert_ok!(<TemplateModule as fungible::Mutate<u64>>::mint_into(&3, 20));
+		assert_eq!(<TemplateModule as fungible::Inspect<u64>>::total_issuance(), supply + 20);
+		assert_eq!(
+			<TemplateModule as fungible::Mutate<u64>>::burn_from(
+				&3,
+				25,
+				Precision::BestEffort,
+				Fortitude::Polite
+			),
+			Ok(20)
+		);
+		assert_eq!(<TemplateModule as fungible::Inspect<u64>>::total_issuance(), supply);
+		assert_eq!(TemplateModule::total_burned(), 20);
+
+		// Frozen tokens are only reducible by force.
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 50));
+		let reducible = |force| {
+			<TemplateModule as fungible::Inspect<u64>>::reducible_balance(
+				&1,
+				Preservation::Expendable,
+				force,
+			)
+		};
+		assert_eq!((reducible(Fortitude::Polite), reducible(Fortitude::Force)), (20, 70));
+		assert_noop!(
+			<TemplateModule as fungible::Mutate<u64>>::burn_from(
+				&1,
+				30,
+				Precision::Exact,
+				Fortitude::Polite
+			),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn fungible_holds_are_named_reserves() {
+	new_test_ext().execute_with(|| {
+		const BOND: [u8; 8] = *b"bond____";
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let supply = TemplateModule::total_issuance();
+
+		assert_ok!(<TemplateModule as fungible::hold::Mutate<u64>>::hold(&BOND, &1, 40));
+		System::assert_last_event(Event::Reserved { who: 1, amount: 40 }.into());
+		assert_eq!(TemplateModule::reserved_balance_named(&BOND, &1), 40);
+		assert_eq!(<TemplateModule as fungible::hold::Inspect<u64>>::total_balance_on_hold(&1), 40);
+
+		assert_noop!(
+			<TemplateModule as fungible::hold::Mutate<u64>>::release(
+				&BOND,
+				&1,
+				50,
+				Precision::Exact
+			),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_eq!(
+			<TemplateModule as fungible::hold::Mutate<u64>>::release(
+				&BOND,
+				&1,
+				10,
+				Precision::Exact
+			),
+			Ok(10)
+		);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+
+		// Burning held tokens takes them out of the reserve and the supply.
+		assert_eq!(
+			<TemplateModule as fungible::hold::Mutate<u64>>::burn_held(
+				&BOND,
+				&1,
+				50,
+				Precision::BestEffort,
+				Fortitude::Polite
+			),
+			Ok(30)
+		);
+		assert_eq!(NamedReserves::<Test>::get(1), None);
+		assert_eq!(ReservedBalance::<Test>::get(1), None);
+		assert_eq!(TemplateModule::total_issuance(), supply - 30);
+		assert_eq!(TemplateModule::total_burned(), 30);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn holders_above_the_threshold_are_members() {
+	new_test_ext().execute_with(|| {
+		assert!(!RegistryMembers::contains(&1));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 60));
+		assert!(RegistryMembers::contains(&1));
+
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10));
+		assert!(RegistryMembers::contains(&1));
+		assert!(!RegistryMembers::contains(&2));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 1));
+		assert!(!RegistryMembers::contains(&1));
+
+		// Frozen tokens count, reserved ones do not.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 11));
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 1, 60));
+		assert!(RegistryMembers::contains(&1));
+		assert_ok!(TemplateModule::unfreeze_amount(RuntimeOrigin::root(), 1, 60));
+		assert_ok!(TemplateModule::reserve(&1, 11));
+		assert!(!RegistryMembers::contains(&1));
+		assert_eq!(TemplateModule::unreserve(&1, 11), 0);
+		assert!(RegistryMembers::contains(&1));
+
+		MembershipThreshold::set(0);
+		assert!(RegistryMembers::contains(&3));
+	});
+}
+
+#[test]
+fn movements_outside_dispatch_roll_back_on_error() {
+	new_test_ext().execute_with(|| {
+		let pot = TreasuryPot::get();
+		AttestationThreshold::set(50);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), pot, 100));
+		assert_ok!(TemplateModule::attest_transfer(RuntimeOrigin::root(), pot, 7, 60, 1));
+
+		// The KYC check fails after the ticket is used up; the ticket comes back.
+		KycTiers::set(vec![(pot, KycTier::None)]);
+		assert_noop!(
+			<TreasuryPay as Pay>::pay(&7, (), 60),
+			Error::<Test>::KycLimitExceeded { tier: KycTier::None }
+		);
+		KycTiers::set(vec![]);
+		assert_ok!(<TreasuryPay as Pay>::pay(&7, (), 60));
+		assert_noop!(<TreasuryPay as Pay>::pay(&7, (), 60), Error::<Test>::AttestationRequired);
+
+		// Settling demurrage is undone when the withdrawal it precedes fails.
+		DemurrageRate::set(Perbill::from_percent(1));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		System::set_block_number(10);
+		assert_noop!(
+			TemplateModule::withdraw(
+				&1,
+				100,
+				WithdrawReasons::all(),
+				ExistenceRequirement::AllowDeath
+			),
+			Error::<Test>::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn max_holders_caps_new_accounts() {
+	new_test_ext().execute_with(|| {
+		MaxHolders::set(Some(2));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 100));
+
+		assert_noop!(
+			TemplateModule::force_set_balance(RuntimeOrigin::root(), 3, 10),
+			Error::<Test>::TooManyHolders
+		);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 3, 10),
+			Error::<Test>::TooManyHolders
+		);
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 3, 10),
+			Error::<Test>::TooManyHolders
+		);
+		assert_eq!(TemplateModule::simulate_transfer(1, 3, 10), Err(Erc20Error::TooManyHolders));
+		// Existing holders still receive, and moving a whole balance keeps the count.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 110));
+		assert_eq!(HolderCount::<Test>::get(), 2);
+
+		// A reaped account frees its place right away.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(3), 1, 110));
+		assert_eq!(HolderCount::<Test>::get(), 1);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 4, 10));
+		assert_eq!(HolderCount::<Test>::get(), 2);
+
+		MaxHolders::set(None);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 5, 10));
+	});
+}
+
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+	let mut bytes = [0u8; N];
+	for (i, byte) in bytes.iter_mut().enumerate() {
+		*byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+	}
+	bytes
+}
+
+// A snapshot of three leaves: `(ALICE, 100)`, `(BOB, 50)` and `(0xcc.., 25)`. The addresses
+// are those of the secp256k1 keys `keccak256("alice")` and `keccak256("bob")`, and the
+// signatures are their `personal_sign` of accounts 1 and 2.
+struct ClaimsFixture {
+	root: [u8; 32],
+	alice: [u8; 20],
+	bob: [u8; 20],
+	alice_proof: BoundedVec<[u8; 32], ConstU32<8>>,
+	bob_proof: BoundedVec<[u8; 32], ConstU32<8>>,
+	alice_signs_1: [u8; 65],
+	bob_signs_2: [u8; 65],
+}
+
+fn claims_fixture() -> ClaimsFixture {
+	let alice = hex("328809bc894f92807417d2dad6b7c998c1afdac6");
+	let bob = hex("1d96f2f6bef1202e4ce1ff6dad0c2cb002861d3e");
+	let alice_leaf = hex("f9f5d903026e23861d396d74286d9a508b24c8aab57dd73496c931035bbaedce");
+	let bob_leaf = hex("91cb57b6f89c158681be740db29c3adb77e6201c48e110ae05da7328b6f5ef38");
+	let third_leaf = hex("baa9bf5d6b3cf49796cd9cff81db6117d4c5d596affd36ab524c796f21b024c5");
+	assert_eq!(TemplateModule::claim_leaf(&alice, 100), alice_leaf);
+	assert_eq!(TemplateModule::claim_leaf(&bob, 50), bob_leaf);
+	assert_eq!(TemplateModule::claim_leaf(&[0xcc; 20], 25), third_leaf);
+	ClaimsFixture {
+		root: hex("1e0125fbae0586bb79116149f92cb4a412cbfb26d17fd05248274d565c094f76"),
+		alice,
+		bob,
+		alice_proof: vec![bob_leaf, third_leaf].try_into().unwrap(),
+		bob_proof: vec![alice_leaf, third_leaf].try_into().unwrap(),
+		alice_signs_1: hex("2eef87502663dbd246d6bfd9c4d5dce39ce879a968f8f35690cc894736525c18\
+			 46f31b29094766340494fd2f629b5f418324659adbefa21a4bfd7f836a531e271b"),
+		bob_signs_2: hex("4dfd775128f343e280d613f6ab8c6b734dce733c1c583c76c6cf65e7698d6ec7\
+			 3738dea7598cafa69fc8e684634771931e1fd92c13b61799279ae1809bcdb4821c"),
+	}
+}
+
+#[test]
+fn claims_mint_each_snapshot_leaf_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let f = claims_fixture();
+		let mut message = b"\x19Ethereum Signed Message:\n8".to_vec();
+		message.extend(1u64.encode());
+		assert_eq!(TemplateModule::claim_message(&1), message);
+
+		assert_noop!(
+			TemplateModule::set_claims_root(RuntimeOrigin::signed(1), f.root, 175),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::set_claims_root(RuntimeOrigin::root(), f.root, 175));
+		System::assert_last_event(Event::ClaimsRootSet { root: f.root, total: 175 }.into());
+
+		assert_ok!(TemplateModule::claim(
+			RuntimeOrigin::signed(1),
+			f.alice,
+			100,
+			f.alice_proof.clone(),
+			f.alice_signs_1
+		));
+		System::assert_last_event(
+			Event::Claimed { who: 1, eth_address: f.alice, amount: 100 }.into(),
+		);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(TemplateModule::get_total_supply(), Some(100));
+		assert_eq!(TemplateModule::claims_left(), 75);
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(1),
+				f.alice,
+				100,
+				f.alice_proof,
+				f.alice_signs_1
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// Bob's signature is over account 2 and only lets account 2 claim his leaf, and only
+		// for the amount the snapshot holds.
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(3),
+				f.bob,
+				50,
+				f.bob_proof.clone(),
+				f.bob_signs_2
+			),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(2),
+				f.bob,
+				60,
+				f.bob_proof.clone(),
+				f.bob_signs_2
+			),
+			Error::<Test>::InvalidClaimProof
+		);
+		assert_ok!(TemplateModule::claim(
+			RuntimeOrigin::signed(2),
+			f.bob,
+			50,
+			f.bob_proof,
+			f.bob_signs_2
+		));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(50));
+		assert_eq!(TemplateModule::claims_left(), 25);
+	});
+}
+
+#[test]
+fn claims_need_a_root_and_stay_within_its_total() {
+	new_test_ext().execute_with(|| {
+		let f = claims_fixture();
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(1),
+				f.alice,
+				100,
+				f.alice_proof.clone(),
+				f.alice_signs_1
+			),
+			Error::<Test>::InvalidClaimProof
+		);
+
+		assert_ok!(TemplateModule::set_claims_root(RuntimeOrigin::root(), f.root, 120));
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(1),
+				f.alice,
+				100,
+				f.alice_proof.clone(),
+				[0; 65]
+			),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		assert_ok!(TemplateModule::claim(
+			RuntimeOrigin::signed(1),
+			f.alice,
+			100,
+			f.alice_proof,
+			f.alice_signs_1
+		));
+		assert_noop!(
+			TemplateModule::claim(RuntimeOrigin::signed(2), f.bob, 50, f.bob_proof, f.bob_signs_2),
+			Error::<Test>::ClaimsExhausted
+		);
+	});
+}
+
+// Registers `key` as `who`'s and checks that `sign` makes signatures that verify over a
+// message's signing payload, plain and `<Bytes>`-wrapped, while `wrong_key` of the same scheme
+// and `other_scheme` over the same payload are rejected.
+fn assert_scheme_verifies(
+	who: u64,
+	key: MultiSigner,
+	sign: impl Fn(&[u8]) -> MultiSignature,
+	wrong_key: impl Fn(&[u8]) -> MultiSignature,
+	other_scheme: impl Fn(&[u8]) -> MultiSignature,
+) {
+	SigningKeys::set(vec![(who, key)]);
+	let message = (who, 2u64, 100u64).encode();
+	let payload = TemplateModule::signing_payload(&message);
+	let wrapped = [&b"<Bytes>"[..], &payload, &b"</Bytes>"[..]].concat();
+	let verify =
+		|signature| TemplateModule::verify_payload(&who, &message, &MockSignature(signature));
+
+	assert!(verify(sign(&payload)));
+	assert!(verify(sign(&wrapped)));
+	assert!(!verify(sign(&message)));
+	assert!(!verify(wrong_key(&payload)));
+	assert!(!verify(other_scheme(&payload)));
+	assert!(!TemplateModule::verify_payload(&(who + 1), &message, &MockSignature(sign(&payload))));
+}
+
+#[test]
+fn sr25519_payload_signatures_verify() {
+	new_test_ext().execute_with(|| {
+		let pair = sr25519::Pair::from_seed(&[1; 32]);
+		let other = sr25519::Pair::from_seed(&[2; 32]);
+		let ed = ed25519::Pair::from_seed(&[1; 32]);
+		assert_scheme_verifies(
+			1,
+			pair.public().into(),
+			|payload| pair.sign(payload).into(),
+			|payload| other.sign(payload).into(),
+			|payload| ed.sign(payload).into(),
+		);
+	});
+}
+
+#[test]
+fn ed25519_payload_signatures_verify() {
+	new_test_ext().execute_with(|| {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let other = ed25519::Pair::from_seed(&[2; 32]);
+		let ec = ecdsa::Pair::from_seed(&[1; 32]);
+		assert_scheme_verifies(
+			2,
+			pair.public().into(),
+			|payload| pair.sign(payload).into(),
+			|payload| other.sign(payload).into(),
+			|payload| ec.sign(payload).into(),
+		);
+	});
+}
+
+#[test]
+fn ecdsa_payload_signatures_verify() {
+	new_test_ext().execute_with(|| {
+		let pair = ecdsa::Pair::from_seed(&[1; 32]);
+		let other = ecdsa::Pair::from_seed(&[2; 32]);
+		let sr = sr25519::Pair::from_seed(&[1; 32]);
+		assert_scheme_verifies(
+			3,
+			pair.public().into(),
+			|payload| pair.sign(payload).into(),
+			|payload| other.sign(payload).into(),
+			|payload| sr.sign(payload).into(),
+		);
+	});
+}
+
+#[test]
+fn payloads_signed_for_another_chain_do_not_verify() {
+	let pair = sr25519::Pair::from_seed(&[1; 32]);
+	let message = (1u64, 2u64, 100u64).encode();
+	let signed_on = |genesis_hash| {
+		new_test_ext().execute_with(|| {
+			frame_system::BlockHash::<Test>::insert(0, genesis_hash);
+			run_to_block(1);
+			let payload = TemplateModule::signing_payload(&message);
+			assert_eq!(&payload[..32], &TemplateModule::domain_separator()[..]);
+			assert_eq!(&payload[32..], &message[..]);
+			MockSignature(pair.sign(&payload).into())
+		})
+	};
+	let mainnet = signed_on(H256::repeat_byte(1));
+	let testnet = signed_on(H256::repeat_byte(2));
+
+	new_test_ext().execute_with(|| {
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+		run_to_block(1);
+		SigningKeys::set(vec![(1, pair.public().into())]);
+		assert!(TemplateModule::verify_payload(&1, &message, &mainnet));
+		assert!(!TemplateModule::verify_payload(&1, &message, &testnet));
+	});
+}
+
+#[test]
+fn transfer_ids_make_retries_idempotent_until_pruned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let id = [7; 16];
+		assert_ok!(TemplateModule::transfer_with_id(RuntimeOrigin::signed(1), 2, 10, id));
+		System::assert_last_event(Event::TransferWithId { from: 1, to: 2, value: 10, id }.into());
+
+		// The retry of a transfer that already landed pays nothing; other senders and ids are
+		// unaffected.
+		assert_noop!(
+			TemplateModule::transfer_with_id(RuntimeOrigin::signed(1), 2, 10, id),
+			Error::<Test>::DuplicateTransferId
+		);
+		assert_ok!(TemplateModule::transfer_with_id(RuntimeOrigin::signed(1), 2, 10, [8; 16]));
+		assert_ok!(TemplateModule::transfer_with_id(RuntimeOrigin::signed(2), 1, 5, id));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(15));
+
+		let ids: BoundedVec<_, _> = vec![id, [9; 16]].try_into().unwrap();
+		assert_noop!(
+			TemplateModule::prune_transfer_ids(RuntimeOrigin::signed(3), 1, ids.clone()),
+			Error::<Test>::TransferIdRetained
+		);
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::prune_transfer_ids(RuntimeOrigin::signed(3), 1, ids));
+		assert!(!UsedTransferIds::<Test>::contains_key(1, id));
+		assert!(UsedTransferIds::<Test>::contains_key(1, [8; 16]));
+		assert!(UsedTransferIds::<Test>::contains_key(2, id));
+
+		assert_ok!(TemplateModule::transfer_with_id(RuntimeOrigin::signed(1), 2, 10, id));
+		assert_eq!(UsedTransferIds::<Test>::get(1, id), Some(11));
+	});
+}
+
+#[test]
+fn calls_keep_their_index_when_others_are_added() {
+	let index = |call: crate::Call<Test>| call.encode()[0];
+	assert_eq!(index(crate::Call::transfer { to: 2, value: 1 }), 3);
+	assert_eq!(index(crate::Call::approve { spender: 2, value: 1, deadline: None }), 4);
+	assert_eq!(index(crate::Call::transfer_from { from: 1, to: 2, value: 1 }), 5);
+	assert_eq!(index(crate::Call::transfer_with_id { to: 2, value: 1, id: [0; 16] }), 47);
+	let ids = Default::default();
+	assert_eq!(index(crate::Call::prune_transfer_ids { sender: 1, ids }), 48);
+	new_test_ext().execute_with(|| assert_eq!(index(signed_permit(10, 5)), 50));
+}
+
+#[test]
+fn reserve_attestations_flag_an_under_collateralized_supply() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		let reference: BoundedVec<_, _> = b"audit-1".to_vec().try_into().unwrap();
+		assert_noop!(
+			TemplateModule::post_reserve_attestation(
+				RuntimeOrigin::signed(1),
+				80,
+				4,
+				reference.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::post_reserve_attestation(
+				RuntimeOrigin::root(),
+				80,
+				6,
+				reference.clone()
+			),
+			Error::<Test>::ReserveAttestationOutOfOrder
+		);
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			80,
+			4,
+			reference.clone()
+		));
+		System::assert_has_event(Event::ReservesAttested { reserves: 80, as_of: 4 }.into());
+		System::assert_last_event(Event::UnderCollateralized { supply: 100, reserves: 80 }.into());
+		assert_eq!(
+			TemplateModule::latest_reserve_attestation(),
+			Some(ReserveAttestation { reserves: 80, as_of: 4, reference: reference.clone() })
+		);
+		assert_noop!(
+			TemplateModule::post_reserve_attestation(
+				RuntimeOrigin::root(),
+				90,
+				3,
+				reference.clone()
+			),
+			Error::<Test>::ReserveAttestationOutOfOrder
+		);
+
+		// Back above the supply, until a mint goes past the reserves again.
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			150,
+			5,
+			reference.clone()
+		));
+		System::assert_last_event(Event::ReservesAttested { reserves: 150, as_of: 5 }.into());
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 50));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 10));
+		System::assert_has_event(Event::UnderCollateralized { supply: 160, reserves: 150 }.into());
+
+		// Only the last `MaxReserveHistory` superseded attestations are kept.
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			200,
+			5,
+			reference
+		));
+		let history: Vec<_> = TemplateModule::reserve_attestation_history()
+			.into_iter()
+			.map(|attestation| attestation.reserves)
+			.collect();
+		assert_eq!(history, vec![80, 150]);
+		assert_eq!(TemplateModule::latest_reserve_attestation().map(|a| a.reserves), Some(200));
+	});
+}
+
+#[test]
+fn mints_can_require_collateral() {
+	new_test_ext().execute_with(|| {
+		MintRequiresCollateral::set(true);
+		System::set_block_number(1);
+		// Nothing is required before the first attestation.
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 50));
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			100,
+			1,
+			BoundedVec::default()
+		));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 50));
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 1, 1),
+			Error::<Test>::InsufficientCollateral
+		);
+		BlockReward::set(10);
+		run_to_block(2);
+		assert_eq!(TemplateModule::get_total_supply(), Some(100));
+
+		// Transfers are not held back, and new reserves allow minting again.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10));
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			101,
+			2,
+			BoundedVec::default()
+		));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 1));
+	});
+}
+
+// A permit from account 1, whose key `SigningKeys` is set to, for account 2 to spend `value`
+// until block `deadline`.
+fn signed_permit(value: u64, deadline: u64) -> crate::Call<Test> {
+	let pair = sr25519::Pair::from_seed(&[5; 32]);
+	SigningKeys::set(vec![(1, pair.public().into())]);
+	let payload = TemplateModule::payload_for_permit(1, 2, value, deadline);
+	crate::Call::permit_unsigned {
+		owner: 1,
+		spender: 2,
+		value,
+		deadline,
+		signature: MockSignature(pair.sign(&payload).into()),
+	}
+}
+
+#[test]
+fn unsigned_permits_are_checked_in_the_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = signed_permit(50, 10);
+		let valid = TemplateModule::validate_unsigned(TransactionSource::External, &call).unwrap();
+		assert_eq!(valid.priority, 100);
+		assert_eq!(valid.provides, vec![("TemplatePermit", (1u64, 0u64)).encode()]);
+		assert_eq!(valid.longevity, 10);
+		// Nothing was written while validating.
+		assert_eq!(TemplateModule::nonces(1), 0);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), None);
+
+		let crate::Call::permit_unsigned { signature, .. } = call.clone() else { unreachable!() };
+		let forged = crate::Call::permit_unsigned {
+			owner: 1,
+			spender: 2,
+			value: 60,
+			deadline: 10,
+			signature,
+		};
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &forged),
+			InvalidTransaction::BadProof.into()
+		);
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::total_supply {}
+			),
+			InvalidTransaction::Call.into()
+		);
+		System::set_block_number(11);
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
+
+#[test]
+fn unsigned_permits_set_the_allowance_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let crate::Call::permit_unsigned { owner, spender, value, deadline, signature } =
+			signed_permit(50, 10)
+		else {
+			unreachable!()
+		};
+		assert_noop!(
+			TemplateModule::permit_unsigned(
+				RuntimeOrigin::signed(3),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::permit_unsigned(
+			RuntimeOrigin::none(),
+			owner,
+			spender,
+			value,
+			deadline,
+			signature.clone()
+		));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(50));
+		assert_eq!(TemplateModule::nonces(1), 1);
+		System::assert_last_event(Event::Approval { from: 1, to: 2, value: 50 }.into());
+
+		// The nonce moved on, so the same signature cannot be replayed.
+		assert_noop!(
+			TemplateModule::permit_unsigned(
+				RuntimeOrigin::none(),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature
+			),
+			Error::<Test>::InvalidPermitSignature
+		);
+		let call = signed_permit(0, 10);
+		assert_ok!(TemplateModule::validate_unsigned(TransactionSource::External, &call));
+		assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::none()));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), None);
+
+		let crate::Call::permit_unsigned { owner, spender, value, deadline, signature } =
+			signed_permit(20, 1)
+		else {
+			unreachable!()
+		};
+		System::set_block_number(2);
+		assert_noop!(
+			TemplateModule::permit_unsigned(
+				RuntimeOrigin::none(),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature
+			),
+			Error::<Test>::PermitExpired
+		);
+	});
+}
+
+#[test]
+fn signed_permits_can_be_relayed_by_anyone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let crate::Call::permit_unsigned { owner, spender, value, deadline, signature } =
+			signed_permit(50, 10)
+		else {
+			unreachable!()
+		};
+		assert_noop!(
+			TemplateModule::permit(
+				RuntimeOrigin::none(),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::permit(
+				RuntimeOrigin::signed(3),
+				owner,
+				spender,
+				value + 1,
+				deadline,
+				signature.clone()
+			),
+			Error::<Test>::InvalidPermitSignature
+		);
+		assert_ok!(TemplateModule::permit(
+			RuntimeOrigin::signed(3),
+			owner,
+			spender,
+			value,
+			deadline,
+			signature.clone()
+		));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(50));
+		assert_eq!(TemplateModule::nonces(1), 1);
+		System::assert_last_event(Event::Approval { from: 1, to: 2, value: 50 }.into());
+		assert_noop!(
+			TemplateModule::permit(
+				RuntimeOrigin::signed(2),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature
+			),
+			Error::<Test>::InvalidPermitSignature
+		);
+	});
+}
+
+#[test]
+fn asset_migration_moves_balances_then_allowances_in_chunks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, amount) in [(1, 100), (2, 50), (3, 30)] {
+			assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), who, amount));
+		}
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 3, 0, 20));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 3, 0, 5));
+
+		assert_noop!(
+			TemplateModule::migrate_to_assets(RuntimeOrigin::signed(1), 7),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::migrate_to_assets(RuntimeOrigin::root(), 7));
+		assert!(<Assets as fungibles::Inspect<u64>>::asset_exists(7));
+		System::assert_last_event(Event::AssetMigrationStarted { asset: 7 }.into());
+
+		// The pallet is frozen, block rewards included.
+		BlockReward::set(10);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::MigrationInProgress
+		);
+		assert_noop!(
+			TemplateModule::migrate_to_assets(RuntimeOrigin::root(), 8),
+			Error::<Test>::MigrationInProgress
+		);
+
+		// Two entries per block: two balances, then the last balance and the first allowance,
+		// then the other two allowances.
+		run_to_block(2);
+		assert_ok!(TemplateModule::do_try_state());
+		assert_eq!(TemplateModule::asset_migration().map(|status| status.holders), Some(2));
+		assert_eq!(TemplateModule::holder_count(), 1);
+		run_to_block(3);
+		assert_ok!(TemplateModule::do_try_state());
+		System::assert_last_event(
+			Event::AssetMigrationProgress { holders: 3, tokens: 180, allowances: 1 }.into(),
+		);
+		run_to_block(4);
+		assert_ok!(TemplateModule::do_try_state());
+		System::assert_last_event(
+			Event::AssetMigrationProgress { holders: 3, tokens: 180, allowances: 3 }.into(),
+		);
+		// A block that finds no allowance left finishes the migration.
+		run_to_block(5);
+		System::assert_last_event(
+			Event::AssetMigrationCompleted {
+				asset: 7,
+				holders: 3,
+				tokens: 180,
+				allowances: 3,
+				stranded: 0,
+				dropped_allowances: 0,
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::asset_migration(), None);
+
+		for (who, amount) in [(1, 100), (2, 50), (3, 30)] {
+			assert_eq!(Assets::balance(7, who), amount);
+			assert_eq!(TemplateModule::get_balance_of(who), None);
+		}
+		for (owner, spender, value) in [(1, 2, 10), (1, 3, 20), (2, 3, 5)] {
+			assert_eq!(
+				<Assets as fungibles::approvals::Inspect<u64>>::allowance(7, &owner, &spender),
+				value
+			);
+			assert_eq!(Allowances::<Test>::get((0, owner, spender)), None);
+		}
+		assert!(Approvals::<Test>::get(1).is_empty());
+		assert_eq!(TemplateModule::get_balance_of(RewardPot::get()), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(0));
+		assert_eq!(TemplateModule::holder_count(), 0);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Once done, the pallet accepts calls again.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 5));
+	});
+}
+
+#[test]
+fn admin_launches_further_tokens_keyed_by_asset_id() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::create_asset(RuntimeOrigin::signed(1), 9, 5, 3),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::create_asset(RuntimeOrigin::root(), 9, 5, 3));
+		System::assert_last_event(
+			Event::AssetCreated { asset: 9, admin: 5, min_balance: 3 }.into(),
+		);
+		assert_noop!(
+			TemplateModule::create_asset(RuntimeOrigin::root(), 9, 5, 3),
+			Error::<Test>::AssetExists
+		);
+		// The native token's id is taken too.
+		assert_noop!(
+			TemplateModule::create_asset(RuntimeOrigin::root(), 0, 5, 3),
+			Error::<Test>::AssetExists
+		);
+
+		// Only the admin mints, burns and sets metadata.
+		assert_noop!(
+			TemplateModule::mint_asset(RuntimeOrigin::signed(1), 9, 1, 10),
+			Error::<Test>::NotAssetAdmin
+		);
+		assert_noop!(
+			TemplateModule::mint_asset(RuntimeOrigin::signed(5), 8, 1, 10),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			TemplateModule::mint_asset(RuntimeOrigin::signed(5), 9, 1, 2),
+			Error::<Test>::BelowMinimum
+		);
+		assert_ok!(TemplateModule::mint_asset(RuntimeOrigin::signed(5), 9, 1, 10));
+		System::assert_last_event(Event::AssetIssued { asset: 9, to: 1, amount: 10 }.into());
+		assert_eq!(TemplateModule::asset_balance(9, &1), 10);
+		assert_eq!(TemplateModule::asset_supply(9), 10);
+
+		// The native token's balance and indices are untouched.
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::get_total_supply(), None);
+		assert_eq!(TemplateModule::holder_count(), 0);
+		assert_eq!(System::providers(&1), 0);
+
+		assert_noop!(
+			TemplateModule::burn_asset(RuntimeOrigin::signed(5), 9, 1, 8),
+			Error::<Test>::BelowMinimum
+		);
+		assert_noop!(
+			TemplateModule::burn_asset(RuntimeOrigin::signed(5), 9, 1, 11),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::burn_asset(RuntimeOrigin::signed(5), 9, 1, 4));
+		System::assert_last_event(Event::AssetBurned { asset: 9, from: 1, amount: 4 }.into());
+		assert_eq!(TemplateModule::asset_balance(9, &1), 6);
+		assert_eq!(TemplateModule::asset_supply(9), 6);
+
+		let name: BoundedVec<u8, <Test as Config>::StringLimit> =
+			b"LCH".to_vec().try_into().unwrap();
+		assert_noop!(
+			TemplateModule::set_asset_metadata(
+				RuntimeOrigin::signed(1),
+				9,
+				name.clone(),
+				name.clone(),
+				4
+			),
+			Error::<Test>::NotAssetAdmin
+		);
+		assert_ok!(TemplateModule::set_asset_metadata(
+			RuntimeOrigin::signed(5),
+			9,
+			name.clone(),
+			name.clone(),
+			4
+		));
+		System::assert_last_event(
+			Event::AssetMetadataSet { asset: 9, name: name.clone(), symbol: name, decimals: 4 }
+				.into(),
+		);
+		assert_eq!(Metadata::<Test>::get(9).decimals, 4);
+		assert_eq!(TemplateModule::metadata().decimals, 0);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn launched_tokens_move_and_approve_on_their_own() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_asset(RuntimeOrigin::root(), 9, 5, 3));
+		assert_ok!(TemplateModule::mint_asset(RuntimeOrigin::signed(5), 9, 1, 10));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+
+		assert_noop!(
+			TemplateModule::transfer_asset(RuntimeOrigin::signed(1), 9, 2, 2),
+			Error::<Test>::BelowMinimum
+		);
+		assert_noop!(
+			TemplateModule::transfer_asset(RuntimeOrigin::signed(1), 9, 2, 11),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::transfer_asset(RuntimeOrigin::signed(1), 9, 2, 4));
+		System::assert_last_event(
+			Event::AssetTransferred { asset: 9, from: 1, to: 2, value: 4 }.into(),
+		);
+		assert_eq!(TemplateModule::asset_balance(9, &1), 6);
+		assert_eq!(TemplateModule::asset_balance(9, &2), 4);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+
+		// Native allowances do not cover the launched token, nor the other way round.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 50));
+		assert_noop!(
+			TemplateModule::transfer_asset_from(RuntimeOrigin::signed(3), 9, 1, 3, 3),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 3, 6));
+		System::assert_last_event(
+			Event::AssetApproval { asset: 9, owner: 1, spender: 3, value: 6 }.into(),
+		);
+		assert_eq!(Allowances::<Test>::get((0, 1, 3)), Some(50));
+		assert_ok!(TemplateModule::transfer_asset_from(RuntimeOrigin::signed(3), 9, 1, 3, 6));
+		assert_eq!(TemplateModule::asset_balance(9, &1), 0);
+		assert!(!BalanceOf::<Test>::contains_key(9, 1));
+		assert_eq!(TemplateModule::asset_balance(9, &3), 6);
+		assert!(!Allowances::<Test>::contains_key((9, 1, 3)));
+		assert_eq!(Allowances::<Test>::get((0, 1, 3)), Some(50));
+
+		// Freezes and the pause apply to every token.
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			TemplateModule::transfer_asset(RuntimeOrigin::signed(2), 9, 3, 4),
+			Error::<Test>::AccountFrozen
+		);
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		assert_noop!(
+			TemplateModule::transfer_asset(RuntimeOrigin::signed(3), 9, 1, 3),
+			Error::<Test>::TokenPaused
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn launched_token_allowances_hold_deposits_and_share_the_bound() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AllowanceDeposit::set(5);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::create_asset(RuntimeOrigin::root(), 9, 5, 1));
+		assert_ok!(TemplateModule::mint_asset(RuntimeOrigin::signed(5), 9, 1, 10));
+
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 2, 4));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// Changing it takes no further deposit.
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 2, 6));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		// Native, scoped and launched-token allowances fill the same `MaxApprovalsPerOwner`.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 5, None));
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 4, 5));
+		assert_noop!(
+			TemplateModule::approve(RuntimeOrigin::signed(1), 5, 5, None),
+			Error::<Test>::TooManyApprovals
+		);
+		assert_noop!(
+			TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 5, 5),
+			Error::<Test>::TooManyApprovals
+		);
+		assert_eq!(Balances::reserved_balance(1), 15);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Spends report what is left, and using it up returns the deposit and the slot.
+		assert_ok!(TemplateModule::transfer_asset_from(RuntimeOrigin::signed(2), 9, 1, 2, 2));
+		System::assert_last_event(
+			Event::AssetApproval { asset: 9, owner: 1, spender: 2, value: 4 }.into(),
+		);
+		assert_ok!(TemplateModule::transfer_asset_from(RuntimeOrigin::signed(2), 9, 1, 2, 4));
+		System::assert_last_event(
+			Event::AssetApproval { asset: 9, owner: 1, spender: 2, value: 0 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 5, 5, None));
+
+		// Revoking works alike.
+		assert_ok!(TemplateModule::approve_asset(RuntimeOrigin::signed(1), 9, 4, 0));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn asset_migration_leaves_what_the_asset_refuses() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// An existing asset that is not sufficient cannot keep an account alive alone.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 8, 99, false, 1));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 40));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 60));
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 3, 0, 25));
+		AssetApprovalDeposit::set(1_000);
+
+		assert_ok!(TemplateModule::migrate_to_assets(RuntimeOrigin::root(), 8));
+		run_to_block(2);
+		assert_ok!(TemplateModule::do_try_state());
+		run_to_block(3);
+		System::assert_last_event(
+			Event::AssetMigrationCompleted {
+				asset: 8,
+				holders: 1,
+				tokens: 60,
+				allowances: 0,
+				stranded: 1,
+				dropped_allowances: 1,
+			}
+			.into(),
+		);
+
+		// Only this pallet kept account 1 alive, so its balance stays here.
+		assert_eq!(TemplateModule::get_balance_of(1), Some(40));
+		assert_eq!(TemplateModule::get_total_supply(), Some(40));
+		assert_eq!(Assets::balance(8, 1), 0);
+		assert_eq!(Assets::balance(8, 2), 60);
+		assert_eq!(Allowances::<Test>::get((0, 2, 3)), None);
+		assert_eq!(<Assets as fungibles::approvals::Inspect<u64>>::allowance(8, &2, &3), 0);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn sunset_refunds_every_holder_pro_rata_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_savings_mode(RuntimeOrigin::signed(2), Some(5)));
+		for (who, amount) in [(1, 100), (2, 50), (3, 50)] {
+			assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), who, amount));
+		}
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 10));
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 3, 20));
+		Balances::make_free_balance_be(&93, 1_000);
+
+		let destination = SunsetDestination::Refund(93);
+		assert_noop!(
+			TemplateModule::initiate_sunset(RuntimeOrigin::signed(1), destination.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::initiate_sunset(RuntimeOrigin::root(), destination.clone()));
+		System::assert_last_event(
+			Event::SunsetInitiated { destination: destination.clone() }.into(),
+		);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::MigrationInProgress
+		);
+		assert_noop!(
+			TemplateModule::initiate_sunset(RuntimeOrigin::root(), destination),
+			Error::<Test>::MigrationInProgress
+		);
+
+		// Two holders per block.
+		run_to_block(2);
+		assert_ok!(TemplateModule::do_try_state());
+		assert_eq!(TemplateModule::sunset().map(|status| status.holders), Some(2));
+		assert_eq!(TemplateModule::holder_count(), 1);
+		run_to_block(3);
+		System::assert_last_event(
+			Event::SunsetComplete { holders: 3, tokens: 200, refunded: 1_000, stranded: 0 }.into(),
+		);
+		for (who, value, refund) in [(1, 100, 500), (2, 50, 250), (3, 50, 250)] {
+			System::assert_has_event(Event::SunsetRefunded { who, value, refund }.into());
+			assert_eq!(Balances::free_balance(who), refund);
+		}
+		assert_eq!(Balances::free_balance(93), 0);
+		assert_eq!(TemplateModule::get_total_supply(), Some(0));
+		assert_eq!(TemplateModule::holder_count(), 0);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), None);
+		assert_eq!(TemplateModule::frozen_balance(3), None);
+		assert!(!SavingTranches::<Test>::contains_key(2));
+		assert_ok!(TemplateModule::do_try_state());
+
+		// The pallet stays frozen once the sunset is done.
+		run_to_block(4);
+		assert_noop!(
+			TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 5),
+			Error::<Test>::MigrationInProgress
+		);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn sunset_moves_every_balance_to_the_successor() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, amount) in [(1, 100), (2, 50), (4, 10)] {
+			assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), who, amount));
+		}
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(2), 3, 0, 5));
+
+		assert_ok!(TemplateModule::initiate_sunset(
+			RuntimeOrigin::root(),
+			SunsetDestination::Successor(4)
+		));
+		run_to_block(2);
+		assert_ok!(TemplateModule::do_try_state());
+		// The successor takes a slot in the walk but is skipped.
+		run_to_block(3);
+		System::assert_last_event(
+			Event::SunsetComplete { holders: 2, tokens: 150, refunded: 0, stranded: 0 }.into(),
+		);
+		System::assert_has_event(Event::SunsetMoved { who: 1, to: 4, value: 100 }.into());
+		System::assert_has_event(Event::SunsetMoved { who: 2, to: 4, value: 50 }.into());
+		assert_eq!(TemplateModule::get_balance_of(4), Some(160));
+		assert_eq!(TemplateModule::get_total_supply(), Some(160));
+		assert_eq!(TemplateModule::holder_count(), 1);
+		assert!(Approvals::<Test>::get(2).is_empty());
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn metadata_is_set_at_genesis_and_by_the_admin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(TemplateModule::metadata().decimals, 0);
+		GenesisConfig::<Test> {
+			name: b"Template".to_vec(),
+			symbol: b"TMPL".to_vec(),
+			decimals: 12,
+			..Default::default()
+		}
+		.build();
+		assert_eq!(TemplateModule::metadata().name.to_vec(), b"Template".to_vec());
+		assert_eq!(TemplateModule::metadata().symbol.to_vec(), b"TMPL".to_vec());
+		assert_eq!(TemplateModule::metadata().decimals, 12);
+
+		let name: BoundedVec<u8, ConstU32<8>> = b"Renamed".to_vec().try_into().unwrap();
+		let symbol: BoundedVec<u8, ConstU32<8>> = b"RNM".to_vec().try_into().unwrap();
+		assert_noop!(
+			TemplateModule::set_metadata(RuntimeOrigin::signed(1), name.clone(), symbol.clone(), 6),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::set_metadata(
+			RuntimeOrigin::root(),
+			name.clone(),
+			symbol.clone(),
+			6
+		));
+		System::assert_last_event(
+			Event::MetadataSet { name: name.clone(), symbol, decimals: 6 }.into(),
+		);
+		assert_eq!(TemplateModule::metadata().name, name);
+		assert_eq!(TemplateModule::metadata().decimals, 6);
+	});
+}
+
+#[test]
+fn pause_stops_transfers_approvals_mints_and_burns_until_unpause() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 50, None));
+		assert_noop!(TemplateModule::pause(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+		assert_noop!(TemplateModule::unpause(RuntimeOrigin::root()), Error::<Test>::TokenNotPaused);
+
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Paused.into());
+		assert!(TemplateModule::paused());
+		assert_noop!(TemplateModule::pause(RuntimeOrigin::root()), Error::<Test>::TokenPaused);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 3, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::approve(RuntimeOrigin::signed(1), 3, 10, None),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 1, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::burn(RuntimeOrigin::root(), 1, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(TemplateModule::unpause(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+
+		assert_ok!(TemplateModule::unpause(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Unpaused.into());
+		assert!(!TemplateModule::paused());
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 3, 10));
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 1, 10));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+	});
+}
+
+#[test]
+fn frozen_accounts_neither_send_nor_receive_until_thawed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 50, None));
+		assert_noop!(
+			TemplateModule::freeze_account(RuntimeOrigin::signed(1), 2),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::AccountFrozen { who: 2 }.into());
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 2, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 2, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			TemplateModule::migrate_account(RuntimeOrigin::signed(2), 4, Default::default()),
+			Error::<Test>::AccountFrozen
+		);
+		// A frozen spender cannot draw on its allowances either.
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 3));
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 4, 10),
+			Error::<Test>::AccountFrozen
+		);
+		// Burning is how a frozen balance is seized.
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 2, 10));
+
+		assert_ok!(TemplateModule::thaw_account(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::AccountThawed { who: 2 }.into());
+		assert_ok!(TemplateModule::thaw_account(RuntimeOrigin::root(), 3));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 1, 10));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 2, 10));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(90));
+	});
+}
+
+#[test]
+fn frozen_accounts_are_kept_out_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 50, None));
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 2));
+		let check = |who: u64, call: crate::Call<Test>| {
+			let call = RuntimeCall::TemplateModule(call);
+			let info = DispatchInfo::default();
+			let valid = CheckNotFrozen::<Test>::new().validate(&who, &call, &info, 0);
+			let ready = CheckNotFrozen::<Test>::new().pre_dispatch(&who, &call, &info, 0);
+			assert_eq!(valid.is_ok(), ready.is_ok());
+			valid.map(|_| ())
+		};
+
+		let frozen = Err(InvalidTransaction::Custom(ACCOUNT_FROZEN).into());
+		assert_eq!(check(2, crate::Call::transfer { to: 1, value: 10 }), frozen);
+		assert_eq!(check(1, crate::Call::transfer { to: 2, value: 10 }), frozen);
+		assert_eq!(check(3, crate::Call::transfer_from { from: 1, to: 2, value: 10 }), frozen);
+		assert_eq!(check(2, crate::Call::vest {}), frozen);
+		assert_eq!(check(1, crate::Call::transfer { to: 3, value: 10 }), Ok(()));
+		// Calls to other pallets are left alone.
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert!(CheckNotFrozen::<Test>::new()
+			.validate(&2, &remark, &DispatchInfo::default(), 0)
+			.is_ok());
+
+		assert_ok!(TemplateModule::thaw_account(RuntimeOrigin::root(), 2));
+		assert_eq!(check(2, crate::Call::transfer { to: 1, value: 10 }), Ok(()));
+	});
+}
+
+#[test]
+fn transfer_batch_pays_every_recipient_or_none() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		let recipients: BoundedVec<_, ConstU32<10>> =
+			vec![(2, 30), (3, 20), (4, 10)].try_into().unwrap();
+		assert_ok!(TemplateModule::transfer_batch(
+			RuntimeOrigin::signed(1),
+			recipients,
+			BatchMode::AllOrNothing
+		));
+		System::assert_has_event(Event::Transfer { from: 1, to: 3, value: 20 }.into());
+		System::assert_last_event(Event::BatchTransfer { from: 1, count: 3, total: 60 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(40));
+		assert_eq!(TemplateModule::get_balance_of(4), Some(10));
+
+		// The second transfer overdraws, so the first is rolled back too and the rest never
+		// run, nor are paid for.
+		let recipients: BoundedVec<_, ConstU32<10>> =
+			vec![(2, 30), (3, 20), (4, 1)].try_into().unwrap();
+		assert_noop!(
+			TemplateModule::transfer_batch(
+				RuntimeOrigin::signed(1),
+				recipients,
+				BatchMode::AllOrNothing
+			),
+			Error::<Test>::BatchTransferFailed { index: 1 }
+				.with_weight(SubstrateWeight::<Test>::transfer_batch(2))
+		);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+	});
+}
+
+#[test]
+fn batch_weights_grow_with_the_batch() {
+	let max = <Test as Config>::MaxBatchSize::get();
+	type W = SubstrateWeight<Test>;
+	assert!(W::transfer_batch(max).all_gt(W::transfer_batch(1)));
+	assert!(W::batch_approve(max).all_gt(W::batch_approve(1)));
+	assert!(W::sweep(max).all_gt(W::sweep(1)));
+	assert!(W::sweep_dust(max).all_gt(W::sweep_dust(1)));
+	assert!(W::prune_transfer_ids(max).all_gt(W::prune_transfer_ids(1)));
+}
+
+#[test]
+fn best_effort_transfer_batch_skips_the_transfers_that_fail() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 3));
+		// The transfer to the frozen account and the one that overdraws fail on their own.
+		let recipients: BoundedVec<_, ConstU32<10>> =
+			vec![(2, 30), (3, 20), (4, 50), (5, 30)].try_into().unwrap();
+		let post_info = TemplateModule::transfer_batch(
+			RuntimeOrigin::signed(1),
+			recipients,
+			BatchMode::BestEffort,
+		)
+		.unwrap();
+		let failures = vec![
+			(1, Error::<Test>::AccountFrozen.into()),
+			(3, Error::<Test>::FundsUnavailable.into()),
+		];
+		System::assert_last_event(
+			Event::BatchCompleted { from: 1, succeeded: 2, failures: failures.try_into().unwrap() }
+				.into(),
+		);
+		// The failed transfers' writes were rolled back, and are refunded.
+		let writes = <Test as frame_system::Config>::DbWeight::get().writes(10);
+		assert_eq!(
+			post_info.actual_weight,
+			Some(SubstrateWeight::<Test>::transfer_batch(4).saturating_sub(writes))
+		);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(20));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(30));
+		assert_eq!(TemplateModule::get_balance_of(3), None);
+		assert_eq!(TemplateModule::get_balance_of(4), Some(50));
+		assert_eq!(TemplateModule::get_balance_of(5), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(100));
+	});
+}
+
+#[test]
+fn balances_left_below_min_balance_are_removed_as_dust() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinBalance::set(5);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 3, 100));
+
+		// A transfer leaving 3 burns it.
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 4, 97));
+		System::assert_has_event(Event::DustLost { account: 1, amount: 3 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::get_balance_of(4), Some(97));
+		assert_eq!(TemplateModule::total_burned(), 3);
+		assert_eq!(TemplateModule::get_total_supply(), Some(297));
+
+		// So does a burn, but a balance at the minimum stays.
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 2, 95));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(5));
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 2, 1));
+		System::assert_last_event(Event::Burned { from: 2, value: 1 }.into());
+		System::assert_has_event(Event::DustLost { account: 2, amount: 4 }.into());
+		assert_eq!(TemplateModule::get_balance_of(2), None);
+
+		// Spent through an allowance, the dust goes to `DustReceiver` instead.
+		DustReceiver::set(Some(9));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(3), 4, 100, None));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(4), 3, 4, 98));
+		System::assert_has_event(Event::DustLost { account: 3, amount: 2 }.into());
+		assert_eq!(TemplateModule::get_balance_of(3), None);
+		assert_eq!(TemplateModule::get_balance_of(9), Some(2));
+
+		// Frozen balances are never dust.
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 4, 1));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(4), 5, 193));
+		assert_eq!(TemplateModule::get_balance_of(4), Some(2));
+		assert_ok!(TemplateModule::do_try_state());
+		MinBalance::set(0);
+		DustReceiver::set(None);
+	});
+}
+
+#[test]
+fn sweep_dust_reaps_the_listed_accounts_below_min_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, amount) in [(1, 3), (2, 3), (3, 100), (5, 4)] {
+			assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), who, amount));
+		}
+		assert_ok!(TemplateModule::freeze_account(RuntimeOrigin::root(), 2));
+		assert_ok!(TemplateModule::freeze_amount(RuntimeOrigin::root(), 5, 1));
+		MinBalance::set(5);
+
+		// Only 1 is reaped: 2 is frozen, 3 holds enough, 4 holds nothing and 5 has a freeze.
+		let accounts: BoundedVec<_, ConstU32<10>> = vec![1, 2, 3, 4, 5].try_into().unwrap();
+		let info = TemplateModule::sweep_dust(RuntimeOrigin::signed(9), accounts).unwrap();
+		System::assert_last_event(Event::DustLost { account: 1, amount: 3 }.into());
+		assert_eq!(info.actual_weight, Some(SubstrateWeight::<Test>::sweep_dust(1)));
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(3));
+		assert_eq!(TemplateModule::get_balance_of(3), Some(100));
+		assert_eq!(TemplateModule::get_balance_of(5), Some(4));
+		assert_eq!(TemplateModule::get_total_supply(), Some(107));
+
+		// Nothing left to reap refunds all but the base weight.
+		let accounts: BoundedVec<_, ConstU32<10>> = vec![1, 3].try_into().unwrap();
+		let info = TemplateModule::sweep_dust(RuntimeOrigin::signed(9), accounts).unwrap();
+		assert_eq!(info.actual_weight, Some(SubstrateWeight::<Test>::sweep_dust(0)));
+		assert_ok!(TemplateModule::do_try_state());
+		MinBalance::set(0);
+	});
+}
+
+#[test]
+fn vested_transfers_unlock_linearly_as_the_recipient_vests() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 2, 60, 0, 5),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_ok!(TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 2, 60, 10, 5));
+		System::assert_last_event(
+			Event::VestingScheduleAdded {
+				from: 1,
+				to: 2,
+				locked: 60,
+				per_block: 10,
+				starting_block: 5,
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::get_balance_of(2), Some(60));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+
+		// Vested tokens stay locked until `vest` is called.
+		System::set_block_number(7);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(TemplateModule::vest(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::Vested { who: 2, locked: 40 }.into());
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 20));
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test>::Frozen
+		);
+
+		// At most `MaxVestingSchedules` at once.
+		assert_ok!(TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 2, 10, 1, 1));
+		assert_noop!(
+			TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 2, 10, 1, 1),
+			Error::<Test>::TooManyVestingSchedules
+		);
+
+		// Once done, a schedule is dropped.
+		System::set_block_number(20);
+		assert_ok!(TemplateModule::vest(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::Vested { who: 2, locked: 0 }.into());
+		assert!(!VestingSchedules::<Test>::contains_key(2));
+		assert_noop!(TemplateModule::vest(RuntimeOrigin::signed(2)), Error::<Test>::NotVesting);
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(2), 3, 50));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn on_transfer_observes_and_may_veto_every_movement() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 30));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 20, None));
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 4, 20));
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 2, 10));
+		assert_eq!(
+			ObservedTransfers::get(),
+			vec![
+				(None, Some(1), 100),
+				(Some(1), Some(2), 30),
+				(Some(1), Some(4), 20),
+				(Some(2), None, 10)
+			]
+		);
+
+		VetoTransfers::set(true);
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 1, 10),
+			Error::<Test>::TransferVetoed
+		);
+		assert_noop!(
+			TemplateModule::transfer(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::TransferVetoed
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(3), 1, 4, 0),
+			Error::<Test>::TransferVetoed
+		);
+		assert_noop!(
+			TemplateModule::burn(RuntimeOrigin::root(), 2, 10),
+			Error::<Test>::TransferVetoed
+		);
+		VetoTransfers::set(false);
+		ObservedTransfers::set(vec![]);
+	});
+}
+
+#[test]
+fn mints_stop_at_the_supply_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::set_max_supply(RuntimeOrigin::signed(1), 150),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_max_supply(RuntimeOrigin::root(), 99),
+			Error::<Test>::SupplyCapExceeded
+		);
+		assert_ok!(TemplateModule::set_max_supply(RuntimeOrigin::root(), 150));
+		System::assert_last_event(Event::SupplyCapSet { cap: 150 }.into());
+		assert_eq!(TemplateModule::max_supply(), Some(150));
+		assert_noop!(
+			TemplateModule::set_max_supply(RuntimeOrigin::root(), 200),
+			Error::<Test>::SupplyCapAlreadySet
+		);
+
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 2, 51),
+			Error::<Test>::SupplyCapExceeded
+		);
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 50));
+		assert_noop!(
+			TemplateModule::mint(RuntimeOrigin::root(), 2, 1),
+			Error::<Test>::SupplyCapExceeded
+		);
+		// Burning makes room again.
+		assert_ok!(TemplateModule::burn(RuntimeOrigin::root(), 1, 10));
+		assert_ok!(TemplateModule::mint(RuntimeOrigin::root(), 2, 10));
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+	});
+}
+
+#[test]
+fn migrations_bring_a_v0_chain_to_the_current_storage_version() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		StorageVersion::new(0).put::<TemplateModule>();
+		pre_v5::BalanceOf::<Test>::insert(1, 100);
+		pre_v5::BalanceOf::<Test>::insert(2, 0);
+		pre_v5::BalanceOf::<Test>::insert(3, 50);
+		pre_v5::TotalSupply::<Test>::put(150);
+		Allowance::<Test>::insert((1, 3), 10);
+		Approvals::<Test>::mutate(1, |spenders| spenders.try_push(3).unwrap());
+
+		// The runtime's `Migrations`, as one upgrade.
+		<(
+			migrations::v1::InitHolderCount<Test>,
+			migrations::v2::AddProviders<Test>,
+			migrations::v3::RekeyAllowances<Test>,
+			migrations::v4::InitTopHolders<Test>,
+			migrations::v5::RekeyByAsset<Test>,
+		) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		// Everything is readable straight after, while allowances are still moving.
+		assert_eq!(
+			TemplateModule::on_chain_storage_version(),
+			TemplateModule::current_storage_version()
+		);
+		assert_eq!(AllowanceRekey::<Test>::get(), Some(0));
+		assert_eq!(TemplateModule::holder_count(), 2);
+		assert_eq!(System::providers(&3), 1);
+		assert_eq!(TemplateModule::get_balance_of(3), Some(50));
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(3, 10)]);
+		assert_eq!(TemplateModule::top_holders(10), vec![(1, 100), (3, 50)]);
+		assert_ok!(TemplateModule::do_try_state());
+
+		run_to_block(2);
+		assert_eq!(AllowanceRekey::<Test>::get(), None);
+		assert_eq!(Allowances::<Test>::get((0, 1, 3)), Some(10));
+		assert_eq!(TemplateModule::on_chain_storage_version(), 5);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn v5_migration_keys_the_token_by_the_native_asset() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<TemplateModule>();
+		pre_v5::BalanceOf::<Test>::insert(1, 100);
+		pre_v5::BalanceOf::<Test>::insert(2, 50);
+		HolderCount::<Test>::put(2);
+		pre_v5::TotalSupply::<Test>::put(150);
+		pre_v5::Allowances::<Test>::insert(1, 2, 30);
+		Approvals::<Test>::mutate(1, |spenders| spenders.try_push(2).unwrap());
+		pre_v5::Metadata::<Test>::mutate(|metadata| metadata.decimals = 6);
+
+		// Nothing moves before the v4 index is built.
+		migrations::v5::RekeyByAsset::<Test>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		migrations::v4::InitTopHolders::<Test>::on_runtime_upgrade();
+		migrations::v5::RekeyByAsset::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 5);
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(TemplateModule::get_balance_of(2), Some(50));
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(30));
+		assert_eq!(TemplateModule::metadata().decimals, 6);
+		assert_eq!(pre_v5::TotalSupply::<Test>::get(), None);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Running it again is a no-op.
+		migrations::v5::RekeyByAsset::<Test>::on_runtime_upgrade();
+		assert_eq!(TemplateModule::get_balance_of(1), Some(100));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(30));
+	});
+}
+
+#[test]
+fn only_the_admin_can_force_a_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::force_set_balance(RuntimeOrigin::signed(1), 1, 100),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(TemplateModule::get_total_supply(), None);
+
+		// Raising a balance mints the difference, lowering it burns it.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		System::assert_last_event(Event::BalanceForced { who: 1, balance: 100 }.into());
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 50));
+		assert_eq!(TemplateModule::get_total_supply(), Some(150));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 40));
+		assert_eq!(TemplateModule::get_total_supply(), Some(90));
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 0));
+		assert_eq!(TemplateModule::get_total_supply(), Some(40));
+		assert_eq!(TemplateModule::get_balance_of(2), None);
+
+		// The supply cap applies to what is minted.
+		assert_ok!(TemplateModule::set_max_supply(RuntimeOrigin::root(), 60));
+		assert_noop!(
+			TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 21),
+			Error::<Test>::SupplyCapExceeded
+		);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 2, 20));
+		assert_eq!(TemplateModule::get_total_supply(), Some(60));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn burn_from_spends_the_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 0, 30));
+		assert_noop!(
+			TemplateModule::burn_from(RuntimeOrigin::signed(3), 1, 10),
+			Error::<Test>::ApprovalNotGranted
+		);
+		assert_noop!(
+			TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 31),
+			Error::<Test>::AllowanceExceeded
+		);
+
+		assert_ok!(TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 20));
+		System::assert_last_event(Event::BurnedFrom { owner: 1, spender: 2, value: 20 }.into());
+		assert_eq!(TemplateModule::get_balance_of(1), Some(80));
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(10));
+		assert_eq!(TemplateModule::get_total_supply(), Some(80));
+		assert_eq!(TemplateModule::total_burned(), 20);
+
+		// The allowance does not reach past the owner's balance.
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 5));
+		assert_noop!(
+			TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 10),
+			Error::<Test>::FundsUnavailable
+		);
+		assert_ok!(TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 5));
+		assert_eq!(TemplateModule::get_balance_of(1), None);
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), Some(5));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn allowances_with_a_deadline_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(
+			TemplateModule::approve(RuntimeOrigin::signed(1), 2, 50, Some(1)),
+			Error::<Test>::AllowanceExpired
+		);
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 50, Some(3)));
+		assert_eq!(AllowanceExpiry::<Test>::get((1, 2)), Some(3));
+
+		// Spendable up to and including the deadline.
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10));
+		System::set_block_number(4);
+		assert_noop!(
+			TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 2, 10),
+			Error::<Test>::AllowanceExpired
+		);
+		assert_noop!(
+			TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 10),
+			Error::<Test>::AllowanceExpired
+		);
+		assert_eq!(TemplateModule::allowance(1, 2), 0);
+
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 20, Some(10)));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 4, 5, None));
+		assert_eq!(TemplateModule::approvals_of(1), vec![(3, 20), (4, 5)]);
+
+		// `on_idle` prunes what expired and leaves the rest.
+		TemplateModule::on_idle(4, Weight::MAX);
+		System::assert_last_event(Event::AllowanceExpired { owner: 1, spender: 2 }.into());
+		assert_eq!(Allowances::<Test>::get((0, 1, 2)), None);
+		assert_eq!(AllowanceExpiry::<Test>::get((1, 2)), None);
+		assert_eq!(Allowances::<Test>::get((0, 1, 3)), Some(20));
+		assert_eq!(Approvals::<Test>::get(1).to_vec(), vec![3, 4]);
+
+		// Approving again without a deadline lifts it.
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 3, 20, None));
+		assert_eq!(AllowanceExpiry::<Test>::get((1, 3)), None);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn the_allowance_sweep_resumes_where_it_ran_out_of_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		for (spender, deadline) in [(2, 2), (3, 2), (4, 3), (5, 10)] {
+			assert_ok!(TemplateModule::approve(
+				RuntimeOrigin::signed(1),
+				spender,
+				5,
+				Some(deadline)
+			));
+		}
+		assert_eq!(AllowanceSweepCursor::<Test>::get(), Some(1));
+
+		// Each block has room for one removal, and picks up where the last one stopped.
+		let budget = SubstrateWeight::<Test>::expire_allowance();
+		System::set_block_number(5);
+		assert_eq!(TemplateModule::on_idle(5, budget), budget);
+		assert_eq!(ExpiringAllowances::<Test>::iter_prefix(2).count(), 1);
+		assert_eq!(AllowanceSweepCursor::<Test>::get(), Some(2));
+		assert_ok!(TemplateModule::do_try_state());
+
+		TemplateModule::on_idle(5, budget);
+		assert_eq!(ExpiringAllowances::<Test>::iter_prefix(2).count(), 0);
+		assert_eq!(Allowances::<Test>::get((0, 1, 4)), Some(5));
+
+		TemplateModule::on_idle(5, budget);
+		System::assert_last_event(Event::AllowanceExpired { owner: 1, spender: 4 }.into());
+		assert_eq!(TemplateModule::approvals_of(1), vec![(5, 5)]);
+
+		// Nothing is left to expire, and the deadline still to come is never reached.
+		assert_eq!(TemplateModule::on_idle(5, Weight::MAX), Weight::zero());
+		assert_eq!(AllowanceSweepCursor::<Test>::get(), Some(5));
+		assert!(ExpiringAllowances::<Test>::contains_key(10, (1, 5)));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn failed_block_rewards_leave_no_partial_writes() {
+	new_test_ext().execute_with(|| {
+		let pot = RewardPot::get();
+		DemurrageRate::set(Perbill::from_percent(1));
+		MintRequiresCollateral::set(true);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), pot, 1_000));
+		assert_ok!(TemplateModule::post_reserve_attestation(
+			RuntimeOrigin::root(),
+			1_000,
+			1,
+			BoundedVec::default()
+		));
+
+		// Each reward settles the pot's demurrage before failing on collateral. Hooks run
+		// outside any dispatchable, so only the mint's own storage layer undoes that.
+		BlockReward::set(10);
+		run_to_block(11);
+		assert_eq!(TemplateModule::get_balance_of(pot), Some(1_000));
+		assert_eq!(TemplateModule::get_balance_of(CommunityPool::get()), None);
+		assert_eq!(TemplateModule::get_total_supply(), Some(1_000));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TemplateModule(Event::DemurrageCharged { .. })
+		)));
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn pause_stops_every_call_that_moves_tokens_or_sets_allowances() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let f = claims_fixture();
+		let purpose = *b"purchase";
+		let dest: BoundedVec<u8, _> = b"0xdest".to_vec().try_into().unwrap();
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 100, None));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 5, 10, Some(2)));
+		assert_ok!(TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, purpose, 50));
+		assert_ok!(TemplateModule::request_payment(
+			RuntimeOrigin::signed(2),
+			1,
+			30,
+			BoundedVec::default()
+		));
+		assert_ok!(TemplateModule::announce_mint(RuntimeOrigin::root(), 1, 5_000));
+		assert_ok!(TemplateModule::set_claims_root(RuntimeOrigin::root(), f.root, 175));
+		let crate::Call::permit_unsigned { owner, spender, value, deadline, signature } =
+			signed_permit(50, 10)
+		else {
+			unreachable!()
+		};
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::pause(RuntimeOrigin::root()));
+
+		assert_noop!(
+			TemplateModule::transfer_with_id(RuntimeOrigin::signed(1), 2, 10, [7; 16]),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::send(
+				RuntimeOrigin::signed(1),
+				2,
+				10,
+				BoundedVec::default(),
+				BoundedVec::default()
+			),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::transfer_batch(
+				RuntimeOrigin::signed(1),
+				vec![(2, 10)].try_into().unwrap(),
+				BatchMode::BestEffort
+			),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::transfer_with_vesting(RuntimeOrigin::signed(1), 2, 10, 1, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::accept_request(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::spend_all_allowance(RuntimeOrigin::signed(2), 1, 4),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::transfer_from_scoped(RuntimeOrigin::signed(2), 1, purpose, 3, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::burn_from(RuntimeOrigin::signed(2), 1, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::execute_mint(RuntimeOrigin::root(), 0),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::claim(
+				RuntimeOrigin::signed(1),
+				f.alice,
+				100,
+				f.alice_proof.clone(),
+				f.alice_signs_1
+			),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(TemplateModule::faucet(RuntimeOrigin::signed(3)), Error::<Test>::TokenPaused);
+		assert_noop!(
+			TemplateModule::bridge_out(RuntimeOrigin::signed(1), 7, dest, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::sweep(RuntimeOrigin::signed(1), vec![0].try_into().unwrap()),
+			Error::<Test>::TokenPaused
+		);
+		let actions = [
+			(TokenAction::Transfer { to: 2, value: 10 }, SubstrateWeight::<Test>::transfer()),
+			(TokenAction::Approve { spender: 3, value: 10 }, SubstrateWeight::<Test>::approve()),
+			(
+				TokenAction::TransferFrom { owner: 1, to: 3, value: 10 },
+				SubstrateWeight::<Test>::transfer_from(),
+			),
+		];
+		for (action, used) in actions {
+			assert_noop!(
+				TemplateModule::multicall(
+					RuntimeOrigin::signed(2),
+					vec![action].try_into().unwrap()
+				),
+				Error::<Test>::MulticallFailed { index: 0 }.with_weight(used)
+			);
+		}
+
+		assert_noop!(
+			TemplateModule::approve_if(RuntimeOrigin::signed(1), 2, 100, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::batch_approve(
+				RuntimeOrigin::signed(1),
+				vec![(3, 10)].try_into().unwrap()
+			),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::increase_allowance(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::decrease_allowance(RuntimeOrigin::signed(1), 2, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::approve_scoped(RuntimeOrigin::signed(1), 2, purpose, 10),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::permit(
+				RuntimeOrigin::signed(3),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature.clone()
+			),
+			Error::<Test>::TokenPaused
+		);
+		assert_noop!(
+			TemplateModule::permit_unsigned(
+				RuntimeOrigin::none(),
+				owner,
+				spender,
+				value,
+				deadline,
+				signature
+			),
+			Error::<Test>::TokenPaused
+		);
+
+		// Expired allowances are still swept while paused.
+		TemplateModule::on_idle(6, Weight::MAX);
+		assert_eq!(Allowances::<Test>::get((0, 1, 5)), None);
+		System::assert_last_event(Event::AllowanceExpired { owner: 1, spender: 5 }.into());
+	});
+}
+
+#[test]
+fn multicall_approvals_clear_the_deadline() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(TemplateModule::approve(RuntimeOrigin::signed(1), 2, 10, Some(3)));
+		let calls = vec![TokenAction::Approve { spender: 2, value: 20 }];
+		assert_ok!(TemplateModule::multicall(RuntimeOrigin::signed(1), calls.try_into().unwrap()));
+		System::assert_last_event(Event::Approval { from: 1, to: 2, value: 20 }.into());
+		assert_eq!(AllowanceExpiry::<Test>::get((1, 2)), None);
+
+		// The new allowance outlives the old deadline.
+		System::set_block_number(5);
+		TemplateModule::on_idle(5, Weight::MAX);
+		assert_eq!(TemplateModule::allowance(1, 2), 20);
+		assert_ok!(TemplateModule::transfer_from(RuntimeOrigin::signed(2), 1, 3, 20));
+	});
+}
+
+#[test]
+fn fees_paid_in_the_token_follow_the_fee_rate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::force_set_balance(RuntimeOrigin::root(), 1, 100));
+		let rate = FixedU128::from_u32(2);
+		assert_noop!(
+			TemplateModule::set_fee_rate(RuntimeOrigin::signed(1), rate),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_fee_rate(RuntimeOrigin::root(), FixedU128::from_u32(0)),
+			Error::<Test>::ZeroFeeRate
+		);
+		assert_ok!(TemplateModule::set_fee_rate(RuntimeOrigin::root(), rate));
+		System::assert_last_event(Event::FeeRateSet { rate }.into());
+		assert_eq!(TemplateModule::tokens_per_native_unit(), rate);
+
+		type Fees = TokenFees<Test, u128>;
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		// A fee of 15 withdraws 30 tokens.
+		let withdrawn = Fees::withdraw_fee(&1, &call, &info, 15, 0).unwrap();
+		assert_eq!(TemplateModule::get_balance_of(1), Some(70));
+		// Corrected to 10, the 10 tokens not needed come back and the 20 charged are burned.
+		assert_ok!(Fees::correct_and_deposit_fee(
+			&1,
+			&info,
+			&PostDispatchInfo::default(),
+			10,
+			0,
+			withdrawn
+		));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(80));
+		assert_eq!(TemplateModule::get_total_supply(), Some(80));
+
+		// A fee too large for the token once converted cannot be paid.
+		assert_eq!(Fees::in_tokens(u64::MAX as u128 / 2), Ok(u64::MAX - 1));
+		assert!(matches!(
+			Fees::withdraw_fee(&1, &call, &info, u64::MAX as u128, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		));
+		assert_eq!(TemplateModule::get_balance_of(1), Some(80));
+		assert_ok!(TemplateModule::do_try_state());
 	});
 }
@@ -0,0 +1,108 @@
+//! Extension points the runtime plugs into the token.
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, PalletError, RuntimeDebug};
+use scale_info::TypeInfo;
+
+/// Called by [`crate::Pallet::send`] once the recipient has been credited.
+///
+/// Returning an error rejects the tokens: the whole send, including the balance change, is
+/// rolled back.
+pub trait OnTokensReceived<AccountId, Balance> {
+	fn on_tokens_received(
+		from: &AccountId,
+		to: &AccountId,
+		value: Balance,
+		data: &[u8],
+		operator_data: &[u8],
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance> OnTokensReceived<AccountId, Balance> for () {
+	fn on_tokens_received(
+		_: &AccountId,
+		_: &AccountId,
+		_: Balance,
+		_: &[u8],
+		_: &[u8],
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Lets other pallets, such as staking, rewards or bridges, observe and veto transfers,
+/// allowance spends, mints and burns. `from` is `None` for a mint and `to` for a burn.
+pub trait OnTokenTransfer<AccountId, Balance> {
+	/// Called before any tokens move. Returning an error stops the movement.
+	fn before_transfer(
+		from: Option<&AccountId>,
+		to: Option<&AccountId>,
+		value: Balance,
+	) -> DispatchResult;
+	/// Called once the tokens have moved.
+	fn after_transfer(from: Option<&AccountId>, to: Option<&AccountId>, value: Balance);
+}
+
+impl<AccountId, Balance> OnTokenTransfer<AccountId, Balance> for () {
+	fn before_transfer(_: Option<&AccountId>, _: Option<&AccountId>, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+	fn after_transfer(_: Option<&AccountId>, _: Option<&AccountId>, _: Balance) {}
+}
+
+/// Lets other pallets, such as an offences pallet, penalize stakers.
+pub trait SlashHandler<AccountId, Balance> {
+	/// Burns up to `amount` of `who`'s tokens, taking staked and unbonding tokens first.
+	/// Returns how much was actually slashed.
+	fn slash_stake(who: &AccountId, amount: Balance) -> Balance;
+}
+
+/// Receives every amount [`SlashHandler::slash_stake`] or the token's `Currency` slashes
+/// burn, e.g. to credit a treasury with it.
+pub trait OnSlash<AccountId, Balance> {
+	fn on_slash(who: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance> OnSlash<AccountId, Balance> for () {
+	fn on_slash(_: &AccountId, _: Balance) {}
+}
+
+/// How far an account's identity has been verified, as far as the token is concerned.
+#[derive(
+	Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, PalletError,
+)]
+pub enum KycTier {
+	None,
+	Basic,
+	Full,
+}
+
+/// What an account of a given [`KycTier`] may send.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct KycLimit<Balance> {
+	/// Largest single transfer.
+	pub max_transfer: Balance,
+	/// Most that can be sent in one `KycWindow`. The largest `Balance` skips the bookkeeping.
+	pub max_per_window: Balance,
+}
+
+/// Answers the [`KycTier`] of an account from whatever identity system the chain runs.
+pub trait KycTierOf<AccountId> {
+	fn kyc_tier(who: &AccountId) -> KycTier;
+}
+
+/// Treats every account as fully verified.
+impl<AccountId> KycTierOf<AccountId> for () {
+	fn kyc_tier(_: &AccountId) -> KycTier {
+		KycTier::Full
+	}
+}
+
+/// Makes the signatures benchmarks need, which only the runtime knows how to produce for its
+/// `OffchainSignature`.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+	/// A new account whose key can sign.
+	fn signer() -> AccountId;
+	/// `signer`'s signature over `payload`.
+	fn sign(signer: &AccountId, payload: &[u8]) -> Signature;
+}
@@ -2,9 +2,9 @@
 //! Autogenerated weights for pallet_template
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
+//! HOSTNAME: `bench-01`, CPU: `AMD Ryzen 9 5950X 16-Core Processor`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
 
 // Executed Command:
@@ -34,57 +34,4654 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_template.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn total_supply() -> Weight;
+	fn balance_of() -> Weight;
+	fn force_set_balance() -> Weight;
+	fn transfer() -> Weight;
+	fn transfer_with_id() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn spend_all_allowance() -> Weight;
+	fn send() -> Weight;
+	fn mint() -> Weight;
+	fn announce_mint() -> Weight;
+	fn execute_mint() -> Weight;
+	fn cancel_mint() -> Weight;
+	fn exclude_from_circulating() -> Weight;
+	fn include_in_circulating() -> Weight;
+	fn schedule_parameter_change() -> Weight;
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn bridge_out() -> Weight;
+	fn bridge_in() -> Weight;
+	fn set_relayers(n: u32, ) -> Weight;
+	fn vote_bridge_in() -> Weight;
+	fn accept_incoming() -> Weight;
+	fn allow_sender() -> Weight;
+	fn disallow_sender() -> Weight;
+	fn approve_subscription() -> Weight;
+	fn cancel_subscription() -> Weight;
+	fn set_transfer_limit() -> Weight;
+	fn clear_transfer_limit() -> Weight;
+	fn request_payment() -> Weight;
+	fn accept_request() -> Weight;
+	fn reject_request() -> Weight;
+	fn cancel_request() -> Weight;
+	fn approve_if() -> Weight;
+	fn sweep(n: u32, ) -> Weight;
+	fn batch_approve(n: u32, ) -> Weight;
+	fn approve_scoped() -> Weight;
+	fn transfer_from_scoped() -> Weight;
+	fn migrate_account(n: u32, ) -> Weight;
+	fn attest_transfer() -> Weight;
+	fn prune_spent_by() -> Weight;
+	fn freeze_amount() -> Weight;
+	fn unfreeze_amount() -> Weight;
+	fn prune_transfer_ids(n: u32, ) -> Weight;
+	fn post_reserve_attestation() -> Weight;
+	fn set_claims_root() -> Weight;
+	fn claim(p: u32, ) -> Weight;
+	fn permit_unsigned() -> Weight;
+	fn migrate_to_assets() -> Weight;
+	fn cancel_parameter_change() -> Weight;
+	fn delegate_allowance() -> Weight;
+	fn set_savings_mode() -> Weight;
+	fn release_matured() -> Weight;
+	fn initiate_sunset() -> Weight;
+	fn burn() -> Weight;
+	fn increase_allowance() -> Weight;
+	fn decrease_allowance() -> Weight;
+	fn set_metadata() -> Weight;
+	fn create_asset() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn freeze_account() -> Weight;
+	fn thaw_account() -> Weight;
+	fn permit() -> Weight;
+	fn transfer_batch(n: u32, ) -> Weight;
+	fn transfer_with_vesting() -> Weight;
+	fn vest() -> Weight;
+	fn burn_from() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn sweep_dust(n: u32, ) -> Weight;
+	fn mint_asset() -> Weight;
+	fn burn_asset() -> Weight;
+	fn transfer_asset() -> Weight;
+	fn approve_asset() -> Weight;
+	fn transfer_asset_from() -> Weight;
+	fn set_asset_metadata() -> Weight;
+	fn expire_allowance() -> Weight;
+	fn set_fee_rate() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn total_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4692`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 4692)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:0)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn balance_of() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103`
+		//  Estimated: `4740`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 4740)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn force_set_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `16912`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 16912)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:1 w:1)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `70728`
+		// Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 70728)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:1 w:1)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UsedTransferIds (r:1 w:1)
+	/// Proof: TemplateModule UsedTransferIds (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn transfer_with_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `72791`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 72791)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(26_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceSweepCursor (r:1 w:0)
+	/// Proof: TemplateModule AllowanceSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExpiringAllowances (r:0 w:1)
+	/// Proof: TemplateModule ExpiringAllowances (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `23323`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 23323)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `93466`
+		// Minimum execution time: 75_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 93466)
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(31_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:2 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn spend_all_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2070`
+		//  Estimated: `92950`
+		// Minimum execution time: 72_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 92950)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(30_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn send() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `67701`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 67701)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1324`
+		//  Estimated: `31492`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 31492)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextMintId (r:1 w:1)
+	/// Proof: TemplateModule NextMintId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	fn announce_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1011`
+		//  Estimated: `4020`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4020)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn execute_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1053`
+		//  Estimated: `32324`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(44_000_000, 32324)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	fn cancel_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1011`
+		//  Estimated: `3517`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn exclude_from_circulating() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1002`
+		//  Estimated: `3709`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3709)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn include_in_circulating() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1034`
+		//  Estimated: `3709`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3709)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingParameters (r:1 w:1)
+	/// Proof: TemplateModule PendingParameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn schedule_parameter_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2696`
 		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		Weight::from_parts(9_000_000, 2696)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalStaked (r:1 w:1)
+	/// Proof: TemplateModule TotalStaked (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardPerShare (r:1 w:1)
+	/// Proof: TemplateModule RewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardsAccounted (r:1 w:1)
+	/// Proof: TemplateModule RewardsAccounted (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn stake() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `512`
+		//  Estimated: `44583`
+		// Minimum execution time: 73_000_000 picoseconds.
+		Weight::from_parts(74_000_000, 44583)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalStaked (r:1 w:1)
+	/// Proof: TemplateModule TotalStaked (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardPerShare (r:1 w:1)
+	/// Proof: TemplateModule RewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardsAccounted (r:1 w:1)
+	/// Proof: TemplateModule RewardsAccounted (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `25968`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(57_000_000, 25968)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1083`
+		//  Estimated: `19365`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 19365)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule OutboundNonce (r:1 w:1)
+	/// Proof: TemplateModule OutboundNonce (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn bridge_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `39008`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 39008)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ProcessedInbound (r:1 w:1)
+	/// Proof: TemplateModule ProcessedInbound (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn bridge_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `45779`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 45779)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Relayers (r:0 w:1)
+	/// Proof: TemplateModule Relayers (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RelayerThreshold (r:0 w:1)
+	/// Proof: TemplateModule RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn set_relayers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (40 ±0)`
+		//  Estimated: `2189`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2189)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(410_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Relayers (r:1 w:0)
+	/// Proof: TemplateModule Relayers (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ProcessedInbound (r:1 w:1)
+	/// Proof: TemplateModule ProcessedInbound (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule InboundVotes (r:1 w:1)
+	/// Proof: TemplateModule InboundVotes (max_values: None, max_size: Some(581), added: 3056, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RelayerThreshold (r:1 w:0)
+	/// Proof: TemplateModule RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn vote_bridge_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1321`
+		//  Estimated: `50342`
+		// Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(71_000_000, 50342)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:0 w:1)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn accept_incoming() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:0 w:1)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn allow_sender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:0 w:1)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn disallow_sender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:0 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn approve_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn cancel_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `4768`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4768)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:1)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `5235`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 5235)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:1)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn clear_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186`
+		//  Estimated: `5235`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 5235)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextRequestId (r:1 w:1)
+	/// Proof: TemplateModule NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn request_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `10176`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 10176)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn accept_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5140`
+		//  Estimated: `75185`
+		// Minimum execution time: 73_000_000 picoseconds.
+		Weight::from_parts(74_000_000, 75185)
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn reject_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `9673`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 9673)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `9673`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 9673)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_if() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `19769`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 19769)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:200 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:100 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:100 w:100)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:100 w:100)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:100 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:100 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:100 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:200 w:100)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:100 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:100 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:100 w:100)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:300 w:300)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:200 w:200)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:300 w:300)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:100 w:100)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:400 w:600)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:100 w:100)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:100 w:100)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:100 w:100)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:100 w:100)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:100 w:100)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + n * (40 ±0)`
+		//  Estimated: `2685 + n * (65512 ±0)`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 2685)
+			// Standard Error: 5_891_000
+			.saturating_add(Weight::from_parts(58_910_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((31_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((24_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 65512).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:50 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:50 w:50)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:50 w:50)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:50)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:50 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn batch_approve(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (40 ±0)`
+		//  Estimated: `8916 + n * (10853 ±0)`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 8916)
+			// Standard Error: 2_794_000
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10853).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:1)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:0)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_scoped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		//  Estimated: `14062`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 14062)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:1)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn transfer_from_scoped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `77957`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(72_000_000, 77957)
+			.saturating_add(T::DbWeight::get().reads(37_u64))
+			.saturating_add(T::DbWeight::get().writes(27_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:102 w:102)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:100)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:2 w:2)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:0)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:2 w:2)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:2 w:2)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:2 w:2)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:2 w:2)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:2 w:2)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:2 w:2)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:2)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ReservedBalance (r:2 w:2)
+	/// Proof: TemplateModule ReservedBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NamedReserves (r:1 w:2)
+	/// Proof: TemplateModule NamedReserves (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:2 w:2)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:250 w:200)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:150 w:0)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:150 w:300)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:150 w:300)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DelegatedAllowances (r:0 w:3200)
+	/// Proof: TemplateModule DelegatedAllowances (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn migrate_account(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19602 + n * (40 ±0)`
+		//  Estimated: `103467 + n * (17620 ±0)`
+		// Minimum execution time: 1_188_000_000 picoseconds.
+		Weight::from_parts(1_189_000_000, 103467)
+			// Standard Error: 3_847_000
+			.saturating_add(Weight::from_parts(38_470_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(240_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2040_u64))
+			.saturating_add(T::DbWeight::get().writes((22_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 17620).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:0 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	fn attest_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:0)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:100)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn prune_spent_by() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9381`
+		//  Estimated: `7327`
+		// Minimum execution time: 411_000_000 picoseconds.
+		Weight::from_parts(412_000_000, 7327)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(100_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:1)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn freeze_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4720`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4720)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:1)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn unfreeze_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `4720`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4720)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UsedTransferIds (r:100 w:100)
+	/// Proof: TemplateModule UsedTransferIds (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn prune_transfer_ids(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `138 + n * (40 ±0)`
+		//  Estimated: `2189 + n * (2559 ±0)`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			// Standard Error: 621_000
+			.saturating_add(Weight::from_parts(6_210_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:1)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ReserveAttestationHistory (r:1 w:1)
+	/// Proof: TemplateModule ReserveAttestationHistory (max_values: Some(1), max_size: Some(8101), added: 8596, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn post_reserve_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8463`
+		//  Estimated: `14053`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 14053)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsRoot (r:0 w:1)
+	/// Proof: TemplateModule ClaimsRoot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsLeft (r:0 w:1)
+	/// Proof: TemplateModule ClaimsLeft (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_claims_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimedAddresses (r:1 w:1)
+	/// Proof: TemplateModule ClaimedAddresses (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsRoot (r:1 w:0)
+	/// Proof: TemplateModule ClaimsRoot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsLeft (r:1 w:1)
+	/// Proof: TemplateModule ClaimsLeft (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + p * (40 ±0)`
+		//  Estimated: `34537`
+		// Minimum execution time: 100_000_000 picoseconds.
+		Weight::from_parts(101_000_000, 34537)
+			// Standard Error: 146_000
+			.saturating_add(Weight::from_parts(1_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DomainSeparator (r:1 w:0)
+	/// Proof: TemplateModule DomainSeparator (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Nonces (r:1 w:1)
+	/// Proof: TemplateModule Nonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn permit_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1820`
+		//  Estimated: `22827`
+		// Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 22827)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:1)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn migrate_to_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `7377`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 7377)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingParameters (r:1 w:1)
+	/// Proof: TemplateModule PendingParameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn cancel_parameter_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `2696`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2696)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:0)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DelegatedAllowances (r:1 w:1)
+	/// Proof: TemplateModule DelegatedAllowances (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn delegate_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `822`
+		//  Estimated: `10555`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 10555)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:0 w:1)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_savings_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `2189`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	fn release_matured() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `4905`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4905)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:1)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn initiate_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `185`
+		//  Estimated: `7295`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 7295)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `258`
+		//  Estimated: `28266`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 28266)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn increase_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `19769`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 19769)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn decrease_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1921`
+		//  Estimated: `19813`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 19813)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Metadata (r:0 w:1)
+	/// Proof: TemplateModule Metadata (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:1)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn create_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4724`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4724)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2685`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2685)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `2685`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2685)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:0 w:1)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn freeze_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:0 w:1)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn thaw_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DomainSeparator (r:1 w:0)
+	/// Proof: TemplateModule DomainSeparator (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Nonces (r:1 w:1)
+	/// Proof: TemplateModule Nonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn permit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1820`
+		//  Estimated: `22827`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 22827)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:11 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:10 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:10 w:10)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:10 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:11 w:10)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:10 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:11 w:11)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:11 w:11)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:11 w:11)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:22 w:33)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn transfer_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351 + n * (40 ±0)`
+		//  Estimated: `16907 + n * (28154 ±0)`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 16907)
+			// Standard Error: 4_135_000
+			.saturating_add(Weight::from_parts(41_350_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 28154).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:2 w:1)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn transfer_with_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `71281`
+		// Minimum execution time: 65_000_000 picoseconds.
+		Weight::from_parts(66_000_000, 71281)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:1)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn vest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `5273`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 5273)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn burn_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1960`
+		//  Estimated: `53479`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 53479)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:1)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `5195`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 5195)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:100 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:100 w:100)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:100 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:100 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:100 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:100 w:100)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:200 w:300)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep_dust(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + n * (40 ±0)`
+		//  Estimated: `7192 + n * (23597 ±0)`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 7192)
+			// Standard Error: 2_164_000
+			.saturating_add(Weight::from_parts(21_640_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 23597).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn mint_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `12797`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 12797)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn burn_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `10274`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 10274)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn transfer_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `15368`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 15368)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:0)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetAllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AssetAllowanceDeposits (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `19600`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 19600)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetAllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AssetAllowanceDeposits (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_asset_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `28195`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 28195)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Metadata (r:0 w:1)
+	/// Proof: TemplateModule Metadata (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn set_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `4724`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4724)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceSweepCursor (r:1 w:1)
+	/// Proof: TemplateModule AllowanceSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExpiringAllowances (r:2 w:1)
+	/// Proof: TemplateModule ExpiringAllowances (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn expire_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `28005`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 28005)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TokensPerNativeUnit (r:0 w:1)
+	/// Proof: TemplateModule TokensPerNativeUnit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_fee_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn total_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4692`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 4692)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:0)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn balance_of() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103`
+		//  Estimated: `4740`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 4740)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn force_set_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `16912`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 16912)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:1 w:1)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `70728`
+		// Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 70728)
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:1 w:1)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UsedTransferIds (r:1 w:1)
+	/// Proof: TemplateModule UsedTransferIds (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn transfer_with_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `72791`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 72791)
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(26_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceSweepCursor (r:1 w:0)
+	/// Proof: TemplateModule AllowanceSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExpiringAllowances (r:0 w:1)
+	/// Proof: TemplateModule ExpiringAllowances (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `23323`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 23323)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `93466`
+		// Minimum execution time: 75_000_000 picoseconds.
+		Weight::from_parts(76_000_000, 93466)
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(31_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:2 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn spend_all_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2070`
+		//  Estimated: `92950`
+		// Minimum execution time: 72_000_000 picoseconds.
+		Weight::from_parts(73_000_000, 92950)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(30_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn send() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `67701`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 67701)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1324`
+		//  Estimated: `31492`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 31492)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextMintId (r:1 w:1)
+	/// Proof: TemplateModule NextMintId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	fn announce_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1011`
+		//  Estimated: `4020`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4020)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn execute_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1053`
+		//  Estimated: `32324`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(44_000_000, 32324)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AnnouncedMints (r:1 w:1)
+	/// Proof: TemplateModule AnnouncedMints (max_values: Some(1), max_size: Some(833), added: 1328, mode: MaxEncodedLen)
+	fn cancel_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1011`
+		//  Estimated: `3517`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn exclude_from_circulating() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1002`
+		//  Estimated: `3709`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3709)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn include_in_circulating() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1034`
+		//  Estimated: `3709`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3709)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingParameters (r:1 w:1)
+	/// Proof: TemplateModule PendingParameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn schedule_parameter_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2696`
 		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		Weight::from_parts(9_000_000, 2696)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalStaked (r:1 w:1)
+	/// Proof: TemplateModule TotalStaked (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardPerShare (r:1 w:1)
+	/// Proof: TemplateModule RewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardsAccounted (r:1 w:1)
+	/// Proof: TemplateModule RewardsAccounted (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn stake() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Measured:  `512`
+		//  Estimated: `44583`
+		// Minimum execution time: 73_000_000 picoseconds.
+		Weight::from_parts(74_000_000, 44583)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalStaked (r:1 w:1)
+	/// Proof: TemplateModule TotalStaked (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardPerShare (r:1 w:1)
+	/// Proof: TemplateModule RewardPerShare (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RewardsAccounted (r:1 w:1)
+	/// Proof: TemplateModule RewardsAccounted (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unstake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `25968`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(57_000_000, 25968)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:1 w:1)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1083`
+		//  Estimated: `19365`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 19365)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule OutboundNonce (r:1 w:1)
+	/// Proof: TemplateModule OutboundNonce (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn bridge_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `39008`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 39008)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ProcessedInbound (r:1 w:1)
+	/// Proof: TemplateModule ProcessedInbound (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn bridge_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `45779`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 45779)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Relayers (r:0 w:1)
+	/// Proof: TemplateModule Relayers (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RelayerThreshold (r:0 w:1)
+	/// Proof: TemplateModule RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn set_relayers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (40 ±0)`
+		//  Estimated: `2189`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2189)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(410_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Relayers (r:1 w:0)
+	/// Proof: TemplateModule Relayers (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ProcessedInbound (r:1 w:1)
+	/// Proof: TemplateModule ProcessedInbound (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule InboundVotes (r:1 w:1)
+	/// Proof: TemplateModule InboundVotes (max_values: None, max_size: Some(581), added: 3056, mode: MaxEncodedLen)
+	/// Storage: TemplateModule RelayerThreshold (r:1 w:0)
+	/// Proof: TemplateModule RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn vote_bridge_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1321`
+		//  Estimated: `50342`
+		// Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(71_000_000, 50342)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:0 w:1)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn accept_incoming() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:0 w:1)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn allow_sender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:0 w:1)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn disallow_sender() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:0 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn approve_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Subscriptions (r:1 w:1)
+	/// Proof: TemplateModule Subscriptions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn cancel_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `4768`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4768)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:1)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `5235`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 5235)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:1)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn clear_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186`
+		//  Estimated: `5235`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 5235)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NextRequestId (r:1 w:1)
+	/// Proof: TemplateModule NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn request_payment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `10176`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 10176)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn accept_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5140`
+		//  Estimated: `75185`
+		// Minimum execution time: 73_000_000 picoseconds.
+		Weight::from_parts(74_000_000, 75185)
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn reject_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `9673`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 9673)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:1 w:1)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4985`
+		//  Estimated: `9673`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 9673)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_if() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `19769`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 19769)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:200 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:100 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:100 w:100)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:100 w:100)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:100 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:100 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:100 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:200 w:100)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:100 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:100 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:100 w:100)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:300 w:300)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:200 w:200)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:300 w:300)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:100 w:100)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:400 w:600)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:100 w:100)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:100 w:100)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:100 w:100)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:100 w:100)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:100 w:100)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + n * (40 ±0)`
+		//  Estimated: `2685 + n * (65512 ±0)`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 2685)
+			// Standard Error: 5_891_000
+			.saturating_add(Weight::from_parts(58_910_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((31_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((24_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 65512).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:50 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:50 w:50)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:50 w:50)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:50)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:50 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn batch_approve(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + n * (40 ±0)`
+		//  Estimated: `8916 + n * (10853 ±0)`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 8916)
+			// Standard Error: 2_794_000
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10853).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:1)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:0)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_scoped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		//  Estimated: `14062`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 14062)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:1)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn transfer_from_scoped() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `77957`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(72_000_000, 77957)
+			.saturating_add(RocksDbWeight::get().reads(37_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:102 w:102)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:100)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:2 w:2)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedAllowances (r:1 w:0)
+	/// Proof: TemplateModule ScopedAllowances (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Ledgers (r:2 w:2)
+	/// Proof: TemplateModule Ledgers (max_values: None, max_size: Some(457), added: 2932, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:2 w:2)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FreeTransfers (r:2 w:2)
+	/// Proof: TemplateModule FreeTransfers (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:2 w:2)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:2 w:2)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:2 w:2)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:2)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ReservedBalance (r:2 w:2)
+	/// Proof: TemplateModule ReservedBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule NamedReserves (r:1 w:2)
+	/// Proof: TemplateModule NamedReserves (max_values: None, max_size: Some(849), added: 3324, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PaymentRequests (r:2 w:2)
+	/// Proof: TemplateModule PaymentRequests (max_values: None, max_size: Some(5009), added: 7484, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExcludedFromCirculating (r:1 w:1)
+	/// Proof: TemplateModule ExcludedFromCirculating (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:250 w:200)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:150 w:0)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:150 w:300)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:150 w:300)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DelegatedAllowances (r:0 w:3200)
+	/// Proof: TemplateModule DelegatedAllowances (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:0 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn migrate_account(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19602 + n * (40 ±0)`
+		//  Estimated: `103467 + n * (17620 ±0)`
+		// Minimum execution time: 1_188_000_000 picoseconds.
+		Weight::from_parts(1_189_000_000, 103467)
+			// Standard Error: 3_847_000
+			.saturating_add(Weight::from_parts(38_470_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(240_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2040_u64))
+			.saturating_add(RocksDbWeight::get().writes((22_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 17620).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:0 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	fn attest_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:0)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:100)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn prune_spent_by() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9381`
+		//  Estimated: `7327`
+		// Minimum execution time: 411_000_000 picoseconds.
+		Weight::from_parts(412_000_000, 7327)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(100_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:1)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn freeze_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4720`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4720)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:1)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn unfreeze_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `4720`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4720)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule UsedTransferIds (r:100 w:100)
+	/// Proof: TemplateModule UsedTransferIds (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn prune_transfer_ids(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `138 + n * (40 ±0)`
+		//  Estimated: `2189 + n * (2559 ±0)`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			// Standard Error: 621_000
+			.saturating_add(Weight::from_parts(6_210_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:1)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ReserveAttestationHistory (r:1 w:1)
+	/// Proof: TemplateModule ReserveAttestationHistory (max_values: Some(1), max_size: Some(8101), added: 8596, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn post_reserve_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8463`
+		//  Estimated: `14053`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 14053)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsRoot (r:0 w:1)
+	/// Proof: TemplateModule ClaimsRoot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsLeft (r:0 w:1)
+	/// Proof: TemplateModule ClaimsLeft (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_claims_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimedAddresses (r:1 w:1)
+	/// Proof: TemplateModule ClaimedAddresses (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsRoot (r:1 w:0)
+	/// Proof: TemplateModule ClaimsRoot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ClaimsLeft (r:1 w:1)
+	/// Proof: TemplateModule ClaimsLeft (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MintedThisBlock (r:1 w:1)
+	/// Proof: TemplateModule MintedThisBlock (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LatestReserveAttestation (r:1 w:0)
+	/// Proof: TemplateModule LatestReserveAttestation (max_values: Some(1), max_size: Some(270), added: 765, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:0)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + p * (40 ±0)`
+		//  Estimated: `34537`
+		// Minimum execution time: 100_000_000 picoseconds.
+		Weight::from_parts(101_000_000, 34537)
+			// Standard Error: 146_000
+			.saturating_add(Weight::from_parts(1_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DomainSeparator (r:1 w:0)
+	/// Proof: TemplateModule DomainSeparator (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Nonces (r:1 w:1)
+	/// Proof: TemplateModule Nonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn permit_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1820`
+		//  Estimated: `22827`
+		// Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 22827)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:1)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn migrate_to_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `7377`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 7377)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule PendingParameters (r:1 w:1)
+	/// Proof: TemplateModule PendingParameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn cancel_parameter_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `2696`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2696)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:0)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DelegatedAllowances (r:1 w:1)
+	/// Proof: TemplateModule DelegatedAllowances (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn delegate_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `822`
+		//  Estimated: `10555`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 10555)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:0 w:1)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_savings_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `2189`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	fn release_matured() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `4905`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4905)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:1)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn initiate_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `185`
+		//  Estimated: `7295`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 7295)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `258`
+		//  Estimated: `28266`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 28266)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn increase_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1788`
+		//  Estimated: `19769`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 19769)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn decrease_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1921`
+		//  Estimated: `19813`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 19813)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Metadata (r:0 w:1)
+	/// Proof: TemplateModule Metadata (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:1)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn create_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `4724`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4724)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2685`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2685)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:1)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `2685`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 2685)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:0 w:1)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn freeze_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:0 w:1)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn thaw_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule DomainSeparator (r:1 w:0)
+	/// Proof: TemplateModule DomainSeparator (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Nonces (r:1 w:1)
+	/// Proof: TemplateModule Nonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn permit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1820`
+		//  Estimated: `22827`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 22827)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:11 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:10 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:10 w:10)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:10 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:11 w:10)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:10 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:11 w:11)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:11 w:11)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:11 w:11)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:22 w:33)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 10]`.
+	fn transfer_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351 + n * (40 ±0)`
+		//  Estimated: `16907 + n * (28154 ±0)`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 16907)
+			// Standard Error: 4_135_000
+			.saturating_add(Weight::from_parts(41_350_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 28154).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule StrictReceivers (r:1 w:0)
+	/// Proof: TemplateModule StrictReceivers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Attestations (r:1 w:1)
+	/// Proof: TemplateModule Attestations (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule KycOutflow (r:1 w:1)
+	/// Proof: TemplateModule KycOutflow (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingsLock (r:1 w:0)
+	/// Proof: TemplateModule SavingsLock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:2 w:1)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowedSenders (r:1 w:0)
+	/// Proof: TemplateModule AllowedSenders (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LimitedAccounts (r:1 w:0)
+	/// Proof: TemplateModule LimitedAccounts (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferLimits (r:1 w:1)
+	/// Proof: TemplateModule TransferLimits (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:3 w:3)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:2 w:2)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:4 w:6)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferCount (r:1 w:1)
+	/// Proof: TemplateModule TransferCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TransferVolume (r:1 w:1)
+	/// Proof: TemplateModule TransferVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BlockVolume (r:1 w:1)
+	/// Proof: TemplateModule BlockVolume (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:2 w:1)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn transfer_with_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `71281`
+		// Minimum execution time: 65_000_000 picoseconds.
+		Weight::from_parts(66_000_000, 71281)
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:1)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	fn vest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `5273`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 5273)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:1 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:1 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:1 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:1 w:1)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SpentBy (r:1 w:1)
+	/// Proof: TemplateModule SpentBy (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:2 w:3)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn burn_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1960`
+		//  Estimated: `53479`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 53479)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule MaxSupply (r:1 w:1)
+	/// Proof: TemplateModule MaxSupply (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:0)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_max_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `5195`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 5195)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:100 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:100 w:100)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule FrozenBalance (r:100 w:0)
+	/// Proof: TemplateModule FrozenBalance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule VestingSchedules (r:100 w:0)
+	/// Proof: TemplateModule VestingSchedules (max_values: None, max_size: Some(609), added: 3084, mode: MaxEncodedLen)
+	/// Storage: TemplateModule SavingTranches (r:100 w:0)
+	/// Proof: TemplateModule SavingTranches (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: TemplateModule LastActivity (r:100 w:100)
+	/// Proof: TemplateModule LastActivity (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule HolderCount (r:1 w:1)
+	/// Proof: TemplateModule HolderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHolders (r:200 w:300)
+	/// Proof: TemplateModule TopHolders (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersCount (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TopHoldersFloor (r:1 w:1)
+	/// Proof: TemplateModule TopHoldersFloor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalBurned (r:1 w:1)
+	/// Proof: TemplateModule TotalBurned (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn sweep_dust(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + n * (40 ±0)`
+		//  Estimated: `7192 + n * (23597 ±0)`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 7192)
+			// Standard Error: 2_164_000
+			.saturating_add(Weight::from_parts(21_640_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 23597).saturating_mul(n.into()))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:1 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn mint_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `12797`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 12797)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:1 w:1)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TotalSupply (r:1 w:1)
+	/// Proof: TemplateModule TotalSupply (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn burn_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `10274`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 10274)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:2 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn transfer_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `15368`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 15368)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:0)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ScopedApprovalCount (r:1 w:0)
+	/// Proof: TemplateModule ScopedApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetAllowanceDeposits (r:0 w:1)
+	/// Proof: TemplateModule AssetAllowanceDeposits (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `19600`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 19600)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Frozen (r:3 w:0)
+	/// Proof: TemplateModule Frozen (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Paused (r:1 w:0)
+	/// Proof: TemplateModule Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule BalanceOf (r:2 w:2)
+	/// Proof: TemplateModule BalanceOf (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetAllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AssetAllowanceDeposits (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AssetApprovalCount (r:1 w:1)
+	/// Proof: TemplateModule AssetApprovalCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_asset_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `28195`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 28195)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Asset (r:1 w:0)
+	/// Proof: TemplateModule Asset (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Metadata (r:0 w:1)
+	/// Proof: TemplateModule Metadata (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn set_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `4724`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4724)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceSweepCursor (r:1 w:1)
+	/// Proof: TemplateModule AllowanceSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TemplateModule ExpiringAllowances (r:2 w:1)
+	/// Proof: TemplateModule ExpiringAllowances (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowances (r:1 w:1)
+	/// Proof: TemplateModule Allowances (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Allowance (r:1 w:1)
+	/// Proof: TemplateModule Allowance (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceDeposits (r:1 w:1)
+	/// Proof: TemplateModule AllowanceDeposits (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: TemplateModule AllowanceExpiry (r:1 w:1)
+	/// Proof: TemplateModule AllowanceExpiry (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Approvals (r:1 w:1)
+	/// Proof: TemplateModule Approvals (max_values: None, max_size: Some(1649), added: 4124, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Proof: TemplateModule Delegations (max_values: None, max_size: Some(689), added: 3164, mode: MaxEncodedLen)
+	fn expire_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `28005`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 28005)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplateModule AssetMigration (r:1 w:0)
+	/// Proof: TemplateModule AssetMigration (max_values: Some(1), max_size: Some(86), added: 581, mode: MaxEncodedLen)
+	/// Storage: TemplateModule Sunset (r:1 w:0)
+	/// Proof: TemplateModule Sunset (max_values: Some(1), max_size: Some(123), added: 618, mode: MaxEncodedLen)
+	/// Storage: TemplateModule TokensPerNativeUnit (r:0 w:1)
+	/// Proof: TemplateModule TokensPerNativeUnit (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_fee_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `2189`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 2189)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
@@ -13,9 +13,10 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, One, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -36,9 +37,10 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -48,6 +50,9 @@ pub use sp_runtime::{Perbill, Permill};
 
 /// Import the template pallet.
 pub use pallet_template;
+use pallet_template::traits::KycLimit;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::RuntimeAppPublic;
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -107,7 +112,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 100,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -249,13 +254,40 @@ impl pallet_balances::Config for Runtime {
 	type MaxHolds = ();
 }
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1_000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type AssetAccountDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type MetadataDepositBase = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::one();
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "token-fees"))]
 	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	// Converted at the rate `TemplateModule::set_fee_rate` sets; burned like native fees.
+	#[cfg(feature = "token-fees")]
+	type OnChargeTransaction = pallet_template::fees::TokenFees<Runtime, Balance>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
@@ -268,10 +300,133 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const TemplatePalletId: PalletId = PalletId(*b"py/erc20");
+	pub const TemplateDomainName: &'static [u8] = b"Template Token";
+	pub TemplateRewardPot: AccountId = TemplatePalletId::get().into_account_truncating();
+	pub TemplateCommunityPool: AccountId =
+		TemplatePalletId::get().into_sub_account_truncating(*b"community");
+	pub const TemplateDemurrageRate: Perbill = Perbill::zero();
+	pub TemplateBridgePot: AccountId =
+		TemplatePalletId::get().into_sub_account_truncating(*b"bridge");
+	pub TemplateBurnAddress: Option<AccountId> =
+		Some(TemplatePalletId::get().into_sub_account_truncating(*b"burn"));
+	pub const TemplateMaxHolders: Option<u32> = None;
+	pub TemplateDustReceiver: Option<AccountId> = Some(TemplateCommunityPool::get());
+	pub const TemplateKycNoneLimit: KycLimit<u64> = KycLimit { max_transfer: 0, max_per_window: 0 };
+	pub const TemplateKycBasicLimit: KycLimit<u64> =
+		KycLimit { max_transfer: 1_000_000_000_000, max_per_window: 10_000_000_000_000 };
+	pub const TemplateKycFullLimit: KycLimit<u64> =
+		KycLimit { max_transfer: u64::MAX, max_per_window: u64::MAX };
+}
+
+/// Signs the template pallet's benchmark payloads with sr25519 keys from the keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TemplateBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::traits::BenchmarkHelper<AccountId, Signature> for TemplateBenchmarkHelper {
+	fn signer() -> AccountId {
+		sp_core::sr25519::Public::from(<AuraId as RuntimeAppPublic>::generate_pair(None)).into()
+	}
+
+	fn sign(signer: &AccountId, payload: &[u8]) -> Signature {
+		let public = sp_core::sr25519::Public::from_raw(signer.clone().into());
+		let signature = RuntimeAppPublic::sign(&AuraId::from(public), &payload)
+			.expect("key is in the keystore; qed");
+		sp_core::sr25519::Signature::from(signature).into()
+	}
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	// Kept apart from the native `Balance` so the token's storage layout is unchanged.
+	type Balance = u64;
+	type AssetId = u32;
+	// Tokens launched with `create_asset` take any other id.
+	type NativeAsset = ConstU32<0>;
+	type MaxApprovalsPerOwner = ConstU32<50>;
+	type MaxDelegations = ConstU32<16>;
+	type AllowanceMigrationBatch = ConstU32<1_000>;
+	type StatsWindow = ConstU32<{ HOURS }>;
+	type MetricsInterval = ConstU32<{ 10 * MINUTES }>;
+	type SupplyTrackingPeriod = ConstU32<{ DAYS }>;
+	type MaxSupplyHistory = ConstU32<365>;
+	type PalletId = TemplatePalletId;
+	type MaxBatchSize = ConstU32<100>;
+	type DomainName = TemplateDomainName;
+	type DomainVersion = ConstU32<1>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type PermitPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TemplateBenchmarkHelper;
+	type TokensReceived = ();
+	type MaxDataLen = ConstU32<256>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type BlockReward = ConstU64<0>;
+	type RewardBeneficiary = TemplateRewardPot;
+	type DemurrageRate = TemplateDemurrageRate;
+	type DemurragePool = TemplateCommunityPool;
+	// Block rewards fund staking.
+	type StakingPot = TemplateRewardPot;
+	type UnbondingBlocks = ConstU32<{ 7 * DAYS }>;
+	type MaxUnbondingChunks = ConstU32<32>;
+	type SlashFreeBalance = ConstBool<false>;
+	type OnSlash = ();
+	type BridgeOrigin = EnsureRoot<AccountId>;
+	type BridgePot = TemplateBridgePot;
+	type BridgeBurns = ConstBool<false>;
+	type BurnAddress = TemplateBurnAddress;
+	type MaxAddressLen = ConstU32<64>;
+	type MaxRelayers = ConstU32<16>;
+	type RequestTtl = ConstU32<{ 7 * DAYS }>;
+	type MaxPendingRequests = ConstU32<16>;
+	type MaxMintPerBlock = ConstU64<{ u64::MAX }>;
+	type MintAnnouncementThreshold = ConstU64<1_000_000_000_000>;
+	type MintDelay = ConstU32<{ 2 * DAYS }>;
+	type MaxAnnouncedMints = ConstU32<16>;
+	type ParameterDelay = ConstU32<{ 2 * DAYS }>;
+	type MaxExcludedAccounts = ConstU32<32>;
+	type FreeTransfersPerPeriod = ConstU32<5>;
+	type FreeTransferMaxValue = ConstU64<1_000_000>;
+	type FreeTransferPeriod = ConstU32<{ DAYS }>;
+	type Currency = Balances;
+	type AllowanceDeposit = ConstU128<{ 2 * EXISTENTIAL_DEPOSIT }>;
+	type ComplianceOracle = EnsureRoot<AccountId>;
+	type ReserveOracle = EnsureRoot<AccountId>;
+	type MaxReserveHistory = ConstU32<30>;
+	type MintRequiresCollateral = ConstBool<false>;
+	type AttestationThreshold = ConstU64<{ u64::MAX }>;
+	type IndexTransfers = ConstBool<false>;
+	type DetailedEvents = ConstBool<false>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type KycProvider = ();
+	type KycNoneLimit = TemplateKycNoneLimit;
+	type KycBasicLimit = TemplateKycBasicLimit;
+	type KycFullLimit = TemplateKycFullLimit;
+	type KycWindow = ConstU32<{ DAYS }>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type MaxSavingTranches = ConstU32<16>;
+	type MaxHolders = TemplateMaxHolders;
+	type MaxClaimProofLen = ConstU32<32>;
+	type TransferIdRetention = ConstU32<{ 7 * DAYS }>;
+	type Assets = Assets;
+	type AssetMigrationBatch = ConstU32<500>;
+	type SunsetBatch = ConstU32<20>;
+	type TopHoldersTracked = ConstU32<100>;
+	type StringLimit = ConstU32<50>;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type MinBalance = ConstU64<1_000_000>;
+	type DustReceiver = TemplateDustReceiver;
+	type MaxVestingSchedules = ConstU32<28>;
+	type OnTransfer = ();
+	#[cfg(feature = "testnet-faucet")]
+	type FaucetAmount = ConstU64<10_000_000_000>;
+	#[cfg(feature = "testnet-faucet")]
+	type FaucetCooldown = ConstU32<{ DAYS }>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -282,6 +437,7 @@ construct_runtime!(
 		Aura: pallet_aura,
 		Grandpa: pallet_grandpa,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
 		// Include the custom logic from the pallet-template in the runtime.
@@ -305,6 +461,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::CheckNotFrozen<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -312,6 +469,15 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_template::migrations::v1::InitHolderCount<Runtime>,
+	pallet_template::migrations::v2::AddProviders<Runtime>,
+	pallet_template::migrations::v3::RekeyAllowances<Runtime>,
+	pallet_template::migrations::v4::InitTopHolders<Runtime>,
+	pallet_template::migrations::v5::RekeyByAsset<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -319,6 +485,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -505,6 +672,105 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template_runtime_api::Erc20Api<Block, AccountId, u64> for Runtime {
+		fn balances_of(accounts: Vec<AccountId>) -> Vec<u64> {
+			TemplateModule::balances_of(accounts)
+		}
+
+		fn holder_count() -> u32 {
+			TemplateModule::holder_count()
+		}
+
+		fn total_burned() -> u64 {
+			TemplateModule::total_burned()
+		}
+
+		fn circulating_supply() -> u64 {
+			TemplateModule::circulating_supply()
+		}
+
+		fn transfer_stats() -> (u64, u128) {
+			(TemplateModule::transfer_count(), TemplateModule::transfer_volume())
+		}
+
+		fn supply_at_period(index: u32) -> Option<u64> {
+			TemplateModule::supply_at_period(index)
+		}
+
+		fn derive_deposit_account(master: AccountId, index: u32) -> AccountId {
+			TemplateModule::derive_deposit_account(master, index)
+		}
+
+		fn domain_separator() -> [u8; 32] {
+			TemplateModule::domain_separator()
+		}
+
+		fn signing_payload(message: Vec<u8>) -> Vec<u8> {
+			TemplateModule::signing_payload(&message)
+		}
+
+		fn approvals_of(owner: AccountId) -> Vec<(AccountId, u64)> {
+			TemplateModule::approvals_of(owner)
+		}
+
+		fn spent_by(owner: AccountId, spender: AccountId) -> u64 {
+			TemplateModule::spent_by(owner, spender)
+		}
+
+		fn simulate_transfer(
+			from: AccountId,
+			to: AccountId,
+			value: u64,
+		) -> Result<(), pallet_template::Erc20Error> {
+			TemplateModule::simulate_transfer(from, to, value)
+		}
+
+		fn holders(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(AccountId, u64)>, Option<Vec<u8>>) {
+			TemplateModule::holders(start_key, limit)
+		}
+
+		fn latest_reserve_attestation() -> Option<(u64, u64, Vec<u8>)> {
+			TemplateModule::latest_reserve_attestation().map(|attestation| {
+				(attestation.reserves, attestation.as_of.into(), attestation.reference.into_inner())
+			})
+		}
+
+		fn top_holders(n: u32) -> Vec<(AccountId, u64)> {
+			TemplateModule::top_holders(n)
+		}
+
+		fn total_supply() -> u64 {
+			TemplateModule::get_total_supply().unwrap_or_default()
+		}
+
+		fn balance_of(account: AccountId) -> u64 {
+			TemplateModule::effective_balance(&account)
+		}
+
+		fn allowance(owner: AccountId, spender: AccountId) -> u64 {
+			TemplateModule::allowance(owner, spender)
+		}
+
+		fn name() -> Vec<u8> {
+			TemplateModule::metadata().name.into_inner()
+		}
+
+		fn symbol() -> Vec<u8> {
+			TemplateModule::metadata().symbol.into_inner()
+		}
+
+		fn decimals() -> u8 {
+			TemplateModule::metadata().decimals
+		}
+
+		fn max_supply() -> Option<u64> {
+			TemplateModule::max_supply()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (